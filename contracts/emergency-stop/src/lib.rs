@@ -1,51 +1,212 @@
 #![no_std]
 
-use risc0_interface::{Receipt, RiscZeroVerifierClient, RiscZeroVerifierInterface, VerifierError};
+use risc0_interface::{
+    Receipt, RiscZeroVerifierClient, RiscZeroVerifierInfoClient, RiscZeroVerifierInterface,
+    VerifierError, roles::GUARDIAN_ROLE, upgrade,
+};
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, contract, contracterror, contractimpl, contracttype,
-    panic_with_error,
+    Address, Bytes, BytesN, Env, Symbol, Vec, contract, contracterror, contractevent, contractimpl,
+    contracttype, panic_with_error,
 };
-use stellar_access::ownable::{self, Ownable};
+use stellar_access::access_control::{self, AccessControl};
 use stellar_contract_utils::pausable::{self, Pausable};
-use stellar_macros::{only_owner, when_not_paused};
+use stellar_macros::{only_admin, only_role, when_not_paused};
 
 #[cfg(test)]
 mod test;
 
 const ZERO_DIGEST: [u8; 32] = [0u8; 32];
 
+const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Guardian rotation delay until the admin sets one, see
+/// [`RiscZeroVerifierEmergencyStop::rotate_guardian`].
+const DEFAULT_GUARDIAN_ROTATION_DELAY: u32 = 7 * DAY_IN_LEDGERS;
+
+/// Version of this build, recorded on deployment and required to increase on every upgrade.
+const CONTRACT_VERSION: u32 = 1;
+
+/// Version of the storage layout of this build, see [`risc0_interface::upgrade`].
+const STORAGE_SCHEMA_VERSION: u32 = 1;
+
 /// Storage keys used by the emergency stop contract.
 #[contracttype]
 pub enum DataKey {
     /// Address of the verifier implementation being wrapped.
     Verifier,
+    /// Ledger sequence at which a temporary pause expires.
+    PausedUntil,
+    /// Guardian rotation delay in ledgers, absent while the default applies.
+    GuardianRotationDelay,
+    /// Guardian rotation waiting for its delay to pass.
+    PendingGuardian,
 }
 
-/// Errors emitted by the emergency stop wrapper.
+/// A guardian rotation waiting for its delay to pass, see
+/// [`RiscZeroVerifierEmergencyStop::rotate_guardian`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingGuardian {
+    /// Guardian replacing the current guardians.
+    pub guardian: Address,
+    /// Ledger sequence from which the rotation can be completed.
+    pub active_ledger: u32,
+}
+
+/// Errors emitted by the emergency stop wrapper (registry range `300..=399`).
+///
+/// The first four moved from `1`, `5`, `1001` and `1002` when the registry was introduced; see
+/// the error codes in `risc0-interface`.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum EmergencyStopError {
     /// Caller is not authorized to perform the requested action.
-    Unauthorized = 1,
+    Unauthorized = 300,
     /// Verifier address is not configured.
-    VerifierNotSet = 5,
+    VerifierNotSet = 301,
     /// Receipt does not prove a circuit-breaker exploit.
-    InvalidProofOfExploit = 1001,
+    InvalidProofOfExploit = 302,
     /// Unpause is not supported by the emergency stop wrapper.
-    UnpauseNotAllowed = 1002,
+    UnpauseNotAllowed = 303,
+    /// Verification is temporarily paused.
+    TemporarilyPaused = 304,
+    /// Guardians can only be added through a delayed guardian rotation.
+    GuardianRotationRequired = 305,
+    /// No guardian rotation is pending.
+    NoPendingGuardian = 306,
+    /// The delay of the pending guardian rotation has not passed yet.
+    GuardianRotationNotReady = 307,
+    /// The guardian rotation delay can only be increased.
+    RotationDelayDecrease = 308,
+}
+
+/// Event emitted when verification is permanently paused.
+///
+/// Published as an [`ESTOP_ALERT`](risc0_interface::alerts::ESTOP_ALERT), see
+/// [`risc0_interface::alerts`].
+#[contractevent(topics = ["risc0_alert", "estop"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyStopped {
+    /// Guardian that stopped verification, or `None` if a proof of exploit did.
+    pub guardian: Option<Address>,
+}
+
+/// Event emitted when verification is temporarily paused.
+///
+/// Published as a [`PAUSE_ALERT`](risc0_interface::alerts::PAUSE_ALERT).
+#[contractevent(topics = ["risc0_alert", "pause"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemporaryPause {
+    /// Ledger sequence at which verification resumes.
+    pub until_ledger: u32,
+}
+
+/// Event emitted when the admin starts a guardian rotation.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianRotationStarted {
+    /// Guardian replacing the current guardians.
+    pub guardian: Address,
+    /// Ledger sequence from which the rotation can be completed.
+    pub active_ledger: u32,
+}
+
+/// Event emitted when a pending guardian rotation is cancelled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianRotationCancelled {
+    /// Guardian of the cancelled rotation.
+    pub guardian: Address,
+    /// Admin or guardian that cancelled the rotation.
+    pub cancelled_by: Address,
+}
+
+/// Event emitted when a guardian rotation completes and the new guardian becomes active.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianRotated {
+    /// The new, sole guardian.
+    pub guardian: Address,
+}
+
+/// Event emitted when the admin increases the guardian rotation delay.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianRotationDelaySet {
+    /// The new delay in ledgers.
+    pub ledgers: u32,
+}
+
+/// Event emitted when the admin replaces the wrapped verifier.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifierUpdated {
+    /// Previously wrapped verifier.
+    pub from: Address,
+    /// Newly wrapped verifier.
+    pub to: Address,
 }
 
 /// Emergency-stop wrapper for a RISC Zero verifier contract.
+///
+/// The wrapper implements [`RiscZeroVerifierInterface`] itself and forwards every call to an
+/// inner verifier, so it can sit at any position in the verification stack:
+///
+/// - In front of a single verifier, registered in the router under that verifier's selector, to
+///   stop one proof system version
+/// - In front of the router, to stop all verification at once
+///
+/// The inner verifier is set at construction and can be replaced by the admin with
+/// [`set_verifier`](Self::set_verifier).
+///
+/// The guardian is set at construction and replaced with a delayed
+/// [`rotate_guardian`](Self::rotate_guardian); granting the guardian role directly is rejected.
 #[contract]
 pub struct RiscZeroVerifierEmergencyStop;
 
 #[contractimpl]
 impl RiscZeroVerifierEmergencyStop {
-    /// Initializes the wrapper with an underlying verifier and guardian owner.
-    pub fn __constructor(env: Env, verifier: Address, owner: Address) {
+    /// Initializes the wrapper with an underlying verifier, the admin managing roles, and the
+    /// initial guardian allowed to trigger the emergency stop.
+    pub fn __constructor(env: Env, verifier: Address, admin: Address, guardian: Address) {
         env.storage().instance().set(&DataKey::Verifier, &verifier);
-        ownable::set_owner(&env, &owner);
+        access_control::set_admin(&env, &admin);
+        access_control::grant_role_no_auth(&env, &guardian, &GUARDIAN_ROLE, &admin);
+        upgrade::set_contract_version(&env, CONTRACT_VERSION);
+        upgrade::set_schema_version(&env, STORAGE_SCHEMA_VERSION);
+    }
+
+    /// Returns the version of the deployed contract code.
+    pub fn contract_version(env: Env) -> u32 {
+        upgrade::contract_version(&env)
+    }
+
+    /// Upgrades the contract code to `new_wasm_hash`. Only the admin can call this.
+    ///
+    /// `new_version` must be the `CONTRACT_VERSION` of the new build and greater than the
+    /// current version; see [`risc0_interface::upgrade`]. Upgrading does not lift a pause.
+    #[only_admin]
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+        new_version: u32,
+    ) -> Result<(), VerifierError> {
+        upgrade::upgrade(&env, new_wasm_hash, new_version)
+    }
+
+    /// Returns the version of the storage layout.
+    pub fn schema_version(env: Env) -> u32 {
+        upgrade::schema_version(&env)
+    }
+
+    /// Migrates storage written by an earlier build to the layout of this build. Only the admin
+    /// can call this.
+    ///
+    /// A no-op if the storage is already up to date; see [`risc0_interface::upgrade`].
+    #[only_admin]
+    pub fn migrate(env: Env) -> Result<(), VerifierError> {
+        upgrade::migrate(&env, STORAGE_SCHEMA_VERSION, migrate_from)
     }
 
     /// Returns the verifier address wrapped by this contract.
@@ -53,10 +214,178 @@ impl RiscZeroVerifierEmergencyStop {
         get_verifier(&env)
     }
 
-    /// Permanently pauses verification. Only the guardian can call this.
-    #[only_owner]
-    pub fn estop(env: Env) {
+    /// Replaces the wrapped verifier, e.g. to insert the wrapper in front of a router. Only the
+    /// admin can call this.
+    #[only_admin]
+    pub fn set_verifier(env: Env, verifier: Address) {
+        let from = get_verifier(&env);
+        env.storage().instance().set(&DataKey::Verifier, &verifier);
+        VerifierUpdated { from, to: verifier }.publish(&env);
+    }
+
+    /// Starts replacing all guardians by `guardian`. Only the admin can call this.
+    ///
+    /// The rotation can be completed with
+    /// [`complete_guardian_rotation`](Self::complete_guardian_rotation) once
+    /// [`guardian_rotation_delay`](Self::guardian_rotation_delay) ledgers have passed, and
+    /// cancelled until then by the admin or any current guardian. An attacker holding only the
+    /// admin key therefore cannot swap the guardian unnoticed: the current guardians see the
+    /// [`GuardianRotationStarted`] event and can cancel the rotation, or stop verification.
+    ///
+    /// Starting a rotation replaces a pending one and restarts the delay. Removing a guardian
+    /// only reduces who can stop verification, so `revoke_role` remains immediate.
+    #[only_admin]
+    pub fn rotate_guardian(env: Env, guardian: Address) {
+        let active_ledger = env
+            .ledger()
+            .sequence()
+            .saturating_add(guardian_rotation_delay(&env));
+        let pending = PendingGuardian {
+            guardian: guardian.clone(),
+            active_ledger,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingGuardian, &pending);
+        GuardianRotationStarted {
+            guardian,
+            active_ledger,
+        }
+        .publish(&env);
+    }
+
+    /// Completes the pending guardian rotation once its delay has passed: the new guardian is
+    /// granted the guardian role, and every other guardian loses it. Anyone can call this.
+    ///
+    /// The rotation was approved by the admin when it started, so completing it does not depend
+    /// on an admin still being set: the role changes are made by the contract itself.
+    ///
+    /// # Panics
+    ///
+    /// - [`EmergencyStopError::NoPendingGuardian`] - No rotation is pending
+    /// - [`EmergencyStopError::GuardianRotationNotReady`] - The delay has not passed yet
+    pub fn complete_guardian_rotation(env: Env) {
+        let pending = read_pending_guardian(&env);
+        if env.ledger().sequence() < pending.active_ledger {
+            panic_with_error!(&env, EmergencyStopError::GuardianRotationNotReady);
+        }
+        env.storage().instance().remove(&DataKey::PendingGuardian);
+
+        let contract = env.current_contract_address();
+        for index in (0..access_control::get_role_member_count(&env, &GUARDIAN_ROLE)).rev() {
+            let guardian = access_control::get_role_member(&env, &GUARDIAN_ROLE, index);
+            if guardian != pending.guardian {
+                access_control::revoke_role_no_auth(&env, &guardian, &GUARDIAN_ROLE, &contract);
+            }
+        }
+        access_control::grant_role_no_auth(&env, &pending.guardian, &GUARDIAN_ROLE, &contract);
+        GuardianRotated {
+            guardian: pending.guardian,
+        }
+        .publish(&env);
+    }
+
+    /// Cancels the pending guardian rotation. Only the admin or a current guardian can call
+    /// this.
+    ///
+    /// # Panics
+    ///
+    /// - [`EmergencyStopError::Unauthorized`] - `caller` is neither the admin nor a guardian
+    /// - [`EmergencyStopError::NoPendingGuardian`] - No rotation is pending
+    pub fn cancel_guardian_rotation(env: Env, caller: Address) {
+        let is_admin = access_control::get_admin(&env).as_ref() == Some(&caller);
+        if !is_admin && access_control::has_role(&env, &caller, &GUARDIAN_ROLE).is_none() {
+            panic_with_error!(&env, EmergencyStopError::Unauthorized);
+        }
+        caller.require_auth();
+
+        let pending = read_pending_guardian(&env);
+        env.storage().instance().remove(&DataKey::PendingGuardian);
+        GuardianRotationCancelled {
+            guardian: pending.guardian,
+            cancelled_by: caller,
+        }
+        .publish(&env);
+    }
+
+    /// Returns the pending guardian rotation, if any.
+    pub fn pending_guardian(env: Env) -> Option<PendingGuardian> {
+        env.storage().instance().get(&DataKey::PendingGuardian)
+    }
+
+    /// Returns the number of ledgers between starting and completing a guardian rotation.
+    pub fn guardian_rotation_delay(env: Env) -> u32 {
+        guardian_rotation_delay(&env)
+    }
+
+    /// Sets the guardian rotation delay to `ledgers`. Only the admin can call this.
+    ///
+    /// The delay can only be increased, as otherwise the admin alone could bypass it. It
+    /// applies to rotations started afterwards.
+    ///
+    /// # Panics
+    ///
+    /// - [`EmergencyStopError::RotationDelayDecrease`] - `ledgers` is below the current delay
+    #[only_admin]
+    pub fn set_guardian_rotation_delay(env: Env, ledgers: u32) {
+        if ledgers < guardian_rotation_delay(&env) {
+            panic_with_error!(&env, EmergencyStopError::RotationDelayDecrease);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::GuardianRotationDelay, &ledgers);
+        GuardianRotationDelaySet { ledgers }.publish(&env);
+    }
+
+    /// Returns the selector of the wrapped verifier.
+    ///
+    /// Fails while paused, so health checks through this wrapper report a triggered stop.
+    #[when_not_paused]
+    pub fn selector(env: Env) -> Result<BytesN<4>, VerifierError> {
+        ensure_not_temporarily_paused(&env);
+        let verifier = get_verifier(&env);
+        Ok(RiscZeroVerifierInfoClient::new(&env, &verifier).selector())
+    }
+
+    /// Returns the post-state digest of the wrapped verifier.
+    ///
+    /// Unlike [`selector`](Self::selector), this does not fail while paused: it only describes
+    /// the claims, and verification fails anyway.
+    pub fn post_state_digest(env: Env) -> BytesN<32> {
+        let verifier = get_verifier(&env);
+        RiscZeroVerifierInfoClient::new(&env, &verifier).post_state_digest()
+    }
+
+    /// Permanently pauses verification. Only a guardian can call this.
+    #[only_role(guardian, "guardian")]
+    pub fn estop(env: Env, guardian: Address) {
         pausable::pause(&env);
+        EmergencyStopped {
+            guardian: Some(guardian),
+        }
+        .publish(&env);
+    }
+
+    /// Temporarily pauses verification for `ledgers` ledgers. Only a guardian can call this.
+    ///
+    /// Verification resumes automatically once the ledger sequence reaches the deadline, with
+    /// no further action. A pause never shortens an ongoing one, and the permanent
+    /// [`estop`](Self::estop) remains available while temporarily paused.
+    #[only_role(guardian, "guardian")]
+    #[when_not_paused]
+    pub fn pause_for(env: Env, ledgers: u32, guardian: Address) {
+        let until_ledger = env.ledger().sequence().saturating_add(ledgers);
+        let until_ledger = until_ledger.max(paused_until(&env).unwrap_or(0));
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PausedUntil, &until_ledger);
+        TemporaryPause { until_ledger }.publish(&env);
+    }
+
+    /// Returns the ledger sequence at which an ongoing temporary pause expires, if any.
+    pub fn paused_until(env: Env) -> Option<u32> {
+        paused_until(&env)
     }
 
     /// Permanently pauses verification via the circuit-breaker receipt.
@@ -67,17 +396,22 @@ impl RiscZeroVerifierEmergencyStop {
             panic_with_error!(&env, EmergencyStopError::InvalidProofOfExploit);
         }
 
-        // Ensure the proof-of-exploit receipt is valid.
-        let _ = Self::verify_integrity(env.clone(), receipt);
+        // Ensure the proof-of-exploit receipt is valid. This calls the verifier directly, so
+        // the receipt is accepted during a temporary pause.
+        let verifier = get_verifier(&env);
+        RiscZeroVerifierClient::new(&env, &verifier).verify_integrity(&receipt);
 
         pausable::pause(&env);
+        EmergencyStopped { guardian: None }.publish(&env);
     }
 }
 
-#[contractimpl]
+#[contractimpl(contracttrait)]
 impl RiscZeroVerifierInterface for RiscZeroVerifierEmergencyStop {
     type Proof = Bytes;
 
+    /// Forwards to the wrapped verifier's `verify` instead of deriving the claim, so checks
+    /// a router performs on the image id still apply.
     #[when_not_paused]
     fn verify(
         env: Env,
@@ -85,6 +419,7 @@ impl RiscZeroVerifierInterface for RiscZeroVerifierEmergencyStop {
         image_id: BytesN<32>,
         journal: BytesN<32>,
     ) -> Result<(), VerifierError> {
+        ensure_not_temporarily_paused(&env);
         let verifier = get_verifier(&env);
         let client = RiscZeroVerifierClient::new(&env, &verifier);
         client.verify(&seal, &image_id, &journal);
@@ -93,28 +428,52 @@ impl RiscZeroVerifierInterface for RiscZeroVerifierEmergencyStop {
 
     #[when_not_paused]
     fn verify_integrity(env: Env, receipt: Receipt) -> Result<(), VerifierError> {
+        ensure_not_temporarily_paused(&env);
         let verifier = get_verifier(&env);
         let client = RiscZeroVerifierClient::new(&env, &verifier);
         client.verify_integrity(&receipt);
         Ok(())
     }
+
+    #[when_not_paused]
+    fn verify_many(env: Env, seal: Bytes, claims: Vec<BytesN<32>>) -> Result<(), VerifierError> {
+        ensure_not_temporarily_paused(&env);
+        let verifier = get_verifier(&env);
+        let client = RiscZeroVerifierClient::new(&env, &verifier);
+        client.verify_many(&seal, &claims);
+        Ok(())
+    }
 }
 
 #[contractimpl(contracttrait)]
-impl Ownable for RiscZeroVerifierEmergencyStop {}
+impl AccessControl for RiscZeroVerifierEmergencyStop {
+    /// Grants `role` to `account`, except for the guardian role, which is only granted by a
+    /// delayed [`rotate_guardian`](RiscZeroVerifierEmergencyStop::rotate_guardian).
+    fn grant_role(e: &Env, account: Address, role: Symbol, caller: Address) {
+        if role == GUARDIAN_ROLE {
+            panic_with_error!(e, EmergencyStopError::GuardianRotationRequired);
+        }
+        access_control::grant_role(e, &account, &role, &caller);
+    }
+}
 
 #[contractimpl]
 impl Pausable for RiscZeroVerifierEmergencyStop {
+    /// Returns whether verification is paused, permanently or temporarily.
     fn paused(env: &Env) -> bool {
-        pausable::paused(env)
+        pausable::paused(env) || paused_until(env).is_some()
     }
 
     fn pause(env: &Env, caller: Address) {
-        let owner = ownable::enforce_owner_auth(env);
-        if owner != caller {
+        if access_control::has_role(env, &caller, &GUARDIAN_ROLE).is_none() {
             panic_with_error!(env, EmergencyStopError::Unauthorized);
         }
+        caller.require_auth();
         pausable::pause(env);
+        EmergencyStopped {
+            guardian: Some(caller),
+        }
+        .publish(env);
     }
 
     fn unpause(env: &Env, _caller: Address) {
@@ -132,3 +491,44 @@ fn get_verifier(env: &Env) -> Address {
         None => panic_with_error!(env, EmergencyStopError::VerifierNotSet),
     }
 }
+
+/// Returns the guardian rotation delay in ledgers.
+fn guardian_rotation_delay(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::GuardianRotationDelay)
+        .unwrap_or(DEFAULT_GUARDIAN_ROTATION_DELAY)
+}
+
+/// Returns the pending guardian rotation, panicking with
+/// [`EmergencyStopError::NoPendingGuardian`] if there is none.
+fn read_pending_guardian(env: &Env) -> PendingGuardian {
+    match RiscZeroVerifierEmergencyStop::pending_guardian(env.clone()) {
+        Some(pending) => pending,
+        None => panic_with_error!(env, EmergencyStopError::NoPendingGuardian),
+    }
+}
+
+/// Returns the deadline of the ongoing temporary pause, or `None` if there is none.
+fn paused_until(env: &Env) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get::<_, u32>(&DataKey::PausedUntil)
+        .filter(|until_ledger| env.ledger().sequence() < *until_ledger)
+}
+
+/// Panics with [`EmergencyStopError::TemporarilyPaused`] during a temporary pause.
+fn ensure_not_temporarily_paused(env: &Env) {
+    if paused_until(env).is_some() {
+        panic_with_error!(env, EmergencyStopError::TemporarilyPaused);
+    }
+}
+
+/// Migrates storage from schema `version` to `version + 1`.
+fn migrate_from(_env: &Env, version: u32) -> Result<(), VerifierError> {
+    match version {
+        // Schema 1 only records the version; unversioned deployments share its layout.
+        0 => Ok(()),
+        _ => Err(VerifierError::UnsupportedSchemaVersion),
+    }
+}
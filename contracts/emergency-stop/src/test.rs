@@ -1,11 +1,20 @@
 extern crate std;
 
-use risc0_interface::{Receipt, RiscZeroVerifierInterface, VerifierError};
+use mock_verifier::{RiscZeroMockVerifier, RiscZeroMockVerifierClient};
+use risc0_interface::{
+    Receipt, RiscZeroVerifierInterface, VerifierError, alerts, roles::GUARDIAN_ROLE, upgrade,
+};
+use risc0_router::RiscZeroVerifierRouter;
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, contract, contractimpl, contracttype, testutils::Address as _,
+    Address, Bytes, BytesN, Env, Event, Vec, contract, contractimpl, contracttype,
+    testutils::{Address as _, Events as _, Ledger as _},
 };
 
-use crate::{RiscZeroVerifierEmergencyStop, RiscZeroVerifierEmergencyStopClient};
+use crate::{
+    EmergencyStopError, EmergencyStopped, GuardianRotated, GuardianRotationCancelled,
+    GuardianRotationDelaySet, GuardianRotationStarted, PendingGuardian,
+    RiscZeroVerifierEmergencyStop, RiscZeroVerifierEmergencyStopClient, TemporaryPause,
+};
 
 #[contract]
 struct MockVerifier;
@@ -17,6 +26,14 @@ enum MockKey {
 
 #[contractimpl]
 impl MockVerifier {
+    pub fn selector(env: Env) -> BytesN<4> {
+        BytesN::from_array(&env, &[0xFF, 0, 0, 1])
+    }
+
+    pub fn post_state_digest(env: Env) -> BytesN<32> {
+        BytesN::from_array(&env, &[0x22; 32])
+    }
+
     pub fn integrity_called(env: Env) -> bool {
         env.storage()
             .instance()
@@ -25,7 +42,7 @@ impl MockVerifier {
     }
 }
 
-#[contractimpl]
+#[contractimpl(contracttrait)]
 impl RiscZeroVerifierInterface for MockVerifier {
     type Proof = Bytes;
 
@@ -44,6 +61,10 @@ impl RiscZeroVerifierInterface for MockVerifier {
             .set(&MockKey::IntegrityCalled, &true);
         Ok(())
     }
+
+    fn verify_many(_env: Env, _seal: Bytes, _claims: Vec<BytesN<32>>) -> Result<(), VerifierError> {
+        Ok(())
+    }
 }
 
 fn setup() -> (
@@ -53,12 +74,16 @@ fn setup() -> (
     MockVerifierClient<'static>,
 ) {
     let env = Env::default();
-    let owner = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
     let verifier_id = env.register(MockVerifier, ());
     let verifier_client = MockVerifierClient::new(&env, &verifier_id);
-    let estop_id = env.register(RiscZeroVerifierEmergencyStop, (verifier_id, owner.clone()));
+    let estop_id = env.register(
+        RiscZeroVerifierEmergencyStop,
+        (verifier_id, admin, guardian.clone()),
+    );
     let estop_client = RiscZeroVerifierEmergencyStopClient::new(&env, &estop_id);
-    (env, owner, estop_client, verifier_client)
+    (env, guardian, estop_client, verifier_client)
 }
 
 fn test_inputs(env: &Env) -> (Bytes, BytesN<32>, BytesN<32>) {
@@ -70,44 +95,244 @@ fn test_inputs(env: &Env) -> (Bytes, BytesN<32>, BytesN<32>) {
 
 #[test]
 fn forwards_verify_when_unpaused() {
-    let (env, _owner, client, _verifier_client) = setup();
+    let (env, _guardian, client, _verifier_client) = setup();
     let (seal, image_id, journal) = test_inputs(&env);
 
     assert_eq!(client.verify(&seal, &image_id, &journal), ());
 }
 
+#[test]
+fn selector_forwards_to_verifier() {
+    let (env, _guardian, client, _verifier_client) = setup();
+
+    assert_eq!(
+        client.selector(),
+        BytesN::from_array(&env, &[0xFF, 0, 0, 1])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn selector_rejects_when_paused() {
+    let (env, guardian, client, _verifier_client) = setup();
+
+    env.mock_all_auths();
+    client.estop(&guardian);
+    client.selector();
+}
+
+#[test]
+fn post_state_digest_forwards_to_verifier_when_paused() {
+    let (env, guardian, client, _verifier_client) = setup();
+
+    env.mock_all_auths();
+    client.estop(&guardian);
+    assert_eq!(
+        client.post_state_digest(),
+        BytesN::from_array(&env, &[0x22; 32])
+    );
+}
+
+#[test]
+fn constructor_grants_guardian_role() {
+    let (_env, guardian, client, _verifier_client) = setup();
+
+    assert!(client.has_role(&guardian, &GUARDIAN_ROLE).is_some());
+    assert!(client.get_admin().is_some());
+}
+
 #[test]
 fn estop_sets_paused() {
-    let (env, _owner, client, _verifier_client) = setup();
+    let (env, guardian, client, _verifier_client) = setup();
+
+    env.mock_all_auths();
+    client.estop(&guardian);
+    let event = EmergencyStopped {
+        guardian: Some(guardian),
+    };
+    assert!(alerts::is_alert(
+        &env,
+        &event.topics(&env),
+        alerts::ESTOP_ALERT
+    ));
+    assert!(
+        env.events()
+            .all()
+            .events()
+            .contains(&event.to_xdr(&env, &client.address))
+    );
+
+    assert!(client.paused());
+}
+
+#[test]
+#[should_panic]
+fn estop_rejects_missing_guardian_auth() {
+    let (_env, guardian, client, _verifier_client) = setup();
+    client.estop(&guardian);
+}
+
+#[test]
+#[should_panic]
+fn estop_rejects_non_guardian() {
+    let (env, _guardian, client, _verifier_client) = setup();
+    let admin = client.get_admin().unwrap();
+
+    env.mock_all_auths();
+    client.estop(&admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #305)")]
+fn grant_role_rejects_guardian_role() {
+    let (env, _guardian, client, _verifier_client) = setup();
+    let admin = client.get_admin().unwrap();
+
+    env.mock_all_auths();
+    client.grant_role(&Address::generate(&env), &GUARDIAN_ROLE, &admin);
+}
 
+#[test]
+fn rotate_guardian_activates_after_delay() {
+    let (env, guardian, client, _verifier_client) = setup();
+    let new_guardian = Address::generate(&env);
+    let delay = client.guardian_rotation_delay();
     env.mock_all_auths();
-    client.estop();
 
+    client.rotate_guardian(&new_guardian);
+    let active_ledger = env.ledger().sequence() + delay;
+    let event = GuardianRotationStarted {
+        guardian: new_guardian.clone(),
+        active_ledger,
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+    assert_eq!(
+        client.pending_guardian(),
+        Some(PendingGuardian {
+            guardian: new_guardian.clone(),
+            active_ledger,
+        })
+    );
+
+    env.ledger().set_sequence_number(active_ledger - 1);
+    let result = client.try_complete_guardian_rotation();
+    assert_eq!(
+        result,
+        Err(Ok(EmergencyStopError::GuardianRotationNotReady.into()))
+    );
+
+    env.ledger().set_sequence_number(active_ledger);
+    client.complete_guardian_rotation();
+    let event = GuardianRotated {
+        guardian: new_guardian.clone(),
+    };
+    assert!(
+        env.events()
+            .all()
+            .events()
+            .contains(&event.to_xdr(&env, &client.address))
+    );
+    assert!(client.has_role(&new_guardian, &GUARDIAN_ROLE).is_some());
+    assert!(client.has_role(&guardian, &GUARDIAN_ROLE).is_none());
+    assert_eq!(client.get_role_member_count(&GUARDIAN_ROLE), 1);
+    assert_eq!(client.pending_guardian(), None);
+
+    client.estop(&new_guardian);
     assert!(client.paused());
 }
 
 #[test]
 #[should_panic]
-fn estop_rejects_non_owner() {
-    let (_env, _owner, client, _verifier_client) = setup();
-    client.estop();
+fn rotate_guardian_requires_admin_auth() {
+    let (env, _guardian, client, _verifier_client) = setup();
+    client.rotate_guardian(&Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #306)")]
+fn complete_guardian_rotation_requires_pending_rotation() {
+    let (_env, _guardian, client, _verifier_client) = setup();
+    client.complete_guardian_rotation();
+}
+
+#[test]
+fn guardian_cancels_rotation() {
+    let (env, guardian, client, _verifier_client) = setup();
+    let new_guardian = Address::generate(&env);
+    env.mock_all_auths();
+    client.rotate_guardian(&new_guardian);
+
+    client.cancel_guardian_rotation(&guardian);
+
+    let event = GuardianRotationCancelled {
+        guardian: new_guardian,
+        cancelled_by: guardian.clone(),
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+    assert_eq!(client.pending_guardian(), None);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + client.guardian_rotation_delay());
+    let result = client.try_complete_guardian_rotation();
+    assert_eq!(
+        result,
+        Err(Ok(EmergencyStopError::NoPendingGuardian.into()))
+    );
+    assert!(client.has_role(&guardian, &GUARDIAN_ROLE).is_some());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn cancel_guardian_rotation_rejects_outsider() {
+    let (env, _guardian, client, _verifier_client) = setup();
+    env.mock_all_auths();
+    client.rotate_guardian(&Address::generate(&env));
+
+    client.cancel_guardian_rotation(&Address::generate(&env));
+}
+
+#[test]
+fn set_guardian_rotation_delay_only_increases() {
+    let (env, _guardian, client, _verifier_client) = setup();
+    let delay = client.guardian_rotation_delay();
+    env.mock_all_auths();
+
+    client.set_guardian_rotation_delay(&(delay * 2));
+    let event = GuardianRotationDelaySet { ledgers: delay * 2 };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+    assert_eq!(client.guardian_rotation_delay(), delay * 2);
+
+    let result = client.try_set_guardian_rotation_delay(&delay);
+    assert_eq!(
+        result,
+        Err(Ok(EmergencyStopError::RotationDelayDecrease.into()))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn pause_rejects_non_guardian() {
+    let (env, _guardian, client, _verifier_client) = setup();
+
+    env.mock_all_auths();
+    client.pause(&Address::generate(&env));
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #1000)")]
 fn verify_rejects_when_paused() {
-    let (env, _owner, client, _verifier_client) = setup();
+    let (env, guardian, client, _verifier_client) = setup();
     let (seal, image_id, journal) = test_inputs(&env);
 
     env.mock_all_auths();
-    client.estop();
+    client.estop(&guardian);
     client.verify(&seal, &image_id, &journal);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #1001)")]
+#[should_panic(expected = "Error(Contract, #302)")]
 fn estop_with_receipt_requires_zero_digest() {
-    let (env, _owner, client, _verifier_client) = setup();
+    let (env, _guardian, client, _verifier_client) = setup();
     let receipt = Receipt {
         seal: Bytes::from_slice(&env, &[0xAA]),
         claim_digest: BytesN::from_array(&env, &[1u8; 32]),
@@ -118,23 +343,271 @@ fn estop_with_receipt_requires_zero_digest() {
 
 #[test]
 fn estop_with_receipt_pauses_and_calls_verifier() {
-    let (env, _owner, client, verifier_client) = setup();
+    let (env, _guardian, client, verifier_client) = setup();
     let receipt = Receipt {
         seal: Bytes::from_slice(&env, &[0xBB]),
         claim_digest: BytesN::from_array(&env, &[0u8; 32]),
     };
 
     client.estop_with_receipt(&receipt);
+    let event = EmergencyStopped { guardian: None };
+    assert!(
+        env.events()
+            .all()
+            .events()
+            .contains(&event.to_xdr(&env, &client.address))
+    );
 
     assert!(client.paused());
     assert!(verifier_client.integrity_called());
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #1002)")]
+#[should_panic(expected = "Error(Contract, #303)")]
 fn unpause_always_panics() {
-    let (env, owner, client, _verifier_client) = setup();
+    let (env, guardian, client, _verifier_client) = setup();
+
+    env.mock_all_auths();
+    client.unpause(&guardian);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #304)")]
+fn pause_for_blocks_verification() {
+    let (env, guardian, client, _verifier_client) = setup();
+    let (seal, image_id, journal) = test_inputs(&env);
+
+    env.mock_all_auths();
+    client.pause_for(&100, &guardian);
+    client.verify(&seal, &image_id, &journal);
+}
+
+#[test]
+fn pause_for_expires_automatically() {
+    let (env, guardian, client, _verifier_client) = setup();
+    let (seal, image_id, journal) = test_inputs(&env);
+    let start = env.ledger().sequence();
 
     env.mock_all_auths();
-    client.unpause(&owner);
+    client.pause_for(&100, &guardian);
+    let event = TemporaryPause {
+        until_ledger: start + 100,
+    };
+    assert!(alerts::is_alert(
+        &env,
+        &event.topics(&env),
+        alerts::PAUSE_ALERT
+    ));
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+    assert!(client.paused());
+    assert_eq!(client.paused_until(), Some(start + 100));
+
+    env.ledger().set_sequence_number(start + 99);
+    assert!(client.paused());
+
+    env.ledger().set_sequence_number(start + 100);
+    assert!(!client.paused());
+    assert_eq!(client.paused_until(), None);
+    assert_eq!(client.verify(&seal, &image_id, &journal), ());
+}
+
+#[test]
+fn pause_for_never_shortens_pause() {
+    let (env, guardian, client, _verifier_client) = setup();
+    let start = env.ledger().sequence();
+
+    env.mock_all_auths();
+    client.pause_for(&100, &guardian);
+    client.pause_for(&10, &guardian);
+
+    assert_eq!(client.paused_until(), Some(start + 100));
+}
+
+#[test]
+#[should_panic]
+fn pause_for_rejects_non_guardian() {
+    let (env, _guardian, client, _verifier_client) = setup();
+
+    env.mock_all_auths();
+    client.pause_for(&100, &Address::generate(&env));
+}
+
+#[test]
+fn estop_with_receipt_during_temporary_pause() {
+    let (env, guardian, client, verifier_client) = setup();
+    let receipt = Receipt {
+        seal: Bytes::from_slice(&env, &[0xBB]),
+        claim_digest: BytesN::from_array(&env, &[0u8; 32]),
+    };
+
+    env.mock_all_auths();
+    client.pause_for(&100, &guardian);
+    client.estop_with_receipt(&receipt);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 100);
+    assert!(client.paused());
+    assert!(verifier_client.integrity_called());
+}
+
+#[test]
+fn set_verifier_replaces_inner_verifier() {
+    let (env, _guardian, client, _verifier_client) = setup();
+    let verifier = env.register(MockVerifier, ());
+
+    env.mock_all_auths();
+    client.set_verifier(&verifier);
+
+    assert_eq!(client.get_verifier(), verifier);
+}
+
+#[test]
+#[should_panic]
+fn set_verifier_requires_admin_auth() {
+    let (env, _guardian, client, _verifier_client) = setup();
+
+    client.set_verifier(&env.register(MockVerifier, ()));
+}
+
+#[test]
+fn chains_in_front_of_router() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, operator, guardian) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+
+    let selector = BytesN::from_array(&env, &[0xFF, 0, 0, 2]);
+    let verifier = env.register(RiscZeroMockVerifier, (selector.clone(),));
+    let router = env.register(
+        RiscZeroVerifierRouter,
+        (
+            admin.clone(),
+            operator,
+            guardian.clone(),
+            soroban_sdk::vec![&env, (selector, verifier.clone())],
+        ),
+    );
+
+    let estop_id = env.register(
+        RiscZeroVerifierEmergencyStop,
+        (router, admin, guardian.clone()),
+    );
+    let client = RiscZeroVerifierEmergencyStopClient::new(&env, &estop_id);
+
+    let image_id = BytesN::from_array(&env, &[7u8; 32]);
+    let journal = BytesN::from_array(&env, &[9u8; 32]);
+    let receipt = RiscZeroMockVerifierClient::new(&env, &verifier).mock_prove(&image_id, &journal);
+
+    assert_eq!(client.verify(&receipt.seal, &image_id, &journal), ());
+    assert_eq!(client.verify_integrity(&receipt), ());
+
+    // The router reports errors through the wrapper.
+    let foreign = Bytes::from_slice(&env, &[0xFF, 0, 0, 3]);
+    assert!(client.try_verify(&foreign, &image_id, &journal).is_err());
+
+    client.estop(&guardian);
+    assert!(client.try_verify_integrity(&receipt).is_err());
+}
+
+/// The router passes the zero claim digest through, so a proof of exploit can trip an
+/// emergency stop in front of it.
+#[test]
+fn estop_with_receipt_in_front_of_router() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, operator, guardian) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+
+    let selector = BytesN::from_array(&env, &[0xFF, 0, 0, 2]);
+    let verifier = env.register(RiscZeroMockVerifier, (selector.clone(),));
+    let router = env.register(
+        RiscZeroVerifierRouter,
+        (
+            admin.clone(),
+            operator,
+            guardian.clone(),
+            soroban_sdk::vec![&env, (selector, verifier.clone())],
+        ),
+    );
+
+    let estop_id = env.register(RiscZeroVerifierEmergencyStop, (router, admin, guardian));
+    let client = RiscZeroVerifierEmergencyStopClient::new(&env, &estop_id);
+
+    let receipt = RiscZeroMockVerifierClient::new(&env, &verifier)
+        .mock_prove_claim(&BytesN::from_array(&env, &[0u8; 32]));
+    client.estop_with_receipt(&receipt);
+
+    assert!(client.paused());
+}
+
+#[test]
+fn constructor_sets_contract_version() {
+    let (_env, _guardian, client, _verifier_client) = setup();
+
+    assert_eq!(client.contract_version(), 1);
+}
+
+#[test]
+fn upgrade_rejects_downgrade() {
+    let (env, _guardian, client, _verifier_client) = setup();
+
+    env.mock_all_auths();
+    let result = client.try_upgrade(&BytesN::from_array(&env, &[0xAB; 32]), &0);
+
+    assert_eq!(result, Err(Ok(VerifierError::VersionNotIncreasing)));
+    assert_eq!(client.contract_version(), 1);
+}
+
+#[test]
+#[should_panic]
+fn upgrade_requires_admin_auth() {
+    let (env, _guardian, client, _verifier_client) = setup();
+
+    client.upgrade(&BytesN::from_array(&env, &[0xAB; 32]), &2);
+}
+
+#[test]
+fn migrate_unversioned_storage() {
+    let (env, _guardian, client, _verifier_client) = setup();
+    assert_eq!(client.schema_version(), 1);
+    env.as_contract(&client.address, || upgrade::set_schema_version(&env, 0));
+
+    env.mock_all_auths();
+    client.migrate();
+
+    assert_eq!(client.schema_version(), 1);
+}
+
+#[test]
+#[should_panic]
+fn migrate_requires_admin_auth() {
+    let (_env, _guardian, client, _verifier_client) = setup();
+
+    client.migrate();
+}
+
+#[test]
+fn error_codes_are_stable() {
+    // Part of the public ABI, see the error registry in `risc0-interface`.
+    assert_eq!(EmergencyStopError::Unauthorized as u32, 300);
+    assert_eq!(EmergencyStopError::VerifierNotSet as u32, 301);
+    assert_eq!(EmergencyStopError::InvalidProofOfExploit as u32, 302);
+    assert_eq!(EmergencyStopError::UnpauseNotAllowed as u32, 303);
+    assert_eq!(EmergencyStopError::TemporarilyPaused as u32, 304);
+    assert_eq!(EmergencyStopError::GuardianRotationRequired as u32, 305);
+    assert_eq!(EmergencyStopError::NoPendingGuardian as u32, 306);
+    assert_eq!(EmergencyStopError::GuardianRotationNotReady as u32, 307);
+    assert_eq!(EmergencyStopError::RotationDelayDecrease as u32, 308);
+
+    // Codes used before the registry, which must never be reassigned.
+    for code in [1, 5, 1001, 1002] {
+        let error = soroban_sdk::Error::from_contract_error(code);
+        assert!(EmergencyStopError::try_from(error).is_err(), "code {code}");
+    }
 }
@@ -0,0 +1,124 @@
+//! Conversions between the Soroban encoding of BN254 points and arkworks points (`ark`
+//! feature).
+//!
+//! Soroban encodes points uncompressed as big-endian coordinates, `x || y` in G1 and
+//! `x.c1 || x.c0 || y.c1 || y.c0` in G2, with all zeros for the point at infinity. The
+//! compressed encoding keeps only `x` in the same byte order, and uses the two spare high bits
+//! of the first byte as flags: `0x80` when `y` is the larger of `y` and `-y`, `0x40` for the
+//! point at infinity. It is the arkworks compressed serialization, in big-endian.
+
+use ark_bn254::{Fq, Fq2, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::{
+    bn254::{BASE_FIELD_MODULUS, FIELD_ELEMENT_SIZE},
+    types::{G1_SIZE, G2_SIZE},
+};
+
+/// Size of a compressed G1 point.
+pub(crate) const G1_COMPRESSED_SIZE: usize = G1_SIZE / 2;
+/// Size of a compressed G2 point.
+pub(crate) const G2_COMPRESSED_SIZE: usize = G2_SIZE / 2;
+
+/// Decodes a G1 point, panicking if it is invalid like the host does.
+pub(crate) fn g1(bytes: &[u8; G1_SIZE]) -> G1Affine {
+    if bytes.iter().all(|&byte| byte == 0) {
+        return G1Affine::zero();
+    }
+    let (x, y) = bytes.split_at(FIELD_ELEMENT_SIZE);
+    G1Affine::new(fq(x), fq(y))
+}
+
+/// Decodes a G2 point, panicking if it is invalid like the host does.
+pub(crate) fn g2(bytes: &[u8; G2_SIZE]) -> G2Affine {
+    if bytes.iter().all(|&byte| byte == 0) {
+        return G2Affine::zero();
+    }
+    let (x, y) = bytes.split_at(2 * FIELD_ELEMENT_SIZE);
+    G2Affine::new(fq2(x), fq2(y))
+}
+
+/// Encodes a G1 point.
+pub(crate) fn g1_to_bytes(point: &G1Affine) -> [u8; G1_SIZE] {
+    let mut bytes = [0u8; G1_SIZE];
+    if let Some((x, y)) = point.xy() {
+        let (x_bytes, y_bytes) = bytes.split_at_mut(FIELD_ELEMENT_SIZE);
+        x_bytes.copy_from_slice(&fq_to_bytes(x));
+        y_bytes.copy_from_slice(&fq_to_bytes(y));
+    }
+    bytes
+}
+
+/// Encodes a G2 point.
+pub(crate) fn g2_to_bytes(point: &G2Affine) -> [u8; G2_SIZE] {
+    let mut bytes = [0u8; G2_SIZE];
+    if let Some((x, y)) = point.xy() {
+        for (chunk, coordinate) in bytes
+            .chunks_exact_mut(FIELD_ELEMENT_SIZE)
+            .zip([x.c1, x.c0, y.c1, y.c0])
+        {
+            chunk.copy_from_slice(&fq_to_bytes(coordinate));
+        }
+    }
+    bytes
+}
+
+/// Compresses a G1 point.
+#[allow(clippy::expect_used, reason = "the buffer is sized for it")]
+pub(crate) fn g1_compress(point: &G1Affine) -> [u8; G1_COMPRESSED_SIZE] {
+    let mut bytes = [0u8; G1_COMPRESSED_SIZE];
+    point
+        .serialize_compressed(bytes.as_mut_slice())
+        .expect("buffer fits a compressed G1 point");
+    bytes.reverse();
+    bytes
+}
+
+/// Compresses a G2 point.
+#[allow(clippy::expect_used, reason = "the buffer is sized for it")]
+pub(crate) fn g2_compress(point: &G2Affine) -> [u8; G2_COMPRESSED_SIZE] {
+    let mut bytes = [0u8; G2_COMPRESSED_SIZE];
+    point
+        .serialize_compressed(bytes.as_mut_slice())
+        .expect("buffer fits a compressed G2 point");
+    bytes.reverse();
+    bytes
+}
+
+/// Decompresses a G1 point, or returns `None` if it is invalid.
+pub(crate) fn g1_decompress(bytes: &[u8; G1_COMPRESSED_SIZE]) -> Option<G1Affine> {
+    let mut bytes = *bytes;
+    bytes.reverse();
+    G1Affine::deserialize_compressed(bytes.as_slice()).ok()
+}
+
+/// Decompresses a G2 point, or returns `None` if it is invalid or outside the subgroup.
+pub(crate) fn g2_decompress(bytes: &[u8; G2_COMPRESSED_SIZE]) -> Option<G2Affine> {
+    let mut bytes = *bytes;
+    bytes.reverse();
+    G2Affine::deserialize_compressed(bytes.as_slice()).ok()
+}
+
+/// Decodes `c1 || c0`.
+fn fq2(bytes: &[u8]) -> Fq2 {
+    let (c1, c0) = bytes.split_at(FIELD_ELEMENT_SIZE);
+    Fq2::new(fq(c0), fq(c1))
+}
+
+/// Decodes a big-endian base field element, which must be below the modulus.
+fn fq(bytes: &[u8]) -> Fq {
+    assert!(
+        bytes < BASE_FIELD_MODULUS.as_slice(),
+        "coordinate is not a canonical base field element"
+    );
+    Fq::from_be_bytes_mod_order(bytes)
+}
+
+/// Encodes a base field element in big-endian.
+fn fq_to_bytes(element: Fq) -> [u8; FIELD_ELEMENT_SIZE] {
+    let mut bytes = [0u8; FIELD_ELEMENT_SIZE];
+    bytes.copy_from_slice(&element.into_bigint().to_bytes_be());
+    bytes
+}
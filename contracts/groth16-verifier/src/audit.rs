@@ -0,0 +1,88 @@
+//! Invariant assertions of the audit build (`audit` feature).
+//!
+//! The lean build relies on guarantees of the Soroban host and on invariants of this crate that
+//! hold by construction:
+//!
+//! - The host rejects non-canonical field encodings, points off the curve, and G2 points outside
+//!   the prime-order subgroup when deserializing proof points (G1 has cofactor 1).
+//! - The public signals are built by the contract from two 128-bit halves per digest, so they
+//!   are always below the scalar field modulus.
+//!
+//! The audit build re-checks these in the contract, for testnet deployments where an upstream
+//! regression should surface as a contract error or assertion rather than go unnoticed. Proof
+//! checks fail with [`VerifierError::MalformedSeal`]; internal invariants panic. G2 subgroup
+//! membership cannot be re-checked, as Soroban exposes no G2 arithmetic.
+
+use soroban_sdk::{
+    BytesN, Env,
+    crypto::bn254::{Bn254G1Affine as G1Affine, Fr},
+};
+
+use risc0_interface::VerifierError;
+
+use crate::{
+    FrExt, PUBLIC_SIGNALS,
+    bn254::{BASE_FIELD_MODULUS, FIELD_ELEMENT_SIZE},
+    types::Groth16Proof,
+};
+
+/// `r - 1` for the BN254 scalar field modulus `r`, in big-endian bytes.
+const SCALAR_FIELD_MODULUS_MINUS_ONE: [u8; FIELD_ELEMENT_SIZE] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x00,
+];
+
+/// Number of leading public signals that are 128-bit halves of the control root and claim
+/// digest; the last one is the BN254 control id.
+const DIGEST_HALF_SIGNALS: usize = 4;
+
+/// Checks that every coordinate of the proof is canonically encoded and that its G1 points are
+/// in the prime-order subgroup.
+///
+/// # Errors
+///
+/// - [`VerifierError::MalformedSeal`] - A coordinate is not below the base field modulus, or a
+///   G1 point is outside the subgroup
+pub(crate) fn check_proof(env: &Env, proof: &Groth16Proof) -> Result<(), VerifierError> {
+    let a = proof.a.to_array();
+    let b = proof.b.to_array();
+    let c = proof.c.to_array();
+    let canonical = a
+        .chunks_exact(FIELD_ELEMENT_SIZE)
+        .chain(b.chunks_exact(FIELD_ELEMENT_SIZE))
+        .chain(c.chunks_exact(FIELD_ELEMENT_SIZE))
+        .all(|coordinate| coordinate < &BASE_FIELD_MODULUS[..]);
+    if !canonical {
+        return Err(VerifierError::MalformedSeal);
+    }
+
+    if !in_subgroup(env, &proof.a) || !in_subgroup(env, &proof.c) {
+        return Err(VerifierError::MalformedSeal);
+    }
+    Ok(())
+}
+
+/// Returns whether `[r]P` is the identity, checked as `[r - 1]P == -P`.
+fn in_subgroup(env: &Env, point: &G1Affine) -> bool {
+    let scalar = Fr::from_bytes(BytesN::from_array(env, &SCALAR_FIELD_MODULUS_MINUS_ONE));
+    env.crypto().bn254().g1_mul(point, &scalar) == -point
+}
+
+/// Asserts the invariants of the public signals built by the contract.
+///
+/// # Panics
+///
+/// Panics if a digest half does not fit in 128 bits, or if a signal is not canonical.
+pub(crate) fn assert_public_signals(pub_signals: &[Fr; PUBLIC_SIGNALS]) {
+    for (index, signal) in pub_signals.iter().enumerate() {
+        let bytes = signal.to_bytes();
+        assert!(Fr::is_canonical(&bytes), "public signal not canonical");
+
+        if index < DIGEST_HALF_SIGNALS {
+            assert!(
+                bytes.to_array()[..16].iter().all(|&byte| byte == 0),
+                "digest half exceeds 128 bits"
+            );
+        }
+    }
+}
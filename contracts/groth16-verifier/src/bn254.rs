@@ -0,0 +1,55 @@
+//! BN254 curve parameters.
+//!
+//! Application contracts composing public inputs for
+//! [`verify_proof`](crate::RiscZeroGroth16Verifier::verify_proof) can validate them against
+//! these constants instead of embedding their own copies. Field elements are encoded as 32-byte
+//! big-endian integers, matching [`Fr::from_bytes`](soroban_sdk::crypto::bn254::Fr::from_bytes)
+//! and the Soroban point encoding.
+
+use soroban_sdk::{BytesN, Env};
+
+/// The size of a serialized field element in bytes.
+pub const FIELD_ELEMENT_SIZE: usize = 32;
+
+/// The BN254 scalar field modulus `r`, i.e. the order of the G1 and G2 groups.
+pub const SCALAR_FIELD_MODULUS: [u8; FIELD_ELEMENT_SIZE] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// The BN254 base field modulus `p`, over which the curve coordinates are defined.
+pub const BASE_FIELD_MODULUS: [u8; FIELD_ELEMENT_SIZE] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// The generator `(1, 2)` of G1, serialized as `x || y`.
+pub const G1_GENERATOR: [u8; 2 * FIELD_ELEMENT_SIZE] = {
+    let mut generator = [0u8; 2 * FIELD_ELEMENT_SIZE];
+    generator[FIELD_ELEMENT_SIZE - 1] = 1;
+    generator[2 * FIELD_ELEMENT_SIZE - 1] = 2;
+    generator
+};
+
+/// Returns [`SCALAR_FIELD_MODULUS`] as [`BytesN`].
+pub fn scalar_field_modulus(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &SCALAR_FIELD_MODULUS)
+}
+
+/// Returns [`BASE_FIELD_MODULUS`] as [`BytesN`].
+pub fn base_field_modulus(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &BASE_FIELD_MODULUS)
+}
+
+/// Returns whether `bytes` encodes a scalar below [`SCALAR_FIELD_MODULUS`].
+///
+/// [`Fr::from_bytes`](soroban_sdk::crypto::bn254::Fr::from_bytes) silently reduces larger
+/// values, so two distinct encodings would otherwise denote the same public input.
+pub fn is_valid_scalar(bytes: &BytesN<32>) -> bool {
+    bytes.to_array() < SCALAR_FIELD_MODULUS
+}
+
+/// Returns whether `bytes` encodes a base field element below [`BASE_FIELD_MODULUS`].
+pub fn is_valid_base_field_element(bytes: &BytesN<32>) -> bool {
+    bytes.to_array() < BASE_FIELD_MODULUS
+}
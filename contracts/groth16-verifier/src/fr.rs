@@ -0,0 +1,221 @@
+//! Helpers for building BN254 scalar field elements ([`Fr`]) from common encodings.
+//!
+//! Public signals of a Groth16 proof are [`Fr`] elements. The SDK only constructs them from a
+//! big-endian [`BytesN<32>`] or a [`U256`](soroban_sdk::U256) and silently reduces values that
+//! are not below the scalar field modulus, so callers otherwise hand-pack byte arrays and
+//! cannot tell when a signal was changed by the reduction.
+//!
+//! The SDK has no arithmetic on [`Fr`] either, so [`FrExt`] also provides the modular
+//! operations needed to derive public signals on-chain. Addition and subtraction use the host
+//! [`U256`] operations; a product of two elements overflows [`U256`], so multiplication is a
+//! Montgomery multiplication on 64-bit limbs.
+
+use soroban_sdk::{Bytes, BytesN, Env, U256, crypto::bn254::Fr};
+
+use risc0_interface::VerifierError;
+
+use crate::bn254::{self, FIELD_ELEMENT_SIZE as FR_SIZE, SCALAR_FIELD_MODULUS};
+
+/// Extension methods for the SDK [`Fr`] type.
+pub trait FrExt: Sized {
+    /// The BN254 scalar field modulus `r`, see [`bn254::SCALAR_FIELD_MODULUS`].
+    const MODULUS: [u8; FR_SIZE] = SCALAR_FIELD_MODULUS;
+
+    /// Creates a field element from a `u64`.
+    fn from_u64(env: &Env, value: u64) -> Self;
+
+    /// Parses a field element from 64 big-endian hex digits, with an optional `0x` prefix.
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::MalformedPublicInputs`] - The string is not 32 bytes of hex, or the
+    ///   value is not below the scalar field modulus
+    fn from_hex(env: &Env, hex: &str) -> Result<Self, VerifierError>;
+
+    /// Creates a field element from big-endian bytes, rejecting values that
+    /// [`Fr::from_bytes`] would reduce.
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::MalformedPublicInputs`] - The value is not below the scalar field
+    ///   modulus
+    fn try_from_bytes(bytes: BytesN<32>) -> Result<Self, VerifierError>;
+
+    /// Returns whether `bytes` encodes a value below the scalar field modulus, see
+    /// [`bn254::is_valid_scalar`].
+    fn is_canonical(bytes: &BytesN<32>) -> bool;
+
+    /// Returns `self + other mod r`.
+    fn add_mod(&self, other: &Self) -> Self;
+
+    /// Returns `self - other mod r`.
+    fn sub_mod(&self, other: &Self) -> Self;
+
+    /// Returns `-self mod r`.
+    fn neg_mod(&self) -> Self;
+
+    /// Returns `self * other mod r`.
+    fn mul_mod(&self, other: &Self) -> Self;
+
+    /// Converts the element to its arkworks representation, for host-side tooling.
+    #[cfg(feature = "ark")]
+    fn to_ark(&self) -> ark_bn254::Fr;
+}
+
+impl FrExt for Fr {
+    #[allow(clippy::indexing_slicing, reason = "the buffer is longer than a u64")]
+    fn from_u64(env: &Env, value: u64) -> Self {
+        let mut bytes = [0u8; FR_SIZE];
+        bytes[FR_SIZE - size_of::<u64>()..].copy_from_slice(&value.to_be_bytes());
+        Fr::from_bytes(BytesN::from_array(env, &bytes))
+    }
+
+    fn from_hex(env: &Env, hex: &str) -> Result<Self, VerifierError> {
+        let hex = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+        if hex.len() != FR_SIZE * 2 {
+            return Err(VerifierError::MalformedPublicInputs);
+        }
+
+        let mut bytes = [0u8; FR_SIZE];
+        for (byte, &[high, low]) in bytes.iter_mut().zip(hex.as_chunks::<2>().0) {
+            *byte = (hex_digit(high)? << 4) | hex_digit(low)?;
+        }
+        Self::try_from_bytes(BytesN::from_array(env, &bytes))
+    }
+
+    fn try_from_bytes(bytes: BytesN<32>) -> Result<Self, VerifierError> {
+        if !Self::is_canonical(&bytes) {
+            return Err(VerifierError::MalformedPublicInputs);
+        }
+        Ok(Fr::from_bytes(bytes))
+    }
+
+    fn is_canonical(bytes: &BytesN<32>) -> bool {
+        bn254::is_valid_scalar(bytes)
+    }
+
+    // Elements are below r < 2^254, so sums of two elements fit in a `U256`, and `From<U256>`
+    // reduces them.
+    fn add_mod(&self, other: &Self) -> Self {
+        Fr::from_u256(self.as_u256().add(other.as_u256()))
+    }
+
+    fn sub_mod(&self, other: &Self) -> Self {
+        self.add_mod(&other.neg_mod())
+    }
+
+    fn neg_mod(&self) -> Self {
+        let modulus =
+            U256::from_be_bytes(self.env(), &Bytes::from_array(self.env(), &Self::MODULUS));
+        Fr::from_u256(modulus.sub(self.as_u256()))
+    }
+
+    fn mul_mod(&self, other: &Self) -> Self {
+        let a = to_limbs(&self.to_bytes().to_array());
+        let b = to_limbs(&other.to_bytes().to_array());
+        // mont(mont(a, b), R^2) = a * b * R^-1 * R^2 * R^-1 = a * b
+        let product = montgomery_mul(&montgomery_mul(&a, &b), &R_SQUARED);
+        Fr::from_bytes(BytesN::from_array(self.env(), &from_limbs(&product)))
+    }
+
+    #[cfg(feature = "ark")]
+    fn to_ark(&self) -> ark_bn254::Fr {
+        use ark_ff::PrimeField;
+
+        ark_bn254::Fr::from_be_bytes_mod_order(&self.to_bytes().to_array())
+    }
+}
+
+/// Decodes a single hex digit.
+fn hex_digit(digit: u8) -> Result<u8, VerifierError> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(VerifierError::MalformedPublicInputs),
+    }
+}
+
+/// [`SCALAR_FIELD_MODULUS`] as little-endian 64-bit limbs.
+const MODULUS_LIMBS: [u64; 4] = [
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// `-r^-1 mod 2^64`.
+const MODULUS_INV: u64 = 0xc2e1f593efffffff;
+
+/// `R^2 mod r`, with the Montgomery radix `R = 2^256`, as little-endian 64-bit limbs.
+const R_SQUARED: [u64; 4] = [
+    0x1bb8e645ae216da7,
+    0x53fe3ab1e35c59e3,
+    0x8c49833d53bb8085,
+    0x0216d0b17f4e44a5,
+];
+
+/// Splits a big-endian integer into little-endian 64-bit limbs.
+fn to_limbs(bytes: &[u8; FR_SIZE]) -> [u64; 4] {
+    let mut limbs = [0u64; 4];
+    let (chunks, _) = bytes.as_chunks::<8>();
+    for (limb, chunk) in limbs.iter_mut().rev().zip(chunks) {
+        *limb = u64::from_be_bytes(*chunk);
+    }
+    limbs
+}
+
+/// Joins little-endian 64-bit limbs into a big-endian integer.
+fn from_limbs(limbs: &[u64; 4]) -> [u8; FR_SIZE] {
+    let mut bytes = [0u8; FR_SIZE];
+    for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs.iter().rev()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    bytes
+}
+
+/// Returns the Montgomery product `a * b * R^-1 mod r` of `a, b < r`.
+///
+/// Coarsely integrated operand scanning: each limb of `b` is multiplied in, then a multiple
+/// of `r` is added to clear the lowest limb, which is shifted out.
+#[allow(clippy::indexing_slicing, reason = "the limb indices are below 4")]
+fn montgomery_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut t = [0u64; 6];
+    for &b_i in b {
+        let mut carry = 0u64;
+        for (t_j, &a_j) in t.iter_mut().zip(a) {
+            (*t_j, carry) = mac(*t_j, a_j, b_i, carry);
+        }
+        (t[4], t[5]) = mac(t[4], 0, 0, carry);
+
+        let m = t[0].wrapping_mul(MODULUS_INV);
+        let (_, mut carry) = mac(t[0], m, MODULUS_LIMBS[0], 0);
+        for j in 1..4 {
+            (t[j - 1], carry) = mac(t[j], m, MODULUS_LIMBS[j], carry);
+        }
+        let (low, high) = mac(t[4], 0, 0, carry);
+        t[3] = low;
+        t[4] = t[5] + high;
+    }
+
+    // The result is below 2r: subtract r once if needed.
+    let result = [t[0], t[1], t[2], t[3]];
+    if t[4] == 0 && from_limbs(&result) < SCALAR_FIELD_MODULUS {
+        return result;
+    }
+    let mut borrow = 0u64;
+    let mut reduced = [0u64; 4];
+    for j in 0..4 {
+        let (difference, underflow_1) = result[j].overflowing_sub(MODULUS_LIMBS[j]);
+        let (difference, underflow_2) = difference.overflowing_sub(borrow);
+        reduced[j] = difference;
+        borrow = u64::from(underflow_1 || underflow_2);
+    }
+    reduced
+}
+
+/// Returns `(low, high)` of `t + a * b + carry`, which fits in 128 bits.
+fn mac(t: u64, a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let value = u128::from(t) + u128::from(a) * u128::from(b) + u128::from(carry);
+    (value as u64, (value >> 64) as u64)
+}
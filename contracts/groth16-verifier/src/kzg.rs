@@ -0,0 +1,63 @@
+//! KZG polynomial commitment openings over BN254.
+//!
+//! Under a trusted setup with secret `tau`, the commitment to a polynomial `p` is
+//! `C = [p(tau)]_1`, and the opening of `p` at `z` to `y = p(z)` is `pi = [q(tau)]_1` for the
+//! quotient `q(X) = (p(X) - y) / (X - z)`. The opening is valid if
+//! `e(C - [y]_1, [1]_2) == e(pi, [tau - z]_2)`.
+//!
+//! Soroban has no G2 arithmetic, so [`verify_opening`] moves `z` to the G1 side and checks
+//! `e(C - [y]_1 + z * pi, [1]_2) * e(-pi, [tau]_2) == 1`, with the same [`PairingBackend`] as
+//! Groth16 verification.
+
+use soroban_sdk::{
+    Env,
+    crypto::bn254::{Bn254G1Affine as G1Affine, Bn254G2Affine as G2Affine, Fr},
+    vec,
+};
+
+use crate::{bn254::G1_GENERATOR, pairing::PairingBackend};
+
+/// The G2 points of a KZG trusted setup needed to check openings.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KzgVerifierKey {
+    /// The G2 generator, `[1]_2`.
+    pub g2: G2Affine,
+    /// The setup secret in G2, `[tau]_2`.
+    pub tau_g2: G2Affine,
+}
+
+/// A claimed evaluation of a committed polynomial.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KzgOpening {
+    /// The evaluation point `z`.
+    pub point: Fr,
+    /// The claimed value `y = p(z)`.
+    pub value: Fr,
+    /// The opening proof `pi = [q(tau)]_1`.
+    pub proof: G1Affine,
+}
+
+/// Returns whether `opening` is a valid opening of `commitment` under `vk`.
+///
+/// Like [`PairingBackend::check_pairing`], may panic if a point is invalid.
+pub fn verify_opening(
+    env: &Env,
+    backend: &impl PairingBackend,
+    vk: &KzgVerifierKey,
+    commitment: &G1Affine,
+    opening: &KzgOpening,
+) -> bool {
+    let bn = env.crypto().bn254();
+    let generator = G1Affine::from_array(env, &G1_GENERATOR);
+
+    // C - [y]_1 + z * pi
+    let lhs = bn.g1_add(
+        &bn.g1_add(commitment, &-bn.g1_mul(&generator, &opening.value)),
+        &bn.g1_mul(&opening.proof, &opening.point),
+    );
+
+    backend.check_pairing(
+        vec![env, lhs, -&opening.proof],
+        vec![env, vk.g2.clone(), vk.tau_g2.clone()],
+    )
+}
@@ -1,20 +1,89 @@
 #![no_std]
+// The `panic-audit` feature denies panicking calls outside of tests, so that malformed input is
+// rejected with a `VerifierError`, which callers can handle, rather than trapping. A panic that
+// is kept must be allowed at its call site, with the reason it cannot be reached.
+#![cfg_attr(
+    all(feature = "panic-audit", not(test)),
+    deny(
+        clippy::expect_used,
+        clippy::indexing_slicing,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented,
+        clippy::unreachable,
+        clippy::unwrap_used
+    )
+)]
+
+// An unwinding Wasm build would defeat the audit: require the `abort` strategy of the release
+// profile.
+#[cfg(all(feature = "panic-audit", target_family = "wasm", not(panic = "abort")))]
+compile_error!("the `panic-audit` feature requires `panic = \"abort\"` for Wasm builds");
 
-use risc0_interface::{Receipt, ReceiptClaim, RiscZeroVerifierInterface, VerifierError};
+use risc0_interface::{
+    Receipt, ReceiptClaim, RiscZeroVerifierInterface, VerifierError, ensure_nonzero_image_id,
+    extract_selector,
+};
+use risc0_stellar_params as params;
 use soroban_sdk::{
-    Bytes, BytesN, Env, String, Vec, contract, contractimpl, crypto::bn254::Fr, vec,
+    Address, Bytes, BytesN, Env, String, Symbol, Vec, contract, contractimpl, contracttype,
+    crypto::bn254::{Bn254G1Affine as G1Affine, Fr},
+    vec,
 };
+use stellar_access::access_control::{self, AccessControl};
+use stellar_macros::only_admin;
 
-use types::{Groth16Proof, Groth16Seal, VerificationKeyBytes};
+#[cfg(any(test, feature = "testutils"))]
+use risc0_interface::debug::VerificationFailure;
+
+pub use fr::FrExt;
+use pairing::{HostPairing, PairingBackend};
+use types::{
+    BuildInfo, Deprecated, DeprecatedVerifierUsed, Deprecation, Groth16Proof, Groth16Seal,
+    KnownControlRoot, LikelyVersionMismatch, SealDecoding, VerificationKeyBytes,
+};
+pub use types::{G1_SIZE, G2_SIZE, PROOF_SIZE, SEAL_SIZE};
 
+#[cfg(feature = "ark")]
+mod ark;
+#[cfg(feature = "audit")]
+mod audit;
+pub mod bn254;
+mod fr;
+pub mod kzg;
+pub mod msm;
+pub mod pairing;
+pub mod seal_format;
 #[cfg(test)]
 mod test;
+#[cfg(feature = "trace")]
+pub mod trace;
 mod types;
 
+/// Storage keys used by the Groth16 verifier.
+#[contracttype]
+enum DataKey {
+    /// Deprecation state, absent while the verifier is current.
+    Deprecation,
+    /// Control roots of other releases, absent while diagnostics are disabled.
+    KnownControlRoots,
+    /// Seal decoding mode, absent while strict.
+    SealDecoding,
+}
+
 /// Groth16 verifier contract for RISC Zero receipts of execution.
 ///
 /// This contract implements the [`RiscZeroVerifierInterface`] using Groth16 zero-knowledge
 /// proofs over the BN254 elliptic curve.
+///
+/// The verification key is fixed at build time, so a new RISC Zero release is supported by
+/// deploying a new verifier under a new selector. The admin then
+/// [`deprecate`](Self::deprecate)s the superseded one to move integrators over.
+///
+/// Testnet deployments can be built with the `audit` feature, which re-checks in the contract
+/// the proof encoding and public signal invariants that the lean mainnet build leaves to the
+/// Soroban host and to construction. See the `audit` module for the list of checks;
+/// [`build_info`](Self::build_info) reports which build is deployed.
 #[contract]
 pub struct RiscZeroGroth16Verifier;
 
@@ -22,15 +91,128 @@ pub struct RiscZeroGroth16Verifier;
 impl RiscZeroGroth16Verifier {
     /// Groth16 verification key for the RISC Zero system.
     ///
-    /// This verification key is generated at build time from `vk.json`
-    const VERIFICATION_KEY: VerificationKeyBytes =
-        include!(concat!(env!("OUT_DIR"), "/verification_key.rs"));
+    /// This verification key is generated at build time from `parameters.json`, see
+    /// [`risc0_stellar_params`].
+    const VERIFICATION_KEY: VerificationKeyBytes = {
+        let vk = params::VERIFICATION_KEY;
+        VerificationKeyBytes {
+            alpha: vk.alpha,
+            beta: vk.beta,
+            gamma: vk.gamma,
+            delta: vk.delta,
+            ic: vk.ic,
+        }
+    };
+
+    const VERSION: &'static str = params::VERSION;
+    const CONTROL_ROOT_0: [u8; 16] = params::CONTROL_ROOT_0;
+    const CONTROL_ROOT_1: [u8; 16] = params::CONTROL_ROOT_1;
+    const BN254_CONTROL_ID: [u8; 32] = params::BN254_CONTROL_ID;
+    const POST_STATE_DIGEST: [u8; 32] = params::POST_STATE_DIGEST;
+    const SELECTOR: [u8; 4] = params::SELECTOR;
+    const PARAMETERS_DIGEST: [u8; 32] = params::PARAMETERS_DIGEST;
+    const GIT_COMMIT: &'static str = include!(concat!(env!("OUT_DIR"), "/git_commit.rs"));
+    const RUSTC_VERSION: &'static str = include!(concat!(env!("OUT_DIR"), "/rustc_version.rs"));
+
+    /// Initializes the verifier with the admin allowed to deprecate it.
+    pub fn __constructor(env: Env, admin: Address) {
+        access_control::set_admin(&env, &admin);
+    }
+
+    /// Deprecates the verifier. Only the admin can call this.
+    ///
+    /// Verifications keep succeeding but publish a [`DeprecatedVerifierUsed`] warning event.
+    /// With `grace_ledgers`, they fail with [`VerifierError::Deprecated`] once that many ledgers
+    /// have passed. Calling this again restarts the deprecation from the current ledger.
+    #[only_admin]
+    pub fn deprecate(env: Env, grace_ledgers: Option<u32>) {
+        let since_ledger = env.ledger().sequence();
+        let sunset_ledger = grace_ledgers.map(|ledgers| since_ledger.saturating_add(ledgers));
+
+        env.storage().instance().set(
+            &DataKey::Deprecation,
+            &Deprecation {
+                since_ledger,
+                sunset_ledger,
+            },
+        );
+        Deprecated {
+            since_ledger,
+            sunset_ledger,
+        }
+        .publish(&env);
+    }
+
+    /// Returns whether the verifier was deprecated.
+    pub fn is_deprecated(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::Deprecation)
+    }
+
+    /// Returns the deprecation state of the verifier, if it was deprecated.
+    pub fn deprecation(env: Env) -> Option<Deprecation> {
+        env.storage().instance().get(&DataKey::Deprecation)
+    }
+
+    /// Sets the control roots of other RISC Zero releases, replacing the previous ones. Only the
+    /// admin can call this.
+    ///
+    /// This enables a diagnostics mode: when a proof fails the pairing check, it is checked
+    /// again under each known control root, and the first match publishes
+    /// [`LikelyVersionMismatch`] before the call fails with [`VerifierError::InvalidProof`].
+    /// Proving with a different RISC Zero version than the verifier embeds is the most common
+    /// integration failure, and otherwise indistinguishable from a forged proof.
+    ///
+    /// The failure rolls the event back with the rest of the invocation, so it never reaches
+    /// the ledger: it is only visible in the diagnostic events of a simulation, a preflight or
+    /// a failed transaction's diagnostics. Off-chain tooling should read it from there.
+    ///
+    /// Each known root costs one extra pairing check on failed verifications only. An empty
+    /// list disables the diagnostics.
+    #[only_admin]
+    pub fn set_known_control_roots(env: Env, roots: Vec<KnownControlRoot>) {
+        if roots.is_empty() {
+            env.storage().instance().remove(&DataKey::KnownControlRoots);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey::KnownControlRoots, &roots);
+        }
+    }
+
+    /// Returns the control roots checked by the diagnostics mode, see
+    /// [`set_known_control_roots`](Self::set_known_control_roots).
+    pub fn known_control_roots(env: Env) -> Vec<KnownControlRoot> {
+        env.storage()
+            .instance()
+            .get(&DataKey::KnownControlRoots)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Sets how the verifier decodes seals. Only the admin can call this.
+    ///
+    /// Verifiers start [`SealDecoding::Strict`], accepting only the native seal layout.
+    /// [`SealDecoding::Permissive`] also accepts the formats detected by
+    /// [`seal_format`](crate::seal_format), for deployments serving relayers that aggregate
+    /// proofs from several toolchains. Seals passing through the router must still start with
+    /// the selector, which ABI-encoded seals do not.
+    #[only_admin]
+    pub fn set_seal_decoding(env: Env, decoding: SealDecoding) {
+        match decoding {
+            SealDecoding::Strict => env.storage().instance().remove(&DataKey::SealDecoding),
+            SealDecoding::Permissive => env
+                .storage()
+                .instance()
+                .set(&DataKey::SealDecoding, &decoding),
+        }
+    }
 
-    const VERSION: &'static str = include!(concat!(env!("OUT_DIR"), "/version.rs"));
-    const CONTROL_ROOT_0: [u8; 16] = include!(concat!(env!("OUT_DIR"), "/control_root_0.rs"));
-    const CONTROL_ROOT_1: [u8; 16] = include!(concat!(env!("OUT_DIR"), "/control_root_1.rs"));
-    const BN254_CONTROL_ID: [u8; 32] = include!(concat!(env!("OUT_DIR"), "/bn254_control_id.rs"));
-    const SELECTOR: [u8; 4] = include!(concat!(env!("OUT_DIR"), "/selector.rs"));
+    /// Returns how the verifier decodes seals, see [`set_seal_decoding`](Self::set_seal_decoding).
+    pub fn seal_decoding(env: Env) -> SealDecoding {
+        env.storage()
+            .instance()
+            .get(&DataKey::SealDecoding)
+            .unwrap_or(SealDecoding::Strict)
+    }
 
     /// Returns the verifier's selector
     pub fn selector(env: Env) -> BytesN<4> {
@@ -42,6 +224,26 @@ impl RiscZeroGroth16Verifier {
         String::from_str(&env, Self::VERSION)
     }
 
+    /// Returns the post-state digest of a halted execution in the verifier's RISC Zero version,
+    /// which the claims built by [`verify`](RiscZeroVerifierInterface::verify) commit to.
+    pub fn post_state_digest(env: Env) -> BytesN<32> {
+        BytesN::from_array(&env, &Self::POST_STATE_DIGEST)
+    }
+
+    /// Returns the build metadata embedded at compile time.
+    ///
+    /// Rebuilding the reported commit with the reported compiler (and the reported features)
+    /// must reproduce this contract's Wasm exactly; the parameters digest identifies the
+    /// verification key inputs.
+    pub fn build_info(env: Env) -> BuildInfo {
+        BuildInfo {
+            git_commit: String::from_str(&env, Self::GIT_COMMIT),
+            rustc_version: String::from_str(&env, Self::RUSTC_VERSION),
+            parameters_digest: BytesN::from_array(&env, &Self::PARAMETERS_DIGEST),
+            audit: cfg!(feature = "audit"),
+        }
+    }
+
     /// Verifies a Groth16 proof with the given public signals.
     ///
     /// This function implements the core Groth16 verification algorithm using the BN254
@@ -52,6 +254,11 @@ impl RiscZeroGroth16Verifier {
     /// where `vk_x` is computed as a linear combination of the verification key's IC points
     /// weighted by the public signals.
     ///
+    /// `e(alpha, beta)` is constant for a given verification key, but it is still passed to the
+    /// host as a fourth pairing: Soroban only exposes a boolean multi-pairing check
+    /// (`pairing_check`), with no `Fq12` type or target-group multiplication, so a value
+    /// precomputed at build time could not be folded into the product.
+    ///
     /// # Parameters
     ///
     /// - `proof`: The Groth16 proof containing points A, B, and C
@@ -62,87 +269,378 @@ impl RiscZeroGroth16Verifier {
         proof: Groth16Proof,
         pub_signals: Vec<Fr>,
     ) -> Result<bool, VerifierError> {
-        let vk = Self::VERIFICATION_KEY.verification_key(&env);
-        let bn = env.crypto().bn254();
+        Self::verify_proof_with(&env, &HostPairing, proof, pub_signals)
+    }
 
-        if pub_signals.len() + 1 != vk.ic.len() as u32 {
-            return Err(VerifierError::MalformedPublicInputs);
-        }
+    /// Verifies a proof like [`verify`](RiscZeroVerifierInterface::verify), additionally
+    /// rejecting proofs that are not in canonical form.
+    ///
+    /// Strict mode only removes the negation malleability of the proof; seals must still not be
+    /// used as unique identifiers, see [`verify_integrity_strict`](Self::verify_integrity_strict).
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::NonCanonicalProof`] - The proof is the mirrored, non-canonical form
+    /// - Any error returned by [`verify`](RiscZeroVerifierInterface::verify)
+    pub fn verify_strict(
+        env: Env,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> Result<(), VerifierError> {
+        ensure_nonzero_image_id(&image_id)?;
+        let receipt = Self::receipt_from_execution(&env, seal, image_id, journal);
+        Self::verify_integrity_strict(env, receipt)
+    }
 
-        let mut vk_x = vk.ic[0].clone();
-        for (s, v) in pub_signals.iter().zip(vk.ic.iter().skip(1)) {
-            let prod = bn.g1_mul(v, &s);
-            vk_x = bn.g1_add(&vk_x, &prod);
+    /// Verifies a receipt like [`verify_integrity`](RiscZeroVerifierInterface::verify_integrity),
+    /// additionally rejecting proofs that are not in canonical form.
+    ///
+    /// Groth16 proofs are malleable: negating `A` and `B` yields a second valid seal for the
+    /// same claim. Strict mode only accepts the form where the y-coordinate of `A` is in the
+    /// lower half of the base field, which removes this negation.
+    ///
+    /// It does not make seals unique: anyone can re-randomize a proof into `(r⁻¹·A, r·B, C)`,
+    /// which verifies for any nonzero scalar `r` without knowledge of the witness or the
+    /// trapdoor. Applications must not use seals as identifiers; replay protection should key
+    /// on the claim digest or an application-level nullifier committed in the journal.
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::NonCanonicalProof`] - The proof is the mirrored, non-canonical form
+    /// - Any error returned by [`verify_integrity`](RiscZeroVerifierInterface::verify_integrity)
+    pub fn verify_integrity_strict(env: Env, mut receipt: Receipt) -> Result<(), VerifierError> {
+        receipt.seal = decode_format(&env, receipt.seal)?;
+        let seal = Groth16Seal::try_from(receipt.seal.clone())?;
+        if !seal.proof.is_canonical() {
+            return Err(VerifierError::NonCanonicalProof);
         }
+        Self::verify_integrity(env, receipt)
+    }
+}
 
-        // Compute the pairing check:
-        // e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1
-        let neg_a = -proof.a;
-        let g1_points = vec![&env, neg_a, vk.alpha, vk_x, proof.c];
-        let g2_points = vec![&env, proof.b, vk.beta, vk.gamma, vk.delta];
+#[cfg(feature = "pairing-check")]
+#[contractimpl]
+impl RiscZeroGroth16Verifier {
+    /// Returns whether `e(g1s[0], g2s[0]) * ... * e(g1s[n], g2s[n]) == 1` over BN254.
+    ///
+    /// A utility for other protocols needing pairing checks, e.g. BLS signature aggregation or
+    /// KZG openings, through this deployed verifier. Points use the Soroban encoding. Like the
+    /// host function, traps if the inputs differ in length or contain a point that is not in
+    /// its group.
+    // The types are spelled out: the contract spec maps a bare `G1Affine` to a BLS12-381 point.
+    pub fn bn254_pairing_check(
+        _env: Env,
+        g1s: Vec<soroban_sdk::crypto::bn254::Bn254G1Affine>,
+        g2s: Vec<soroban_sdk::crypto::bn254::Bn254G2Affine>,
+    ) -> bool {
+        HostPairing.check_pairing(g1s, g2s)
+    }
+}
 
-        Ok(bn.pairing_check(g1_points, g2_points))
+#[cfg(feature = "trace")]
+#[contractimpl]
+impl RiscZeroGroth16Verifier {
+    /// Verifies a receipt like [`verify_integrity`](RiscZeroVerifierInterface::verify_integrity),
+    /// publishing the budget consumed by each stage of the verification.
+    ///
+    /// See [`trace`] for the stages and the events.
+    pub fn verify_integrity_traced(env: Env, receipt: Receipt) -> Result<(), VerifierError> {
+        let mut tracer = trace::Tracer::start(&env);
+        Self::verify_integrity_staged(&env, &HostPairing, receipt, |stage| tracer.stage(stage))
     }
 }
 
+#[cfg(any(test, feature = "testutils"))]
 #[contractimpl]
+impl RiscZeroGroth16Verifier {
+    /// Returns the context of the last failed verification, for debugging tests.
+    ///
+    /// See [`risc0_interface::debug`].
+    pub fn last_error(env: Env) -> Option<VerificationFailure> {
+        risc0_interface::debug::last_failure(&env)
+    }
+}
+
+#[contractimpl(contracttrait)]
 impl RiscZeroVerifierInterface for RiscZeroGroth16Verifier {
     type Proof = Groth16Seal;
 
+    /// The claim is built with the post-state digest of the verifier's RISC Zero version, see
+    /// [`post_state_digest`](RiscZeroGroth16Verifier::post_state_digest).
     fn verify(
         env: Env,
         seal: Bytes,
         image_id: BytesN<32>,
         journal: BytesN<32>,
     ) -> Result<(), VerifierError> {
-        let claim = ReceiptClaim::new(&env, image_id, journal);
+        ensure_nonzero_image_id(&image_id)?;
+        let receipt = Self::receipt_from_execution(&env, seal, image_id, journal);
+        Self::verify_integrity(env, receipt)
+    }
+
+    /// A zero claim digest is accepted: a valid proof for it is the emergency stop's proof of
+    /// exploit, see [`ensure_nonzero_claim_digest`](risc0_interface::ensure_nonzero_claim_digest).
+    ///
+    /// The selector is checked before the seal is decoded, so a seal from another proof system
+    /// fails with [`VerifierError::InvalidSelector`] regardless of its length. A seal carrying
+    /// this verifier's selector but with the wrong length fails with
+    /// [`VerifierError::MalformedSeal`] and publishes [`SealLengthMismatch`](types::SealLengthMismatch).
+    ///
+    /// On a deprecated verifier, this publishes [`DeprecatedVerifierUsed`] and fails with
+    /// [`VerifierError::Deprecated`] after the sunset ledger.
+    ///
+    /// With [`SealDecoding::Permissive`], the seal is first normalized to the native layout.
+    ///
+    /// A proof failing the pairing check may publish [`LikelyVersionMismatch`], see
+    /// [`set_known_control_roots`](RiscZeroGroth16Verifier::set_known_control_roots). The call
+    /// still fails, so the event is rolled back and only visible in simulation and diagnostic
+    /// output.
+    fn verify_integrity(env: Env, receipt: Receipt) -> Result<(), VerifierError> {
+        #[cfg(any(test, feature = "testutils"))]
+        let failure_context = receipt.clone();
+        let result = Self::verify_integrity_with(&env, &HostPairing, receipt);
+        #[cfg(any(test, feature = "testutils"))]
+        risc0_interface::debug::record_result(
+            &env,
+            &failure_context.seal,
+            &failure_context.claim_digest,
+            result,
+        );
+        result
+    }
+
+    /// Groth16 seals prove exactly one claim, so only single-claim requests are accepted.
+    fn verify_many(env: Env, seal: Bytes, claims: Vec<BytesN<32>>) -> Result<(), VerifierError> {
+        if claims.len() != 1 {
+            return Err(VerifierError::UnsupportedAggregation);
+        }
         let receipt = Receipt {
             seal,
-            claim_digest: claim.digest(&env),
+            claim_digest: claims.get_unchecked(0),
         };
         Self::verify_integrity(env, receipt)
     }
+}
 
-    fn verify_integrity(env: Env, receipt: Receipt) -> Result<(), VerifierError> {
-        let seal = Self::Proof::try_from(receipt.seal)?;
+#[contractimpl(contracttrait)]
+impl AccessControl for RiscZeroGroth16Verifier {}
 
-        if seal.selector != Self::SELECTOR {
+impl RiscZeroGroth16Verifier {
+    /// Returns the receipt of a successful execution of `image_id` committing `journal`, like
+    /// [`Receipt::from_execution`] with the verifier's post-state digest.
+    fn receipt_from_execution(
+        env: &Env,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> Receipt {
+        let post_state_digest = BytesN::from_array(env, &Self::POST_STATE_DIGEST);
+        let claim =
+            ReceiptClaim::with_post_state_digest(env, image_id, journal, post_state_digest, 0);
+        Receipt {
+            seal,
+            claim_digest: claim.digest(env),
+        }
+    }
+
+    /// Verifies a Groth16 proof like [`verify_proof`](Self::verify_proof), checking the final
+    /// pairing equation with `pairing`.
+    pub fn verify_proof_with(
+        env: &Env,
+        pairing: &impl PairingBackend,
+        proof: Groth16Proof,
+        pub_signals: Vec<Fr>,
+    ) -> Result<bool, VerifierError> {
+        let vk_x = msm::linear_combination(env, Self::VERIFICATION_KEY.ic, &pub_signals)?;
+        Ok(Self::check_pairing_equation(env, pairing, proof, vk_x))
+    }
+
+    /// Returns whether `proof` satisfies the Groth16 pairing equation, given `vk_x`, the
+    /// linear combination of the public signals with the IC points.
+    fn check_pairing_equation(
+        env: &Env,
+        pairing: &impl PairingBackend,
+        proof: Groth16Proof,
+        vk_x: G1Affine,
+    ) -> bool {
+        let vk = Self::VERIFICATION_KEY.verification_key(env);
+
+        // Compute the pairing check:
+        // e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1
+        let neg_a = -proof.a;
+        let g1_points = vec![env, neg_a, vk.alpha, vk_x, proof.c];
+        let g2_points = vec![env, proof.b, vk.beta, vk.gamma, vk.delta];
+
+        pairing.check_pairing(g1_points, g2_points)
+    }
+
+    /// Verifies a receipt like [`verify_integrity`](RiscZeroVerifierInterface::verify_integrity),
+    /// checking pairings with `pairing`.
+    ///
+    /// Must run in the context of a verifier contract, which stores the deprecation state and
+    /// the known control roots.
+    pub fn verify_integrity_with(
+        env: &Env,
+        pairing: &impl PairingBackend,
+        receipt: Receipt,
+    ) -> Result<(), VerifierError> {
+        Self::verify_integrity_staged(env, pairing, receipt, |_| {})
+    }
+
+    /// Verifies a receipt like [`verify_integrity_with`](Self::verify_integrity_with), calling
+    /// `on_stage` with the name of each stage as it completes: `decode`, `signals`, `msm` and
+    /// `pairing`.
+    fn verify_integrity_staged(
+        env: &Env,
+        pairing: &impl PairingBackend,
+        receipt: Receipt,
+        mut on_stage: impl FnMut(&str),
+    ) -> Result<(), VerifierError> {
+        check_deprecation(env)?;
+
+        let seal = decode_format(env, receipt.seal)?;
+        if extract_selector(&seal)? != Self::SELECTOR {
             return Err(VerifierError::InvalidSelector);
         }
 
-        let (claim_0, claim_1) = split_digest(&env, receipt.claim_digest);
+        let seal = Groth16Seal::decode(env, seal)?;
+        #[cfg(feature = "audit")]
+        audit::check_proof(env, &seal.proof)?;
+        on_stage("decode");
 
-        let control_root_0 = {
-            let mut bytes = [0u8; 32];
-            bytes[16..32].copy_from_slice(&Self::CONTROL_ROOT_0);
-            BytesN::from_array(&env, &bytes)
-        };
+        let pub_signals = build_public_signals(env, &receipt.claim_digest);
+        let pub_signals = Vec::from_array(env, pub_signals);
+        on_stage("signals");
 
-        let control_root_1 = {
-            let mut bytes = [0u8; 32];
-            bytes[16..32].copy_from_slice(&Self::CONTROL_ROOT_1);
-            BytesN::from_array(&env, &bytes)
-        };
+        let vk_x = msm::linear_combination(env, Self::VERIFICATION_KEY.ic, &pub_signals)?;
+        on_stage("msm");
 
-        // Convert BN254_CONTROL_ID to BytesN<32>
-        let bn254_control_id: BytesN<32> = BytesN::from_array(&env, &Self::BN254_CONTROL_ID);
-
-        // Create public signals as Fr field elements
-        let mut pub_signals = Vec::new(&env);
-        pub_signals.push_back(Fr::from_bytes(control_root_0));
-        pub_signals.push_back(Fr::from_bytes(control_root_1));
-        pub_signals.push_back(Fr::from_bytes(claim_0));
-        pub_signals.push_back(Fr::from_bytes(claim_1));
-        pub_signals.push_back(Fr::from_bytes(bn254_control_id));
-
-        // Verify the proof and panic if invalid
-        match Self::verify_proof(env, seal.proof, pub_signals)? {
-            true => Ok(()),
-            false => Err(VerifierError::InvalidProof),
+        let valid = Self::check_pairing_equation(env, pairing, seal.proof.clone(), vk_x);
+        on_stage("pairing");
+        if valid {
+            return Ok(());
         }
+        diagnose_version_mismatch(env, pairing, &seal.proof, &receipt.claim_digest);
+        Err(VerifierError::InvalidProof)
+    }
+}
+
+/// Fails with [`VerifierError::Deprecated`] after the sunset ledger, and otherwise publishes a
+/// [`DeprecatedVerifierUsed`] warning if the verifier is deprecated.
+fn check_deprecation(env: &Env) -> Result<(), VerifierError> {
+    let Some(deprecation) = RiscZeroGroth16Verifier::deprecation(env.clone()) else {
+        return Ok(());
+    };
+
+    let sunset_ledger = deprecation.sunset_ledger;
+    if sunset_ledger.is_some_and(|sunset| env.ledger().sequence() >= sunset) {
+        return Err(VerifierError::Deprecated);
+    }
+    DeprecatedVerifierUsed { sunset_ledger }.publish(env);
+    Ok(())
+}
+
+/// Normalizes `seal` to the native layout if the verifier decodes seals permissively.
+fn decode_format(env: &Env, seal: Bytes) -> Result<Bytes, VerifierError> {
+    match RiscZeroGroth16Verifier::seal_decoding(env.clone()) {
+        SealDecoding::Strict => Ok(seal),
+        SealDecoding::Permissive => seal_format::normalize(env, seal),
     }
 }
 
+/// Checks a failed proof under each known control root, publishing [`LikelyVersionMismatch`]
+/// and returning the first one it verifies under.
+fn diagnose_version_mismatch(
+    env: &Env,
+    pairing: &impl PairingBackend,
+    proof: &Groth16Proof,
+    claim_digest: &BytesN<32>,
+) -> Option<KnownControlRoot> {
+    let roots = RiscZeroGroth16Verifier::known_control_roots(env.clone());
+    let known = roots.into_iter().find(|known| {
+        let control_root = split_digest(env, known.control_root.clone());
+        let pub_signals = Vec::from_array(env, public_signals(env, control_root, claim_digest));
+        matches!(
+            RiscZeroGroth16Verifier::verify_proof_with(env, pairing, proof.clone(), pub_signals),
+            Ok(true)
+        )
+    })?;
+
+    LikelyVersionMismatch {
+        version: known.version.clone(),
+        control_root: known.control_root.clone(),
+    }
+    .publish(env);
+    Some(known)
+}
+
+/// Number of public signals of a RISC Zero Groth16 proof.
+pub const PUBLIC_SIGNALS: usize = 5;
+
+/// Builds the public signals the embedded verification key checks a RISC Zero Groth16 proof of
+/// `claim_digest` against, in order:
+///
+/// 1. `control_root_0` and `control_root_1`, the halves of the embedded control root
+/// 2. `claim_0` and `claim_1`, the halves of the claim digest
+/// 3. The BN254 control id
+///
+/// Digests are split like `splitDigest` in the Ethereum `RiscZeroGroth16Verifier`: the byte
+/// order is reversed, then the low 128 bits come first. The signals are therefore the
+/// `uint256` values the Ethereum verifier passes to its Groth16 verifier for the same claim.
+pub fn build_public_signals(env: &Env, claim_digest: &BytesN<32>) -> [Fr; PUBLIC_SIGNALS] {
+    let control_root = {
+        let mut control_root_0 = [0u8; 32];
+        control_root_0[16..32].copy_from_slice(&RiscZeroGroth16Verifier::CONTROL_ROOT_0);
+        let mut control_root_1 = [0u8; 32];
+        control_root_1[16..32].copy_from_slice(&RiscZeroGroth16Verifier::CONTROL_ROOT_1);
+        (
+            BytesN::from_array(env, &control_root_0),
+            BytesN::from_array(env, &control_root_1),
+        )
+    };
+    public_signals(env, control_root, claim_digest)
+}
+
+/// Builds the public signals of a RISC Zero Groth16 proof like [`build_public_signals`], under
+/// the given control root halves.
+fn public_signals(
+    env: &Env,
+    (control_root_0, control_root_1): (BytesN<32>, BytesN<32>),
+    claim_digest: &BytesN<32>,
+) -> [Fr; PUBLIC_SIGNALS] {
+    let [claim_0, claim_1] = digest_to_fr(env, claim_digest);
+    let bn254_control_id = BytesN::from_array(env, &RiscZeroGroth16Verifier::BN254_CONTROL_ID);
+
+    let pub_signals = [
+        Fr::from_bytes(control_root_0),
+        Fr::from_bytes(control_root_1),
+        claim_0,
+        claim_1,
+        Fr::from_bytes(bn254_control_id),
+    ];
+    #[cfg(feature = "audit")]
+    audit::assert_public_signals(&pub_signals);
+    pub_signals
+}
+
+/// Maps a SHA-256 digest to the two scalars a RISC Zero circuit takes it as, the way the claim
+/// digest becomes `claim_0` and `claim_1` in [`build_public_signals`].
+///
+/// A digest does not fit in a single scalar below `r`, so it is split instead of reduced: the
+/// byte order is reversed, then the low 128 bits come first. Both scalars are below `2^128`,
+/// so no information is lost to the reduction modulo `r`. Circuits taking digests the same way
+/// get consistent public inputs from this function.
+pub fn digest_to_fr(env: &Env, digest: &BytesN<32>) -> [Fr; 2] {
+    let (first, second) = split_digest(env, digest.clone());
+    [Fr::from_bytes(first), Fr::from_bytes(second)]
+}
+
+/// Hashes `bytes` with SHA-256 and maps the digest to two scalars with [`digest_to_fr`].
+pub fn hash_to_fr(env: &Env, bytes: &Bytes) -> [Fr; 2] {
+    digest_to_fr(env, &env.crypto().sha256(bytes).into())
+}
+
 /// Splits a digest into two 32-byte parts after reversing byte order.
 ///
 /// This function reverses the byte order of the input digest and splits it into
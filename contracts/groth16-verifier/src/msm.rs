@@ -0,0 +1,72 @@
+//! Multi-scalar multiplication of the verification key's IC points.
+//!
+//! Groth16 verification folds the public signals into `vk_x = IC[0] + sum_i s_i * IC[i + 1]`.
+//! Soroban has no MSM host function, so this is one `g1_mul` and one `g1_add` per signal.
+//!
+//! The IC points are decoded from the embedded encoding as they are consumed, rather than all
+//! up front, so the points held by the contract do not grow with the number of public inputs.
+//! The signals are folded in chunks of [`MSM_CHUNK_SIZE`]: each chunk is summed on its own,
+//! then added to the accumulator. This keeps verification keys of circuits with 50+ public
+//! inputs within Soroban's memory limits.
+
+use soroban_sdk::{
+    Env, Vec,
+    crypto::bn254::{Bn254G1Affine as G1Affine, Fr},
+};
+
+use risc0_interface::VerifierError;
+
+use crate::types::G1_SIZE;
+
+/// Number of IC points folded per chunk by [`linear_combination`].
+pub const MSM_CHUNK_SIZE: usize = 8;
+
+/// Returns `ic[0] + sum_i scalars[i] * ic[i + 1]`, in chunks of [`MSM_CHUNK_SIZE`] points.
+///
+/// # Errors
+///
+/// - [`VerifierError::MalformedPublicInputs`] - `ic` does not have one more point than there
+///   are scalars
+pub fn linear_combination(
+    env: &Env,
+    ic: &[[u8; G1_SIZE]],
+    scalars: &Vec<Fr>,
+) -> Result<G1Affine, VerifierError> {
+    linear_combination_chunked(env, ic, scalars, MSM_CHUNK_SIZE)
+}
+
+/// Computes [`linear_combination`] in chunks of `chunk_size` points.
+///
+/// # Panics
+///
+/// If `chunk_size` is zero.
+pub fn linear_combination_chunked(
+    env: &Env,
+    ic: &[[u8; G1_SIZE]],
+    scalars: &Vec<Fr>,
+    chunk_size: usize,
+) -> Result<G1Affine, VerifierError> {
+    let Some((first, points)) = ic.split_first() else {
+        return Err(VerifierError::MalformedPublicInputs);
+    };
+    if points.len() != scalars.len() as usize {
+        return Err(VerifierError::MalformedPublicInputs);
+    }
+
+    let bn = env.crypto().bn254();
+    let mut acc = G1Affine::from_array(env, first);
+    let mut scalars = scalars.iter();
+    for chunk in points.chunks(chunk_size) {
+        let mut terms = chunk
+            .iter()
+            .zip(scalars.by_ref())
+            .map(|(point, scalar)| bn.g1_mul(&G1Affine::from_array(env, point), &scalar));
+        // `chunks` never yields an empty chunk, and there is a scalar per point.
+        let Some(first_term) = terms.next() else {
+            continue;
+        };
+        let partial = terms.fold(first_term, |sum, term| bn.g1_add(&sum, &term));
+        acc = bn.g1_add(&acc, &partial);
+    }
+    Ok(acc)
+}
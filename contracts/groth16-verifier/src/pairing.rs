@@ -0,0 +1,109 @@
+//! Backends for the BN254 multi-pairing check at the end of Groth16 verification.
+//!
+//! The contract always checks pairings with the Soroban host ([`HostPairing`]). The check is
+//! behind [`PairingBackend`] so the rest of the verification (selector checks, seal decoding,
+//! public signal construction) can also run against:
+//!
+//! - [`ArkPairing`] (`ark` feature): arkworks, to cross-check the host from host-side tooling
+//! - [`StubPairing`] (`testutils` feature): a deterministic test double, to unit test the
+//!   verification control flow without valid proofs or real pairings
+
+#[cfg(any(test, feature = "testutils"))]
+use core::cell::{Cell, RefCell};
+
+use soroban_sdk::{
+    Vec,
+    crypto::bn254::{Bn254G1Affine as G1Affine, Bn254G2Affine as G2Affine},
+};
+
+/// A BN254 multi-pairing check.
+pub trait PairingBackend {
+    /// Returns whether `e(g1s[0], g2s[0]) * ... * e(g1s[n], g2s[n]) == 1`.
+    ///
+    /// Like the host function, implementations may panic if the inputs have different
+    /// lengths or contain invalid points.
+    fn check_pairing(&self, g1s: Vec<G1Affine>, g2s: Vec<G2Affine>) -> bool;
+}
+
+/// Checks pairings with the Soroban host function.
+pub struct HostPairing;
+
+impl PairingBackend for HostPairing {
+    fn check_pairing(&self, g1s: Vec<G1Affine>, g2s: Vec<G2Affine>) -> bool {
+        g1s.env().crypto().bn254().pairing_check(g1s, g2s)
+    }
+}
+
+/// Checks pairings with arkworks, for host-side tooling.
+///
+/// Points are decoded from the Soroban encoding and must be valid, as the host requires.
+#[cfg(feature = "ark")]
+pub struct ArkPairing;
+
+#[cfg(feature = "ark")]
+impl PairingBackend for ArkPairing {
+    fn check_pairing(&self, g1s: Vec<G1Affine>, g2s: Vec<G2Affine>) -> bool {
+        use ark_bn254::Bn254;
+        use ark_ec::pairing::Pairing;
+        use ark_ff::Zero;
+
+        use crate::ark::{g1, g2};
+
+        assert_eq!(g1s.len(), g2s.len(), "pairing inputs differ in length");
+        let g1s = g1s.iter().map(|point| g1(&point.to_array()));
+        let g2s = g2s.iter().map(|point| g2(&point.to_array()));
+        Bn254::multi_pairing(g1s, g2s).is_zero()
+    }
+}
+
+/// A deterministic [`PairingBackend`] returning a fixed result and recording its inputs.
+///
+/// Running verification against it exercises everything before the pairing check: a
+/// verification that is rejected earlier never calls it, and one that reaches it succeeds or
+/// fails with the configured result.
+#[cfg(any(test, feature = "testutils"))]
+pub struct StubPairing {
+    result: bool,
+    calls: Cell<u32>,
+    last_input: RefCell<Option<(Vec<G1Affine>, Vec<G2Affine>)>>,
+}
+
+#[cfg(any(test, feature = "testutils"))]
+impl StubPairing {
+    /// Returns a stub whose pairing checks all succeed.
+    pub fn accepting() -> Self {
+        Self::new(true)
+    }
+
+    /// Returns a stub whose pairing checks all fail.
+    pub fn rejecting() -> Self {
+        Self::new(false)
+    }
+
+    fn new(result: bool) -> Self {
+        Self {
+            result,
+            calls: Cell::new(0),
+            last_input: RefCell::new(None),
+        }
+    }
+
+    /// Returns the number of pairing checks so far.
+    pub fn calls(&self) -> u32 {
+        self.calls.get()
+    }
+
+    /// Returns the points of the last pairing check, if any.
+    pub fn last_input(&self) -> Option<(Vec<G1Affine>, Vec<G2Affine>)> {
+        self.last_input.borrow().clone()
+    }
+}
+
+#[cfg(any(test, feature = "testutils"))]
+impl PairingBackend for StubPairing {
+    fn check_pairing(&self, g1s: Vec<G1Affine>, g2s: Vec<G2Affine>) -> bool {
+        self.calls.set(self.calls.get() + 1);
+        self.last_input.replace(Some((g1s, g2s)));
+        self.result
+    }
+}
@@ -0,0 +1,180 @@
+//! Detection and normalization of the seal encodings produced by other toolchains.
+//!
+//! The verifier decodes the native layout, `selector || A || B || C` with uncompressed points,
+//! which is also what `risc0-ethereum` produces. Relayers aggregating proofs from several
+//! toolchains also receive:
+//!
+//! - [`SealFormat::EvmAbi`]: the native seal ABI-encoded as a Solidity `bytes` value, i.e. a
+//!   `0x20` offset word, a length word, and the seal zero-padded to 32-byte words
+//! - [`SealFormat::Compressed`]: `selector || A || B || C` with compressed points, see the
+//!   `ark` module for the point encoding
+//!
+//! [`normalize`] converts these to the native layout. The verifier only does so when
+//! [`SealDecoding::Permissive`](crate::types::SealDecoding::Permissive) is configured. Soroban
+//! exposes no square roots in the base field, so compressed seals can only be decompressed
+//! off-chain, with the `ark` feature.
+
+use soroban_sdk::{Bytes, Env};
+
+#[cfg(feature = "ark")]
+use crate::ark::{
+    G1_COMPRESSED_SIZE, G2_COMPRESSED_SIZE, g1, g1_compress, g1_decompress, g1_to_bytes, g2,
+    g2_compress, g2_decompress, g2_to_bytes,
+};
+#[cfg(feature = "ark")]
+use crate::types::Groth16Seal;
+use crate::types::SEAL_SIZE;
+use risc0_constants::SELECTOR_SIZE;
+use risc0_interface::VerifierError;
+
+/// Size of an ABI word.
+const WORD_SIZE: u32 = 32;
+
+/// Size of a compressed seal: the selector, then `A`, `B` and `C` with half-size points.
+const COMPRESSED_SEAL_SIZE: u32 = (SELECTOR_SIZE + (SEAL_SIZE - SELECTOR_SIZE) / 2) as u32;
+
+/// An encoding of a Groth16 seal.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SealFormat {
+    /// `selector || A || B || C` with uncompressed points.
+    Native,
+    /// A seal ABI-encoded as a Solidity `bytes` value.
+    EvmAbi,
+    /// `selector || A || B || C` with compressed points.
+    Compressed,
+}
+
+/// Detects the format of `seal` from its length and, for ABI-encoded seals, its header.
+///
+/// Returns `None` for seals of no known format. Detection does not validate the content,
+/// which is left to decoding.
+pub fn detect(seal: &Bytes) -> Option<SealFormat> {
+    match seal.len() {
+        len if len == SEAL_SIZE as u32 => Some(SealFormat::Native),
+        COMPRESSED_SEAL_SIZE => Some(SealFormat::Compressed),
+        _ => abi_payload_len(seal).map(|_| SealFormat::EvmAbi),
+    }
+}
+
+/// Converts `seal` to the native layout.
+///
+/// Native seals and seals of no known format are returned unchanged, so that decoding reports
+/// their errors as usual.
+///
+/// # Errors
+///
+/// - [`VerifierError::MalformedSeal`] - An ABI-encoded seal has non-zero padding, or a
+///   compressed seal has an invalid point or cannot be decompressed without the `ark` feature
+#[cfg_attr(not(feature = "ark"), allow(unused_variables))]
+pub fn normalize(env: &Env, seal: Bytes) -> Result<Bytes, VerifierError> {
+    match detect(&seal) {
+        None | Some(SealFormat::Native) => Ok(seal),
+        Some(SealFormat::EvmAbi) => {
+            let len = abi_payload_len(&seal).ok_or(VerifierError::MalformedSeal)?;
+            let payload_end = 2 * WORD_SIZE + len;
+            if seal.slice(payload_end..).iter().any(|byte| byte != 0) {
+                return Err(VerifierError::MalformedSeal);
+            }
+            Ok(seal.slice(2 * WORD_SIZE..payload_end))
+        }
+        #[cfg(feature = "ark")]
+        Some(SealFormat::Compressed) => decompress(env, &seal),
+        #[cfg(not(feature = "ark"))]
+        Some(SealFormat::Compressed) => Err(VerifierError::MalformedSeal),
+    }
+}
+
+/// ABI-encodes a seal as a Solidity `bytes` value, the inverse of [`normalize`] for
+/// [`SealFormat::EvmAbi`].
+pub fn encode_abi(env: &Env, seal: &Bytes) -> Bytes {
+    let mut encoded = Bytes::new(env);
+    encoded.extend_from_array(&word(WORD_SIZE));
+    encoded.extend_from_array(&word(seal.len()));
+    encoded.append(seal);
+    let padding = seal.len().next_multiple_of(WORD_SIZE) - seal.len();
+    for _ in 0..padding {
+        encoded.push_back(0);
+    }
+    encoded
+}
+
+/// Compresses a native seal, the inverse of [`normalize`] for [`SealFormat::Compressed`].
+///
+/// # Errors
+///
+/// - [`VerifierError::MalformedSeal`] - The seal is not a native seal
+#[cfg(feature = "ark")]
+pub fn compress(seal: &Bytes) -> Result<Bytes, VerifierError> {
+    let seal = Groth16Seal::try_from(seal.clone())?;
+    let proof = seal.proof;
+
+    let mut compressed = Bytes::from(&seal.selector);
+    compressed.extend_from_array(&g1_compress(&g1(&proof.a.to_array())));
+    compressed.extend_from_array(&g2_compress(&g2(&proof.b.to_array())));
+    compressed.extend_from_array(&g1_compress(&g1(&proof.c.to_array())));
+    Ok(compressed)
+}
+
+/// Decompresses a seal of [`COMPRESSED_SEAL_SIZE`] bytes.
+#[cfg(feature = "ark")]
+fn decompress(env: &Env, seal: &Bytes) -> Result<Bytes, VerifierError> {
+    let mut compressed = [0u8; COMPRESSED_SEAL_SIZE as usize];
+    seal.copy_into_slice(&mut compressed);
+    let (selector, points) = compressed.split_at(SELECTOR_SIZE);
+    let (a, points) = points.split_at(G1_COMPRESSED_SIZE);
+    let (b, c) = points.split_at(G2_COMPRESSED_SIZE);
+
+    let a = a
+        .try_into()
+        .ok()
+        .and_then(g1_decompress)
+        .ok_or(VerifierError::MalformedSeal)?;
+    let b = b
+        .try_into()
+        .ok()
+        .and_then(g2_decompress)
+        .ok_or(VerifierError::MalformedSeal)?;
+    let c = c
+        .try_into()
+        .ok()
+        .and_then(g1_decompress)
+        .ok_or(VerifierError::MalformedSeal)?;
+
+    let mut native = Bytes::from_slice(env, selector);
+    native.extend_from_array(&g1_to_bytes(&a));
+    native.extend_from_array(&g2_to_bytes(&b));
+    native.extend_from_array(&g1_to_bytes(&c));
+    Ok(native)
+}
+
+/// Returns the payload length of an ABI-encoded `bytes` value, if `seal` is one.
+fn abi_payload_len(seal: &Bytes) -> Option<u32> {
+    if seal.len() < 2 * WORD_SIZE || read_word(seal, 0)? != WORD_SIZE {
+        return None;
+    }
+    let len = read_word(seal, WORD_SIZE)?;
+    let encoded_len = len
+        .checked_next_multiple_of(WORD_SIZE)?
+        .checked_add(2 * WORD_SIZE)?;
+    (seal.len() == encoded_len).then_some(len)
+}
+
+/// Reads the ABI word at `offset` as a `u32`, or `None` if it does not fit.
+fn read_word(seal: &Bytes, offset: u32) -> Option<u32> {
+    let mut bytes = [0u8; WORD_SIZE as usize];
+    seal.slice(offset..offset + WORD_SIZE)
+        .copy_into_slice(&mut bytes);
+    let (high, low) = bytes.split_at(WORD_SIZE as usize - size_of::<u32>());
+    if high.iter().any(|&byte| byte != 0) {
+        return None;
+    }
+    Some(u32::from_be_bytes(low.try_into().ok()?))
+}
+
+/// Encodes `value` as an ABI word.
+#[allow(clippy::indexing_slicing, reason = "the word is longer than a u32")]
+fn word(value: u32) -> [u8; WORD_SIZE as usize] {
+    let mut bytes = [0u8; WORD_SIZE as usize];
+    bytes[WORD_SIZE as usize - size_of::<u32>()..].copy_from_slice(&value.to_be_bytes());
+    bytes
+}
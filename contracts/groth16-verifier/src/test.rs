@@ -1,12 +1,30 @@
 extern crate std;
 
-use soroban_sdk::{Bytes, BytesN, Env};
-use std::println;
+use risc0_interface::{Receipt, ReceiptClaim, VerifierError};
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, Event, String, Symbol, Vec,
+    crypto::bn254::{Bn254Fp, Bn254G1Affine, Fr},
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec,
+    xdr::ToXdr,
+};
+use std::{println, string::ToString};
 
-use crate::{RiscZeroGroth16Verifier, RiscZeroGroth16VerifierClient};
+use crate::{
+    FrExt, G1_SIZE, G2_SIZE, PROOF_SIZE, RiscZeroGroth16Verifier, RiscZeroGroth16VerifierClient,
+    SEAL_SIZE, bn254, build_public_signals, diagnose_version_mismatch, digest_to_fr, hash_to_fr,
+    msm,
+    pairing::{HostPairing, PairingBackend, StubPairing},
+    seal_format::{self, SealFormat},
+    split_digest,
+    types::{
+        Deprecated, DeprecatedVerifierUsed, Deprecation, Groth16Proof, Groth16Seal,
+        KnownControlRoot, LikelyVersionMismatch, SealDecoding, SealLengthMismatch,
+    },
+};
 
 /// Test seal data for benchmarks
-const TEST_SEAL: [u8; 260] = [
+const TEST_SEAL: [u8; SEAL_SIZE] = [
     115, 196, 87, 186, 0, 237, 128, 235, 234, 82, 162, 215, 108, 219, 83, 253, 51, 151, 104, 190,
     16, 27, 191, 115, 52, 20, 229, 22, 168, 155, 98, 214, 70, 109, 143, 168, 39, 163, 217, 215,
     117, 155, 119, 189, 172, 46, 218, 8, 164, 36, 138, 163, 47, 66, 185, 51, 132, 186, 120, 68,
@@ -31,10 +49,14 @@ const TEST_IMAGE_ID: [u8; 32] = [
 /// Test journal data
 const TEST_JOURNAL: [u8; 4] = [0x01, 0x00, 0x00, 0x78];
 
+/// Selector of the `RiscZeroGroth16Verifier` deployed on Ethereum for the embedded
+/// parameters (risc0 v3.0.0), as listed in risc0-ethereum's `deployment.toml`.
+const ETHEREUM_SELECTOR: [u8; 4] = [0x73, 0xc4, 0x57, 0xba];
+
 /// Helper to setup test environment and client
 fn setup_test() -> (Env, RiscZeroGroth16VerifierClient<'static>) {
     let env = Env::default();
-    let contract_id = env.register(RiscZeroGroth16Verifier, ());
+    let contract_id = env.register(RiscZeroGroth16Verifier, (Address::generate(&env),));
     let client = RiscZeroGroth16VerifierClient::new(&env, &contract_id);
     (env, client)
 }
@@ -47,6 +69,44 @@ fn prepare_inputs(env: &Env) -> (Bytes, BytesN<32>, BytesN<32>) {
     (seal, image_id, journal_digest.into())
 }
 
+#[test]
+fn test_selector_matches_ethereum_deployment() {
+    let (env, client) = setup_test();
+
+    // The selector is derived at build time from the control root, the BN254 control id
+    // and the verification key digest, so any drift in `compute_selector`, the point
+    // hashing or the tagged hashing in `build-utils` shows up here.
+    assert_eq!(client.version(), String::from_str(&env, "3.0.0"));
+    assert_eq!(
+        client.selector(),
+        BytesN::from_array(&env, &ETHEREUM_SELECTOR)
+    );
+}
+
+#[test]
+fn test_post_state_digest_matches_interface() {
+    let (_env, client) = setup_test();
+    assert_eq!(
+        client.post_state_digest().to_array(),
+        ReceiptClaim::POST_STATE_DIGEST_HALTED
+    );
+}
+
+#[test]
+fn test_build_info() {
+    let (env, client) = setup_test();
+    let parameters = Bytes::from_slice(&env, include_bytes!("../../../crates/params/parameters.json"));
+
+    let info = client.build_info();
+    assert_eq!(
+        info.parameters_digest,
+        env.crypto().sha256(&parameters).to_array()
+    );
+    assert!(info.rustc_version.to_string().starts_with("rustc "));
+    assert!(!info.git_commit.is_empty());
+    assert_eq!(info.audit, cfg!(feature = "audit"));
+}
+
 #[test]
 fn test_verify_proof() {
     let (env, client) = setup_test();
@@ -55,6 +115,1309 @@ fn test_verify_proof() {
     assert_eq!(client.verify(&seal, &image_id, &journal_digest), ());
 }
 
+#[test]
+fn test_verify_many_accepts_single_claim() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+    let claim = risc0_interface::ReceiptClaim::new(&env, image_id, journal_digest);
+
+    assert_eq!(
+        client.verify_many(&seal, &vec![&env, claim.digest(&env)]),
+        ()
+    );
+}
+
+#[test]
+fn test_verify_many_rejects_multiple_claims() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+    let claim_digest =
+        risc0_interface::ReceiptClaim::new(&env, image_id, journal_digest).digest(&env);
+
+    let result = client.try_verify_many(&seal, &vec![&env, claim_digest.clone(), claim_digest]);
+    assert_eq!(result, Err(Ok(VerifierError::UnsupportedAggregation)));
+
+    let result = client.try_verify_many(&seal, &vec![&env]);
+    assert_eq!(result, Err(Ok(VerifierError::UnsupportedAggregation)));
+}
+
+#[test]
+fn test_verify_rejects_padded_seal() {
+    let (env, client) = setup_test();
+    let (mut seal, image_id, journal_digest) = prepare_inputs(&env);
+    seal.push_back(0);
+
+    let result = client.try_verify(&seal, &image_id, &journal_digest);
+    assert_eq!(result, Err(Ok(VerifierError::MalformedSeal)));
+}
+
+// Decoding never panics: every truncation of a seal fails with a typed error, whatever format
+// it is detected as.
+#[test]
+fn test_verify_rejects_truncated_seals() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+    env.mock_all_auths();
+
+    for decoding in [SealDecoding::Strict, SealDecoding::Permissive] {
+        client.set_seal_decoding(&decoding);
+        for len in 0..seal.len() {
+            let result = client.try_verify(&seal.slice(..len), &image_id, &journal_digest);
+            assert_eq!(
+                result,
+                Err(Ok(VerifierError::MalformedSeal)),
+                "{decoding:?} seal of {len} bytes"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_verify_rejects_zero_image_id() {
+    let (env, client) = setup_test();
+    let (seal, _image_id, journal_digest) = prepare_inputs(&env);
+    let image_id = BytesN::from_array(&env, &[0u8; 32]);
+
+    let result = client.try_verify(&seal, &image_id, &journal_digest);
+    assert_eq!(result, Err(Ok(VerifierError::ZeroImageId)));
+    let result = client.try_verify_strict(&seal, &image_id, &journal_digest);
+    assert_eq!(result, Err(Ok(VerifierError::ZeroImageId)));
+}
+
+#[test]
+fn test_verify_rejects_foreign_selector_before_length() {
+    let (env, client) = setup_test();
+    let (_seal, image_id, journal_digest) = prepare_inputs(&env);
+    let seal = Bytes::from_slice(&env, &[0xFF, 0, 0, 2, 0xAA, 0xBB]);
+
+    let result = client.try_verify(&seal, &image_id, &journal_digest);
+    assert_eq!(result, Err(Ok(VerifierError::InvalidSelector)));
+}
+
+#[test]
+fn test_last_error_records_failed_verification() {
+    let (env, client) = setup_test();
+    let (seal, image_id, _journal_digest) = prepare_inputs(&env);
+    assert_eq!(client.last_error(), None);
+
+    let journal_digest = BytesN::from_array(&env, &[0u8; 32]);
+    let result = client.try_verify(&seal, &image_id, &journal_digest);
+    assert_eq!(result, Err(Ok(VerifierError::InvalidProof)));
+
+    let failure = client.last_error().unwrap();
+    assert_eq!(
+        failure.selector,
+        Some(BytesN::from_array(&env, &ETHEREUM_SELECTOR))
+    );
+    assert_eq!(
+        failure.claim_digest,
+        ReceiptClaim::new(&env, image_id, journal_digest).digest(&env)
+    );
+    assert_eq!(failure.error, VerifierError::InvalidProof as u32);
+}
+
+#[test]
+fn test_decode_reports_seal_length_mismatch() {
+    for actual in [259, 261] {
+        let (env, client) = setup_test();
+        let mut seal = [0u8; 261].to_vec();
+        seal[..260].copy_from_slice(&TEST_SEAL);
+        let seal = Bytes::from_slice(&env, &seal[..actual as usize]);
+
+        env.as_contract(&client.address, || {
+            let result = Groth16Seal::decode(&env, seal);
+            assert!(matches!(result, Err(VerifierError::MalformedSeal)));
+        });
+
+        let event = SealLengthMismatch {
+            expected: 260,
+            actual,
+        };
+        assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+    }
+}
+
+#[test]
+fn test_seal_to_bytes_round_trip() {
+    let env = Env::default();
+    let seal = Bytes::from_slice(&env, &TEST_SEAL);
+
+    let decoded = Groth16Seal::try_from(seal.clone()).unwrap();
+    assert_eq!(decoded.to_bytes(&env), seal);
+
+    let proof = test_proof(&env);
+    assert_eq!(proof.to_bytes(&env), seal.slice(4..));
+}
+
+#[test]
+fn test_public_sizes_match_encoding() {
+    let env = Env::default();
+    let proof = test_proof(&env);
+
+    assert_eq!(proof.a.to_array().len(), G1_SIZE);
+    assert_eq!(proof.b.to_array().len(), G2_SIZE);
+    assert_eq!(proof.to_bytes(&env).len() as usize, PROOF_SIZE);
+    assert_eq!(
+        (SEAL_SIZE, PROOF_SIZE, G1_SIZE, G2_SIZE),
+        (260, 256, 64, 128)
+    );
+}
+
+#[test]
+fn test_seal_to_bytes_encodes_modified_proof() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+
+    // Negating only `A` yields a well-formed seal whose pairing check fails.
+    let mut decoded = Groth16Seal::try_from(seal).unwrap();
+    decoded.proof.a = -decoded.proof.a;
+    let seal = decoded.to_bytes(&env);
+
+    let result = client.try_verify(&seal, &image_id, &journal_digest);
+    assert_eq!(result, Err(Ok(VerifierError::InvalidProof)));
+}
+
+#[test]
+fn test_decode_valid_seal_publishes_nothing() {
+    let (env, client) = setup_test();
+    let (seal, _image_id, _journal_digest) = prepare_inputs(&env);
+
+    env.as_contract(&client.address, || {
+        assert!(Groth16Seal::decode(&env, seal).is_ok());
+    });
+    assert!(env.events().all().events().is_empty());
+}
+
+// ============================================================================
+// DEPRECATION TESTS
+// ============================================================================
+
+#[test]
+fn test_deprecate_warns_but_keeps_verifying() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+    assert!(!client.is_deprecated());
+
+    env.mock_all_auths();
+    client.deprecate(&None);
+    let since_ledger = env.ledger().sequence();
+    let event = Deprecated {
+        since_ledger,
+        sunset_ledger: None,
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+    assert!(client.is_deprecated());
+    assert_eq!(
+        client.deprecation(),
+        Some(Deprecation {
+            since_ledger,
+            sunset_ledger: None,
+        })
+    );
+
+    client.verify(&seal, &image_id, &journal_digest);
+    let event = DeprecatedVerifierUsed {
+        sunset_ledger: None,
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_deprecate_fails_after_grace_period() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+    let start = env.ledger().sequence();
+
+    env.mock_all_auths();
+    client.deprecate(&Some(100));
+
+    env.ledger().set_sequence_number(start + 99);
+    client.verify(&seal, &image_id, &journal_digest);
+    let event = DeprecatedVerifierUsed {
+        sunset_ledger: Some(start + 100),
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+
+    env.ledger().set_sequence_number(start + 100);
+    let result = client.try_verify(&seal, &image_id, &journal_digest);
+    assert_eq!(result, Err(Ok(VerifierError::Deprecated)));
+}
+
+#[test]
+#[should_panic]
+fn test_deprecate_requires_admin_auth() {
+    let (_env, client) = setup_test();
+
+    // Should trap on admin.require_auth().
+    client.deprecate(&None);
+}
+
+// ============================================================================
+// VERSION MISMATCH DIAGNOSTICS TESTS
+// ============================================================================
+
+/// Control root embedded from `parameters.json` (risc0 v3.0.0).
+const TEST_CONTROL_ROOT: &str = "a54dc85ac99f851c92d7c96d7318af41dbe7c0194edfcc37eb4d422a998c1f56";
+
+fn known_control_root(env: &Env, version: &str, control_root: [u8; 32]) -> KnownControlRoot {
+    KnownControlRoot {
+        version: String::from_str(env, version),
+        control_root: BytesN::from_array(env, &control_root),
+    }
+}
+
+#[test]
+fn test_set_known_control_roots() {
+    let (env, client) = setup_test();
+    assert!(client.known_control_roots().is_empty());
+
+    env.mock_all_auths();
+    let roots = vec![&env, known_control_root(&env, "2.0.0", [0x11; 32])];
+    client.set_known_control_roots(&roots);
+    assert_eq!(client.known_control_roots(), roots);
+
+    client.set_known_control_roots(&vec![&env]);
+    assert!(client.known_control_roots().is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_set_known_control_roots_requires_admin_auth() {
+    let (env, client) = setup_test();
+
+    // Should trap on admin.require_auth().
+    client.set_known_control_roots(&vec![&env]);
+}
+
+#[test]
+fn test_diagnose_version_mismatch_finds_matching_root() {
+    let (env, client) = setup_test();
+    let (_seal, image_id, journal_digest) = prepare_inputs(&env);
+    let claim_digest = ReceiptClaim::new(&env, image_id, journal_digest).digest(&env);
+
+    // The test proof verifies under the embedded root, standing in for a proof of a release
+    // listed as known.
+    let control_root = hex::decode(TEST_CONTROL_ROOT).unwrap().try_into().unwrap();
+    let matching = known_control_root(&env, "3.0.0", control_root);
+    env.mock_all_auths();
+    client.set_known_control_roots(&vec![
+        &env,
+        known_control_root(&env, "2.0.0", [0x11; 32]),
+        matching.clone(),
+    ]);
+
+    env.as_contract(&client.address, || {
+        let known = diagnose_version_mismatch(&env, &HostPairing, &test_proof(&env), &claim_digest);
+        assert_eq!(known, Some(matching.clone()));
+    });
+
+    let event = LikelyVersionMismatch {
+        version: matching.version,
+        control_root: matching.control_root,
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_diagnose_version_mismatch_without_match_publishes_nothing() {
+    let (env, client) = setup_test();
+    let (seal, image_id, _journal_digest) = prepare_inputs(&env);
+    let claim_digest = ReceiptClaim::new(
+        &env,
+        image_id.clone(),
+        BytesN::from_array(&env, &[0x42; 32]),
+    )
+    .digest(&env);
+
+    env.mock_all_auths();
+    client.set_known_control_roots(&vec![&env, known_control_root(&env, "2.0.0", [0x11; 32])]);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            diagnose_version_mismatch(&env, &HostPairing, &test_proof(&env), &claim_digest),
+            None
+        );
+    });
+    assert!(env.events().all().events().is_empty());
+
+    // Verification still fails as usual.
+    let result = client.try_verify(&seal, &image_id, &BytesN::from_array(&env, &[0x42; 32]));
+    assert_eq!(result, Err(Ok(VerifierError::InvalidProof)));
+}
+
+// ============================================================================
+// AUDIT BUILD TESTS
+// ============================================================================
+
+#[cfg(feature = "audit")]
+#[test]
+fn test_audit_accepts_valid_proof() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+
+    env.as_contract(&client.address, || {
+        assert!(crate::audit::check_proof(&env, &test_proof(&env)).is_ok());
+    });
+    client.verify(&seal, &image_id, &journal_digest);
+}
+
+#[cfg(feature = "audit")]
+#[test]
+fn test_audit_rejects_non_canonical_coordinate() {
+    let (env, client) = setup_test();
+
+    // Replace A.x with the base field modulus, which the host would reduce or reject.
+    let mut seal = TEST_SEAL;
+    seal[4..36].copy_from_slice(
+        &hex::decode("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47").unwrap(),
+    );
+    let proof = Groth16Seal::try_from(Bytes::from_slice(&env, &seal))
+        .unwrap()
+        .proof;
+
+    env.as_contract(&client.address, || {
+        let result = crate::audit::check_proof(&env, &proof);
+        assert!(matches!(result, Err(VerifierError::MalformedSeal)));
+    });
+}
+
+// ============================================================================
+// FR HELPER TESTS
+// ============================================================================
+
+/// The BN254 scalar field modulus `r`.
+const R_HEX: &str = "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+
+/// `r - 1`, the largest canonical scalar.
+const R_MINUS_ONE_HEX: &str = "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000";
+
+#[test]
+fn test_fr_from_u64() {
+    let env = Env::default();
+    let mut bytes = [0u8; 32];
+    bytes[24..].copy_from_slice(&0x0102_0304_0506_0708u64.to_be_bytes());
+
+    assert_eq!(
+        Fr::from_u64(&env, 0x0102_0304_0506_0708),
+        Fr::from_bytes(BytesN::from_array(&env, &bytes))
+    );
+}
+
+#[test]
+fn test_fr_from_hex() {
+    let env = Env::default();
+    let expected = Fr::from_u64(&env, 0xAB);
+    let hex = "00000000000000000000000000000000000000000000000000000000000000ab";
+
+    assert_eq!(Fr::from_hex(&env, hex), Ok(expected.clone()));
+    assert_eq!(
+        Fr::from_hex(&env, &std::format!("0x{}", hex.to_uppercase())),
+        Ok(expected)
+    );
+    assert!(Fr::from_hex(&env, R_MINUS_ONE_HEX).is_ok());
+}
+
+#[test]
+fn test_fr_from_hex_rejects_malformed() {
+    let env = Env::default();
+    let not_hex = "zz000000000000000000000000000000000000000000000000000000000000ab";
+
+    for hex in ["ab", not_hex, R_HEX] {
+        assert_eq!(
+            Fr::from_hex(&env, hex),
+            Err(VerifierError::MalformedPublicInputs),
+            "{hex}"
+        );
+    }
+}
+
+#[test]
+fn test_fr_try_from_bytes() {
+    let env = Env::default();
+    let bytes =
+        |hex: &str| BytesN::from_array(&env, &hex::decode(hex).unwrap().try_into().unwrap());
+
+    assert!(Fr::is_canonical(&bytes(R_MINUS_ONE_HEX)));
+    assert!(!Fr::is_canonical(&bytes(R_HEX)));
+    assert_eq!(
+        Fr::try_from_bytes(bytes(R_MINUS_ONE_HEX)),
+        Ok(Fr::from_bytes(bytes(R_MINUS_ONE_HEX)))
+    );
+    assert_eq!(
+        Fr::try_from_bytes(bytes(R_HEX)),
+        Err(VerifierError::MalformedPublicInputs)
+    );
+}
+
+#[test]
+fn test_bn254_constants() {
+    let env = Env::default();
+    let bytes =
+        |hex: &str| BytesN::from_array(&env, &hex::decode(hex).unwrap().try_into().unwrap());
+
+    assert_eq!(Fr::MODULUS, bn254::SCALAR_FIELD_MODULUS);
+    assert_eq!(bn254::scalar_field_modulus(&env), bytes(R_HEX));
+    assert!(bn254::is_valid_scalar(&bytes(R_MINUS_ONE_HEX)));
+    assert!(!bn254::is_valid_scalar(&bn254::scalar_field_modulus(&env)));
+
+    let p_minus_one = "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46";
+    assert!(bn254::is_valid_base_field_element(&bytes(p_minus_one)));
+    assert!(!bn254::is_valid_base_field_element(
+        &bn254::base_field_modulus(&env)
+    ));
+
+    // The generator is on the curve: doubling it through the host matches `[2]G`.
+    let bn = env.crypto().bn254();
+    let generator = Bn254G1Affine::from_array(&env, &bn254::G1_GENERATOR);
+    assert_eq!(
+        bn.g1_add(&generator, &generator),
+        bn.g1_mul(&generator, &Fr::from_u64(&env, 2))
+    );
+}
+
+#[test]
+fn test_msm_chunking_matches_scalar_sum() {
+    const PUBLIC_INPUTS: u64 = 60;
+
+    let env = Env::default();
+    // Checks the arithmetic over several runs; the cost of one run is covered by the budget
+    // tests.
+    env.cost_estimate().budget().reset_unlimited();
+    let bn = env.crypto().bn254();
+    let generator = Bn254G1Affine::from_array(&env, &bn254::G1_GENERATOR);
+
+    // IC[i] = [i + 1]G and s_i = i + 1, so vk_x = [1 + sum_i (i + 1) * (i + 2)]G.
+    let ic: std::vec::Vec<[u8; G1_SIZE]> = (1..=PUBLIC_INPUTS + 1)
+        .map(|k| bn.g1_mul(&generator, &Fr::from_u64(&env, k)).to_array())
+        .collect();
+    let mut scalars = Vec::new(&env);
+    for k in 1..=PUBLIC_INPUTS {
+        scalars.push_back(Fr::from_u64(&env, k));
+    }
+    let expected_scalar = 1 + (1..=PUBLIC_INPUTS).map(|k| k * (k + 1)).sum::<u64>();
+    let expected = bn.g1_mul(&generator, &Fr::from_u64(&env, expected_scalar));
+
+    assert_eq!(
+        msm::linear_combination(&env, &ic, &scalars),
+        Ok(expected.clone())
+    );
+    for chunk_size in [1, 7, PUBLIC_INPUTS as usize, 2 * PUBLIC_INPUTS as usize] {
+        assert_eq!(
+            msm::linear_combination_chunked(&env, &ic, &scalars, chunk_size),
+            Ok(expected.clone()),
+            "chunk size {chunk_size}"
+        );
+    }
+
+    scalars.pop_back();
+    assert_eq!(
+        msm::linear_combination(&env, &ic, &scalars),
+        Err(VerifierError::MalformedPublicInputs)
+    );
+    assert_eq!(
+        msm::linear_combination(&env, &[], &Vec::new(&env)),
+        Err(VerifierError::MalformedPublicInputs)
+    );
+}
+
+#[cfg(feature = "ark")]
+#[test]
+fn test_bn254_moduli_match_ark() {
+    use ark_ff::{BigInteger, PrimeField};
+
+    let r = ark_bn254::Fr::MODULUS.to_bytes_be();
+    let p = ark_bn254::Fq::MODULUS.to_bytes_be();
+    assert_eq!(r.as_slice(), bn254::SCALAR_FIELD_MODULUS);
+    assert_eq!(p.as_slice(), bn254::BASE_FIELD_MODULUS);
+}
+
+/// Expected values computed with Python integers.
+#[test]
+fn test_fr_arithmetic() {
+    let env = Env::default();
+    let fr = |hex: &str| Fr::from_hex(&env, hex).unwrap();
+    let zero = Fr::from_u64(&env, 0);
+    let one = Fr::from_u64(&env, 1);
+    let r_minus_one = fr(R_MINUS_ONE_HEX);
+    // SHA-256("a") and SHA-256("b") reduced mod r.
+    let a = fr("0906474745553d2419811ad9941e7ad906b74ed62d968c2da9f8a135efee48b7");
+    let b = fr("0dbf99a31f07b9207b3909aee36058d7638991b80f1abbb98791f91ae59c009c");
+
+    assert_eq!(
+        a.add_mod(&b),
+        fr("16c5e0ea645cf64494ba2488777ed3b06a40e08e3cb147e7318a9a50d58a4953")
+    );
+    assert_eq!(
+        a.sub_mod(&b),
+        fr("2baafc17077f242d569856e1323f7a5ecb61a5669835410566489daefa52481c")
+    );
+    assert_eq!(
+        a.mul_mod(&b),
+        fr("2a4b7a6630249d4f2ad9cf61e647d7c553c5dcd3b29aa1402f9dcd4c4791433c")
+    );
+    assert_eq!(
+        a.neg_mod(),
+        fr("275e072b9bdc63059ecf2adced62dd84217c99724c22e46399e9545e0011b74a")
+    );
+
+    // Wrap-around at the modulus.
+    assert_eq!(r_minus_one.add_mod(&one), zero);
+    assert_eq!(zero.sub_mod(&one), r_minus_one);
+    assert_eq!(zero.neg_mod(), zero);
+    assert_eq!(r_minus_one.mul_mod(&r_minus_one), one);
+    assert_eq!(r_minus_one.mul_mod(&zero), zero);
+    assert_eq!(a.mul_mod(&one), a);
+}
+
+#[cfg(feature = "ark")]
+#[test]
+fn test_fr_arithmetic_matches_ark() {
+    let env = Env::default();
+    let elements: std::vec::Vec<Fr> = (0u8..8)
+        .map(|i| Fr::from_bytes(env.crypto().sha256(&Bytes::from_array(&env, &[i])).into()))
+        .chain([
+            Fr::from_u64(&env, 0),
+            Fr::from_hex(&env, R_MINUS_ONE_HEX).unwrap(),
+        ])
+        .collect();
+
+    for a in &elements {
+        assert_eq!(a.neg_mod().to_ark(), -a.to_ark());
+        for b in &elements {
+            assert_eq!(a.add_mod(b).to_ark(), a.to_ark() + b.to_ark());
+            assert_eq!(a.sub_mod(b).to_ark(), a.to_ark() - b.to_ark());
+            assert_eq!(a.mul_mod(b).to_ark(), a.to_ark() * b.to_ark());
+        }
+    }
+}
+
+#[cfg(feature = "ark")]
+#[test]
+fn test_fr_to_ark() {
+    let env = Env::default();
+
+    assert_eq!(Fr::from_u64(&env, 42).to_ark(), ark_bn254::Fr::from(42u64));
+}
+
+/// Public signals of 0, 1 and `r - 1` must fold into the same `vk_x` as arkworks computes
+/// from the embedded key, so that a limb-ordering regression in the scalar encoding cannot go
+/// unnoticed on the values where it is least visible.
+#[cfg(feature = "ark")]
+#[test]
+fn test_verify_proof_edge_scalars_match_ark() {
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::PrimeField;
+
+    use crate::ark::{g1, g1_to_bytes};
+
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let r_minus_one: [u8; 32] = hex::decode(R_MINUS_ONE_HEX).unwrap().try_into().unwrap();
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    let cases = [
+        [[0u8; 32]; 5],
+        [one; 5],
+        [r_minus_one; 5],
+        [[0u8; 32], one, r_minus_one, one, [0u8; 32]],
+    ];
+
+    let ic = RiscZeroGroth16Verifier::VERIFICATION_KEY.ic;
+    let proof = Groth16Seal::try_from(Bytes::from_slice(&env, &TEST_SEAL))
+        .unwrap()
+        .proof;
+    for scalars in cases {
+        let expected = scalars
+            .iter()
+            .zip(&ic[1..])
+            .fold(g1(&ic[0]).into_group(), |acc, (scalar, point)| {
+                acc + g1(point) * ark_bn254::Fr::from_be_bytes_mod_order(scalar)
+            })
+            .into_affine();
+
+        let mut signals = Vec::new(&env);
+        for scalar in &scalars {
+            signals.push_back(Fr::from_bytes(BytesN::from_array(&env, scalar)));
+        }
+        let stub = StubPairing::accepting();
+        RiscZeroGroth16Verifier::verify_proof_with(&env, &stub, proof.clone(), signals).unwrap();
+
+        let (g1s, _g2s) = stub.last_input().unwrap();
+        assert_eq!(g1s.get(2).unwrap().to_array(), g1_to_bytes(&expected));
+    }
+}
+
+/// Returns the mirrored seal `(-A, -B, C)`, which proves the same claim.
+fn mirror_seal(env: &Env, seal: &[u8; 260]) -> [u8; 260] {
+    let neg = |bytes: &[u8]| -> [u8; 32] {
+        let fp = Bn254Fp::from_bytes(BytesN::from_array(env, &bytes.try_into().unwrap()));
+        (-fp).to_array()
+    };
+
+    // Seal layout: selector (4) || A (x, y) || B (x_0, x_1, y_0, y_1) || C (x, y)
+    let mut mirrored = *seal;
+    for offset in [36, 132, 164] {
+        let negated = neg(&seal[offset..offset + 32]);
+        mirrored[offset..offset + 32].copy_from_slice(&negated);
+    }
+    mirrored
+}
+
+#[test]
+fn test_mirrored_proof_is_valid() {
+    let (env, client) = setup_test();
+    let (_seal, image_id, journal_digest) = prepare_inputs(&env);
+    let mirrored = Bytes::from_slice(&env, &mirror_seal(&env, &TEST_SEAL));
+
+    assert_eq!(client.verify(&mirrored, &image_id, &journal_digest), ());
+}
+
+#[test]
+fn test_verify_strict_accepts_only_canonical_form() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+    let mirrored = Bytes::from_slice(&env, &mirror_seal(&env, &TEST_SEAL));
+
+    // The test seal has the y-coordinate of `A` in the upper half of the field.
+    let result = client.try_verify_strict(&seal, &image_id, &journal_digest);
+    assert_eq!(result, Err(Ok(VerifierError::NonCanonicalProof)));
+    assert_eq!(
+        client.verify_strict(&mirrored, &image_id, &journal_digest),
+        ()
+    );
+}
+
+#[test]
+fn test_verify_integrity_strict_accepts_only_canonical_form() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+    let claim_digest =
+        risc0_interface::ReceiptClaim::new(&env, image_id, journal_digest).digest(&env);
+    let mirrored = Bytes::from_slice(&env, &mirror_seal(&env, &TEST_SEAL));
+
+    let receipt = risc0_interface::Receipt {
+        seal,
+        claim_digest: claim_digest.clone(),
+    };
+    let result = client.try_verify_integrity_strict(&receipt);
+    assert_eq!(result, Err(Ok(VerifierError::NonCanonicalProof)));
+
+    let receipt = risc0_interface::Receipt {
+        seal: mirrored,
+        claim_digest,
+    };
+    assert_eq!(client.verify_integrity_strict(&receipt), ());
+}
+
+// ============================================================================
+// PUBLIC SIGNALS TESTS
+// ============================================================================
+
+/// `reverseByteOrderUint256` of the Ethereum verifier, on a `uint256` held as `(high, low)`
+/// 128-bit halves: the same mask-and-shift swaps of bytes, then pairs, then 4- and 8-byte
+/// groups, then the two halves. Independent of [`split_digest`], which reverses a byte array.
+fn reverse_byte_order_uint256((high, low): (u128, u128)) -> (u128, u128) {
+    let swap = |v: u128| {
+        let v = ((v & 0xFF00FF00FF00FF00FF00FF00FF00FF00) >> 8)
+            | ((v & 0x00FF00FF00FF00FF00FF00FF00FF00FF) << 8);
+        let v = ((v & 0xFFFF0000FFFF0000FFFF0000FFFF0000) >> 16)
+            | ((v & 0x0000FFFF0000FFFF0000FFFF0000FFFF) << 16);
+        let v = ((v & 0xFFFFFFFF00000000FFFFFFFF00000000) >> 32)
+            | ((v & 0x00000000FFFFFFFF00000000FFFFFFFF) << 32);
+        // Swapping the 8-byte halves of a 16-byte lane is a rotation.
+        v.rotate_left(64)
+    };
+    (swap(low), swap(high))
+}
+
+/// `splitDigest` of the Ethereum verifier: `(uint128(reversed), uint128(reversed >> 128))`,
+/// each as the `uint256` public signal the circuit takes.
+fn solidity_split_digest(digest: &[u8; 32]) -> ([u8; 32], [u8; 32]) {
+    let high = u128::from_be_bytes(digest[..16].try_into().unwrap());
+    let low = u128::from_be_bytes(digest[16..].try_into().unwrap());
+    let (reversed_high, reversed_low) = reverse_byte_order_uint256((high, low));
+
+    let signal = |value: u128| {
+        let mut signal = [0u8; 32];
+        signal[16..].copy_from_slice(&value.to_be_bytes());
+        signal
+    };
+    (signal(reversed_low), signal(reversed_high))
+}
+
+fn assert_split_digest_matches_solidity(env: &Env, digest: [u8; 32]) {
+    let (claim_0, claim_1) = split_digest(env, BytesN::from_array(env, &digest));
+    let (expected_0, expected_1) = solidity_split_digest(&digest);
+    assert_eq!(
+        (claim_0.to_array(), claim_1.to_array()),
+        (expected_0, expected_1),
+        "digest {}",
+        hex::encode(digest)
+    );
+}
+
+#[test]
+fn test_split_digest_matches_solidity() {
+    let env = Env::default();
+
+    let mut digests = std::vec![
+        [0x00; 32],
+        [0xFF; 32],
+        core::array::from_fn(|i| i as u8),
+        core::array::from_fn(|i| 0xFF - i as u8),
+        core::array::from_fn(|i| if i < 16 { 0xFF } else { 0x00 }),
+        core::array::from_fn(|i| if i < 16 { 0x00 } else { 0xFF }),
+        core::array::from_fn(|i| if i % 2 == 0 { 0xA5 } else { 0x5A }),
+    ];
+    // Every byte position on its own, to pin where each byte of the digest ends up.
+    for position in 0..32 {
+        let mut digest = [0u8; 32];
+        digest[position] = 0x80 | position as u8;
+        digests.push(digest);
+    }
+    // Pseudo-random digests from a SHA-256 chain.
+    let mut digest = [0u8; 32];
+    for _ in 0..256 {
+        digest = env
+            .crypto()
+            .sha256(&Bytes::from_array(&env, &digest))
+            .to_array();
+        digests.push(digest);
+    }
+
+    for digest in digests {
+        assert_split_digest_matches_solidity(&env, digest);
+    }
+}
+
+/// The reference itself, against the `splitDigest(0x000102..1f)` vector of the Ethereum
+/// verifier.
+#[test]
+fn test_solidity_split_digest_vector() {
+    let (claim_0, claim_1) = solidity_split_digest(&core::array::from_fn(|i| i as u8));
+
+    assert_eq!(
+        hex::encode(claim_0),
+        "000000000000000000000000000000000f0e0d0c0b0a09080706050403020100"
+    );
+    assert_eq!(
+        hex::encode(claim_1),
+        "000000000000000000000000000000001f1e1d1c1b1a19181716151413121110"
+    );
+}
+
+/// Parses hex field elements, as written in the Ethereum verifier's sources.
+fn signals(env: &Env, hex: [&str; 5]) -> [Fr; 5] {
+    hex.map(|hex| Fr::from_hex(env, hex).unwrap())
+}
+
+#[test]
+fn test_build_public_signals_matches_ethereum_vectors() {
+    let env = Env::default();
+    let claim_digest = BytesN::from_array(&env, &core::array::from_fn(|i| i as u8));
+
+    // `CONTROL_ROOT_0`, `CONTROL_ROOT_1` and `BN254_CONTROL_ID` of the Ethereum verifier for
+    // risc0 v3.0.0, and `splitDigest(0x000102..1f)`.
+    let expected = signals(
+        &env,
+        [
+            "0x0000000000000000000000000000000041af18736dc9d7921c859fc95ac84da5",
+            "0x00000000000000000000000000000000561f8c992a424deb37ccdf4e19c0e7db",
+            "0x000000000000000000000000000000000f0e0d0c0b0a09080706050403020100",
+            "0x000000000000000000000000000000001f1e1d1c1b1a19181716151413121110",
+            "0x04446e66d300eb7fb45c9726bb53c793dda407a62e9601618bb43c5c14657ac0",
+        ],
+    );
+    assert_eq!(build_public_signals(&env, &claim_digest), expected);
+}
+
+#[test]
+fn test_build_public_signals_splits_claim_digest() {
+    let env = Env::default();
+    let zero = build_public_signals(&env, &BytesN::from_array(&env, &[0u8; 32]));
+
+    // The first digest byte is the lowest byte of `claim_0`, the last the highest of `claim_1`.
+    let mut digest = [0u8; 32];
+    digest[0] = 0xAB;
+    digest[31] = 0xCD;
+    let signals = build_public_signals(&env, &BytesN::from_array(&env, &digest));
+
+    let mut claim_0 = [0u8; 32];
+    claim_0[31] = 0xAB;
+    let mut claim_1 = [0u8; 32];
+    claim_1[16] = 0xCD;
+    assert_eq!(signals[..2], zero[..2]);
+    assert_eq!(
+        signals[2],
+        Fr::from_bytes(BytesN::from_array(&env, &claim_0))
+    );
+    assert_eq!(
+        signals[3],
+        Fr::from_bytes(BytesN::from_array(&env, &claim_1))
+    );
+    assert_eq!(signals[4], zero[4]);
+}
+
+#[test]
+fn test_hash_to_fr_matches_claim_signals() {
+    let env = Env::default();
+    let preimage = Bytes::from_slice(&env, b"custom circuit input");
+    let digest: BytesN<32> = env.crypto().sha256(&preimage).into();
+
+    // The same mapping as the claim digest of a RISC Zero proof.
+    let signals = build_public_signals(&env, &digest);
+    assert_eq!(
+        digest_to_fr(&env, &digest),
+        [signals[2].clone(), signals[3].clone()]
+    );
+    assert_eq!(hash_to_fr(&env, &preimage), digest_to_fr(&env, &digest));
+
+    // Both halves are below 2^128, so the mapping never reduces.
+    let all_ones = digest_to_fr(&env, &BytesN::from_array(&env, &[0xFF; 32]));
+    let mut max = [0u8; 32];
+    max[16..].fill(0xFF);
+    assert_eq!(
+        all_ones,
+        [
+            Fr::from_bytes(BytesN::from_array(&env, &max)),
+            Fr::from_bytes(BytesN::from_array(&env, &max))
+        ]
+    );
+}
+
+#[test]
+fn test_build_public_signals_verifies_test_proof() {
+    let (env, client) = setup_test();
+    let (_seal, image_id, journal_digest) = prepare_inputs(&env);
+    let claim_digest = ReceiptClaim::new(&env, image_id.clone(), journal_digest).digest(&env);
+    let other_digest =
+        ReceiptClaim::new(&env, image_id, BytesN::from_array(&env, &[0x42; 32])).digest(&env);
+
+    let pub_signals = build_public_signals(&env, &claim_digest);
+    assert!(client.verify_proof(&test_proof(&env), &Vec::from_array(&env, pub_signals)));
+    let pub_signals = build_public_signals(&env, &other_digest);
+    assert!(!client.verify_proof(&test_proof(&env), &Vec::from_array(&env, pub_signals)));
+}
+
+// ============================================================================
+// PAIRING BACKEND TESTS
+// ============================================================================
+
+/// Returns the receipt of the test seal, for a claim the proof does not prove unless
+/// `journal_digest` is the test journal's.
+fn test_receipt(env: &Env, journal_digest: BytesN<32>) -> Receipt {
+    let (seal, image_id, _journal_digest) = prepare_inputs(env);
+    Receipt {
+        seal,
+        claim_digest: ReceiptClaim::new(env, image_id, journal_digest).digest(env),
+    }
+}
+
+#[test]
+fn test_stub_pairing_decides_result() {
+    let (env, client) = setup_test();
+    let receipt = test_receipt(&env, BytesN::from_array(&env, &[0x42; 32]));
+
+    env.as_contract(&client.address, || {
+        let accepting = StubPairing::accepting();
+        let result =
+            RiscZeroGroth16Verifier::verify_integrity_with(&env, &accepting, receipt.clone());
+        assert_eq!(result, Ok(()));
+        assert_eq!(accepting.calls(), 1);
+
+        let rejecting = StubPairing::rejecting();
+        let result = RiscZeroGroth16Verifier::verify_integrity_with(&env, &rejecting, receipt);
+        assert_eq!(result, Err(VerifierError::InvalidProof));
+        assert_eq!(rejecting.calls(), 1);
+    });
+}
+
+#[test]
+fn test_stub_pairing_not_called_for_rejected_seal() {
+    let (env, client) = setup_test();
+    let mut receipt = test_receipt(&env, BytesN::from_array(&env, &[0x42; 32]));
+    let stub = StubPairing::accepting();
+
+    env.as_contract(&client.address, || {
+        receipt.seal.set(0, 0xFF);
+        let result = RiscZeroGroth16Verifier::verify_integrity_with(&env, &stub, receipt.clone());
+        assert_eq!(result, Err(VerifierError::InvalidSelector));
+
+        receipt.seal = prepare_inputs(&env).0;
+        receipt.seal.push_back(0);
+        let result = RiscZeroGroth16Verifier::verify_integrity_with(&env, &stub, receipt);
+        assert_eq!(result, Err(VerifierError::MalformedSeal));
+
+        let result = RiscZeroGroth16Verifier::verify_proof_with(
+            &env,
+            &stub,
+            test_proof(&env),
+            vec![&env, Fr::from_u64(&env, 1)],
+        );
+        assert_eq!(result, Err(VerifierError::MalformedPublicInputs));
+    });
+    assert_eq!(stub.calls(), 0);
+}
+
+#[test]
+fn test_stub_pairing_counts_diagnostic_pairings() {
+    let (env, client) = setup_test();
+    let receipt = test_receipt(&env, BytesN::from_array(&env, &[0x42; 32]));
+    env.mock_all_auths();
+    client.set_known_control_roots(&vec![
+        &env,
+        known_control_root(&env, "2.0.0", [0x11; 32]),
+        known_control_root(&env, "2.1.0", [0x22; 32]),
+    ]);
+
+    let stub = StubPairing::rejecting();
+    env.as_contract(&client.address, || {
+        let result = RiscZeroGroth16Verifier::verify_integrity_with(&env, &stub, receipt);
+        assert_eq!(result, Err(VerifierError::InvalidProof));
+    });
+    assert_eq!(stub.calls(), 3);
+}
+
+#[test]
+fn test_stub_pairing_records_groth16_equation() {
+    let (env, client) = setup_test();
+    let receipt = test_receipt(&env, prepare_inputs(&env).2);
+    let proof = test_proof(&env);
+    let stub = StubPairing::accepting();
+
+    env.as_contract(&client.address, || {
+        RiscZeroGroth16Verifier::verify_integrity_with(&env, &stub, receipt).unwrap();
+    });
+
+    // `e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta)`, which holds for the test
+    // proof of its own claim.
+    let (g1s, g2s) = stub.last_input().unwrap();
+    assert_eq!(g1s.get_unchecked(0), -proof.a);
+    assert_eq!(g1s.get_unchecked(3), proof.c);
+    assert_eq!(g2s.get_unchecked(0), proof.b);
+    assert!(HostPairing.check_pairing(g1s, g2s));
+}
+
+#[cfg(feature = "pairing-check")]
+#[test]
+fn test_bn254_pairing_check_entrypoint() {
+    let (env, client) = setup_test();
+    let receipt = test_receipt(&env, prepare_inputs(&env).2);
+    let stub = StubPairing::accepting();
+    env.as_contract(&client.address, || {
+        RiscZeroGroth16Verifier::verify_integrity_with(&env, &stub, receipt).unwrap();
+    });
+
+    let (mut g1s, g2s) = stub.last_input().unwrap();
+    assert!(client.bn254_pairing_check(&g1s, &g2s));
+
+    g1s.set(0, -g1s.get_unchecked(0));
+    assert!(!client.bn254_pairing_check(&g1s, &g2s));
+}
+
+#[cfg(feature = "ark")]
+#[test]
+fn test_ark_pairing_matches_host() {
+    use crate::pairing::ArkPairing;
+
+    let (env, client) = setup_test();
+    let stub = StubPairing::accepting();
+    let cases = [
+        (prepare_inputs(&env).2, true),
+        (BytesN::from_array(&env, &[0x42; 32]), false),
+    ];
+    for (journal_digest, valid) in cases {
+        let receipt = test_receipt(&env, journal_digest);
+        env.as_contract(&client.address, || {
+            RiscZeroGroth16Verifier::verify_integrity_with(&env, &stub, receipt).unwrap();
+        });
+
+        let (g1s, g2s) = stub.last_input().unwrap();
+        assert_eq!(ArkPairing.check_pairing(g1s.clone(), g2s.clone()), valid);
+        assert_eq!(HostPairing.check_pairing(g1s, g2s), valid);
+    }
+}
+
+// ============================================================================
+// KZG TESTS
+// ============================================================================
+
+/// Opens `p(X) = 3 + X + 4X^2 + X^3 + 5X^4` at a point, with a setup and proof computed with
+/// arkworks, and checks that a wrong value or point is rejected.
+#[cfg(feature = "ark")]
+#[test]
+fn test_kzg_verify_opening() {
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::{BigInteger, PrimeField};
+    use soroban_sdk::crypto::bn254::Bn254G2Affine;
+
+    use crate::{
+        ark::{g1_to_bytes, g2_to_bytes},
+        kzg::{self, KzgOpening, KzgVerifierKey},
+        pairing::ArkPairing,
+    };
+
+    fn eval(coeffs: &[ark_bn254::Fr], x: ark_bn254::Fr) -> ark_bn254::Fr {
+        coeffs
+            .iter()
+            .rev()
+            .fold(ark_bn254::Fr::from(0u64), |acc, c| acc * x + c)
+    }
+
+    let env = Env::default();
+    let fr = |value: ark_bn254::Fr| {
+        let bytes: [u8; 32] = value.into_bigint().to_bytes_be().try_into().unwrap();
+        Fr::from_bytes(BytesN::from_array(&env, &bytes))
+    };
+    let g1 = |scalar: ark_bn254::Fr| {
+        let point = (ark_bn254::G1Affine::generator() * scalar).into_affine();
+        Bn254G1Affine::from_array(&env, &g1_to_bytes(&point))
+    };
+    let g2 = |scalar: ark_bn254::Fr| {
+        let point = (ark_bn254::G2Affine::generator() * scalar).into_affine();
+        Bn254G2Affine::from_array(&env, &g2_to_bytes(&point))
+    };
+
+    let tau = ark_bn254::Fr::from(0x5eed_u64);
+    let z = ark_bn254::Fr::from(42u64);
+    let p: std::vec::Vec<ark_bn254::Fr> = [3u64, 1, 4, 1, 5].map(ark_bn254::Fr::from).to_vec();
+    let y = eval(&p, z);
+
+    // Synthetic division of p(X) - y by X - z.
+    let mut q = std::vec![ark_bn254::Fr::from(0u64); p.len() - 1];
+    let mut carry = ark_bn254::Fr::from(0u64);
+    for i in (1..p.len()).rev() {
+        carry = p[i] + carry * z;
+        q[i - 1] = carry;
+    }
+
+    let vk = KzgVerifierKey {
+        g2: g2(ark_bn254::Fr::from(1u64)),
+        tau_g2: g2(tau),
+    };
+    let commitment = g1(eval(&p, tau));
+    let opening = KzgOpening {
+        point: fr(z),
+        value: fr(y),
+        proof: g1(eval(&q, tau)),
+    };
+    assert!(kzg::verify_opening(
+        &env,
+        &HostPairing,
+        &vk,
+        &commitment,
+        &opening
+    ));
+    assert!(kzg::verify_opening(
+        &env,
+        &ArkPairing,
+        &vk,
+        &commitment,
+        &opening
+    ));
+
+    let wrong_value = KzgOpening {
+        value: fr(y + ark_bn254::Fr::from(1u64)),
+        ..opening.clone()
+    };
+    assert!(!kzg::verify_opening(
+        &env,
+        &HostPairing,
+        &vk,
+        &commitment,
+        &wrong_value
+    ));
+    let wrong_point = KzgOpening {
+        point: fr(z + ark_bn254::Fr::from(1u64)),
+        ..opening
+    };
+    assert!(!kzg::verify_opening(
+        &env,
+        &HostPairing,
+        &vk,
+        &commitment,
+        &wrong_point
+    ));
+}
+
+// ============================================================================
+// SEAL FORMAT TESTS
+// ============================================================================
+
+#[test]
+fn test_seal_format_detection() {
+    let env = Env::default();
+    let seal = Bytes::from_slice(&env, &TEST_SEAL);
+
+    assert_eq!(seal_format::detect(&seal), Some(SealFormat::Native));
+    let abi = seal_format::encode_abi(&env, &seal);
+    assert_eq!(abi.len(), 352);
+    assert_eq!(seal_format::detect(&abi), Some(SealFormat::EvmAbi));
+    let compressed = Bytes::from_slice(&env, &[0u8; 132]);
+    assert_eq!(
+        seal_format::detect(&compressed),
+        Some(SealFormat::Compressed)
+    );
+
+    assert_eq!(seal_format::detect(&seal.slice(..259)), None);
+    // An ABI header whose length disagrees with the encoding size.
+    let mut bad_length = abi.clone();
+    bad_length.set(62, 2);
+    assert_eq!(seal_format::detect(&bad_length), None);
+}
+
+#[test]
+fn test_normalize_abi_seal() {
+    let env = Env::default();
+    let seal = Bytes::from_slice(&env, &TEST_SEAL);
+    let abi = seal_format::encode_abi(&env, &seal);
+
+    assert_eq!(seal_format::normalize(&env, abi.clone()), Ok(seal.clone()));
+    assert_eq!(seal_format::normalize(&env, seal.clone()), Ok(seal));
+
+    let mut dirty_padding = abi;
+    dirty_padding.set(dirty_padding.len() - 1, 1);
+    assert_eq!(
+        seal_format::normalize(&env, dirty_padding),
+        Err(VerifierError::MalformedSeal)
+    );
+}
+
+#[test]
+fn test_seal_decoding_defaults_to_strict() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+    let abi = seal_format::encode_abi(&env, &seal);
+
+    assert_eq!(client.seal_decoding(), SealDecoding::Strict);
+    let result = client.try_verify(&abi, &image_id, &journal_digest);
+    assert_eq!(result, Err(Ok(VerifierError::InvalidSelector)));
+}
+
+#[test]
+fn test_permissive_seal_decoding_accepts_abi_seal() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+    let abi = seal_format::encode_abi(&env, &seal);
+    env.mock_all_auths();
+
+    client.set_seal_decoding(&SealDecoding::Permissive);
+    assert_eq!(client.seal_decoding(), SealDecoding::Permissive);
+    client.verify(&abi, &image_id, &journal_digest);
+    client.verify(&seal, &image_id, &journal_digest);
+    let mirrored = Bytes::from_slice(&env, &mirror_seal(&env, &TEST_SEAL));
+    let mirrored_abi = seal_format::encode_abi(&env, &mirrored);
+    client.verify_strict(&mirrored_abi, &image_id, &journal_digest);
+
+    client.set_seal_decoding(&SealDecoding::Strict);
+    let result = client.try_verify(&abi, &image_id, &journal_digest);
+    assert_eq!(result, Err(Ok(VerifierError::InvalidSelector)));
+}
+
+#[test]
+#[should_panic]
+fn test_set_seal_decoding_requires_admin_auth() {
+    let (_env, client) = setup_test();
+    client.set_seal_decoding(&SealDecoding::Permissive);
+}
+
+#[cfg(feature = "ark")]
+#[test]
+fn test_normalize_compressed_seal() {
+    let env = Env::default();
+    let seal = Bytes::from_slice(&env, &TEST_SEAL);
+
+    let compressed = seal_format::compress(&seal).unwrap();
+    assert_eq!(
+        seal_format::detect(&compressed),
+        Some(SealFormat::Compressed)
+    );
+    assert_eq!(seal_format::normalize(&env, compressed.clone()), Ok(seal));
+
+    // An x-coordinate of `A` above the base field modulus.
+    let mut invalid = compressed;
+    invalid.set(4, 0x3F);
+    assert_eq!(
+        seal_format::normalize(&env, invalid),
+        Err(VerifierError::MalformedSeal)
+    );
+}
+
+// ============================================================================
+// XDR GOLDEN TESTS - Wire Format Stability
+// ============================================================================
+
+/// Asserts that `xdr` matches the checked-in golden encoding.
+///
+/// A mismatch means the wire format of a `contracttype` changed (e.g. a field was renamed,
+/// retyped or removed), which breaks every off-chain client encoding these values. Update the
+/// golden file only for an intentional, versioned format change.
+fn assert_golden(xdr: Bytes, golden: &str) {
+    let mut bytes = std::vec![0u8; xdr.len() as usize];
+    xdr.copy_into_slice(&mut bytes);
+    assert_eq!(hex::encode(bytes), golden.trim());
+}
+
+/// Decodes the Groth16 proof embedded in the test seal.
+fn test_proof(env: &Env) -> Groth16Proof {
+    let seal = Bytes::from_slice(env, &TEST_SEAL);
+    Groth16Proof::try_from(seal.slice(4..)).unwrap()
+}
+
+#[test]
+fn test_receipt_xdr_golden() {
+    let env = Env::default();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+    let receipt = risc0_interface::Receipt {
+        seal,
+        claim_digest: risc0_interface::ReceiptClaim::new(&env, image_id, journal_digest)
+            .digest(&env),
+    };
+
+    assert_golden(
+        receipt.to_xdr(&env),
+        include_str!("../testdata/receipt.xdr.hex"),
+    );
+}
+
+#[test]
+fn test_groth16_proof_xdr_golden() {
+    let env = Env::default();
+
+    assert_golden(
+        test_proof(&env).to_xdr(&env),
+        include_str!("../testdata/groth16_proof.xdr.hex"),
+    );
+}
+
+#[test]
+fn test_fr_xdr_golden() {
+    let env = Env::default();
+    let fr = Fr::from_bytes(BytesN::from_array(&env, &TEST_IMAGE_ID));
+
+    assert_golden(fr.to_xdr(&env), include_str!("../testdata/fr.xdr.hex"));
+}
+
+#[test]
+fn test_g1_affine_xdr_golden() {
+    let env = Env::default();
+
+    assert_golden(
+        test_proof(&env).a.to_xdr(&env),
+        include_str!("../testdata/g1_affine.xdr.hex"),
+    );
+}
+
+#[test]
+fn test_g2_affine_xdr_golden() {
+    let env = Env::default();
+
+    assert_golden(
+        test_proof(&env).b.to_xdr(&env),
+        include_str!("../testdata/g2_affine.xdr.hex"),
+    );
+}
+
 // ============================================================================
 // BENCHMARKS - Gas Consumption Tracking
 // ============================================================================
@@ -115,3 +1478,188 @@ fn bench_receipt_claim_digest() {
     // Print results
     print_budget(&env, "ReceiptClaim::digest()");
 }
+
+#[cfg(feature = "trace")]
+#[test]
+fn bench_verify_integrity_traced() {
+    use soroban_sdk::{
+        TryFromVal,
+        xdr::{ContractEventBody, ScVal},
+    };
+
+    use crate::trace::StageTraced;
+
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+    let claim = ReceiptClaim::new(&env, image_id, journal_digest);
+    let receipt = Receipt {
+        seal,
+        claim_digest: claim.digest(&env),
+    };
+
+    assert_eq!(client.verify_integrity_traced(&receipt), ());
+
+    // Topics are the event name and the stage; the data is a map of the fields, by name.
+    let stages: std::vec::Vec<StageTraced> = env
+        .events()
+        .all()
+        .events()
+        .iter()
+        .map(|event| {
+            let ContractEventBody::V0(body) = &event.body;
+            let field = |index: usize| match &body.data {
+                ScVal::Map(Some(map)) => match map[index].val {
+                    ScVal::U64(value) => value,
+                    ref value => panic!("unexpected field: {value:?}"),
+                },
+                data => panic!("unexpected event data: {data:?}"),
+            };
+            StageTraced {
+                stage: Symbol::try_from_val(&env, &body.topics[1]).unwrap(),
+                cpu_instructions: field(0),
+                memory_bytes: field(1),
+            }
+        })
+        .collect();
+    for stage in &stages {
+        println!(
+            "{:>8}: {:>10} CPU instructions, {:>8} bytes",
+            stage.stage.to_string(),
+            stage.cpu_instructions,
+            stage.memory_bytes
+        );
+    }
+
+    let names: std::vec::Vec<_> = stages.iter().map(|s| s.stage.to_string()).collect();
+    assert_eq!(names, ["decode", "signals", "msm", "pairing"]);
+    // The pairing check dominates the cost of verification.
+    let pairing = stages[3].cpu_instructions;
+    assert!(stages[..3].iter().all(|s| s.cpu_instructions < pairing));
+}
+
+// ============================================================================
+// BUDGET BOUNDARIES
+// ============================================================================
+
+/// Per-transaction CPU instruction limit of the Stellar network.
+const NETWORK_CPU_LIMIT: u64 = 100_000_000;
+
+/// Per-transaction memory limit of the Stellar network, in bytes.
+const NETWORK_MEM_LIMIT: u64 = 41_943_040;
+
+/// CPU instructions a single verification must fit in (measured at ~35.3M).
+///
+/// Raising this requires checking that a router and an emergency stop in front of the
+/// verifier, plus the calling contract, still fit in [`NETWORK_CPU_LIMIT`].
+const VERIFY_CPU_BUDGET: u64 = 40_000_000;
+
+/// Memory a single verification must fit in, in bytes (measured at ~216KB).
+const VERIFY_MEM_BUDGET: u64 = 1_048_576;
+
+/// CPU instructions decoding a seal must fit in (measured at ~4.3K).
+///
+/// Decoding copies the seal out of the host once; slicing it into intermediate `Bytes`
+/// objects instead costs ~6.8K.
+const DECODE_CPU_BUDGET: u64 = 5_000;
+
+/// CPU instructions decoding the embedded verification key must fit in (measured at ~4K).
+const VK_DECODE_CPU_BUDGET: u64 = 5_000;
+
+const _: () = assert!(VERIFY_CPU_BUDGET <= NETWORK_CPU_LIMIT);
+const _: () = assert!(VERIFY_MEM_BUDGET <= NETWORK_MEM_LIMIT);
+
+#[test]
+fn budget_verify_fits() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+
+    env.cost_estimate()
+        .budget()
+        .reset_limits(VERIFY_CPU_BUDGET, VERIFY_MEM_BUDGET);
+    assert_eq!(client.verify(&seal, &image_id, &journal_digest), ());
+}
+
+#[test]
+fn budget_seal_decode_fits() {
+    let env = Env::default();
+    let seal = Bytes::from_slice(&env, &TEST_SEAL);
+
+    env.cost_estimate()
+        .budget()
+        .reset_limits(DECODE_CPU_BUDGET, VERIFY_MEM_BUDGET);
+    let decoded = Groth16Seal::try_from(seal.clone()).unwrap();
+
+    env.cost_estimate().budget().reset_unlimited();
+    assert_eq!(decoded.to_bytes(&env), seal);
+}
+
+// Caching the decoded key in instance storage would make every verification load it with the
+// instance, which costs more than decoding it from the embedded bytes (~16K against ~4K).
+#[test]
+fn budget_verification_key_decode_beats_instance_storage() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+
+    let decode_cost = env.as_contract(&client.address, || {
+        env.cost_estimate()
+            .budget()
+            .reset_limits(VK_DECODE_CPU_BUDGET, VERIFY_MEM_BUDGET);
+        RiscZeroGroth16Verifier::VERIFICATION_KEY.verification_key(&env);
+        env.cost_estimate().budget().cpu_instruction_cost()
+    });
+    env.cost_estimate().budget().reset_unlimited();
+    client.verify(&seal, &image_id, &journal_digest);
+    let uncached_cost = env.cost_estimate().budget().cpu_instruction_cost();
+
+    env.as_contract(&client.address, || {
+        let vk = RiscZeroGroth16Verifier::VERIFICATION_KEY.verification_key(&env);
+        let stored = (
+            vk.alpha.to_bytes(),
+            vk.beta.to_bytes(),
+            vk.gamma.to_bytes(),
+            vk.delta.to_bytes(),
+        );
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "cached_vk"), &stored);
+    });
+    env.cost_estimate().budget().reset_unlimited();
+    client.verify(&seal, &image_id, &journal_digest);
+    let cached_cost = env.cost_estimate().budget().cpu_instruction_cost();
+
+    assert!(cached_cost - uncached_cost > decode_cost);
+}
+
+#[test]
+fn budget_verify_integrity_fits() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+    let claim = risc0_interface::ReceiptClaim::new(&env, image_id, journal_digest);
+    let receipt = risc0_interface::Receipt {
+        seal,
+        claim_digest: claim.digest(&env),
+    };
+
+    env.cost_estimate()
+        .budget()
+        .reset_limits(VERIFY_CPU_BUDGET, VERIFY_MEM_BUDGET);
+    assert_eq!(client.verify_integrity(&receipt), ());
+}
+
+#[test]
+#[should_panic(expected = "Error(Budget, ExceededLimit)")]
+fn budget_verify_exhausted_below_boundary() {
+    let (env, client) = setup_test();
+    let (seal, image_id, journal_digest) = prepare_inputs(&env);
+
+    // The pairing check alone costs more than a quarter of the budget, so this limit must
+    // abort verification. If it no longer does, lower `VERIFY_CPU_BUDGET` to the new cost.
+    env.cost_estimate()
+        .budget()
+        .reset_limits(VERIFY_CPU_BUDGET / 4, VERIFY_MEM_BUDGET);
+    assert!(
+        client
+            .try_verify(&seal, &image_id, &journal_digest)
+            .is_err()
+    );
+}
@@ -0,0 +1,69 @@
+//! Per-stage cost tracing of verification (`trace` feature).
+//!
+//! [`verify_integrity_traced`](crate::RiscZeroGroth16Verifier::verify_integrity_traced) runs the
+//! same verification as `verify_integrity`, publishing a [`StageTraced`] event with the budget
+//! consumed by each stage as it completes:
+//!
+//! 1. `decode`: deprecation check, seal format and selector checks, proof decoding
+//! 2. `signals`: construction of the public signals from the claim digest
+//! 3. `msm`: folding of the public signals into the verification key's IC points
+//! 4. `pairing`: the final multi-pairing check
+//!
+//! A verification rejected at a stage publishes the events of the stages before it only.
+//!
+//! The budget is read through the `testutils` API of the Soroban SDK, which the feature
+//! enables: traced builds profile the contract natively, e.g. under the mainnet limits of the
+//! integration tests, and cannot be compiled to Wasm or deployed.
+
+use soroban_sdk::{Env, Symbol, contractevent};
+
+/// Event published when a stage of a traced verification completes.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StageTraced {
+    /// Name of the stage, see the [module documentation](self).
+    #[topic]
+    pub stage: Symbol,
+    /// CPU instructions consumed by the stage.
+    pub cpu_instructions: u64,
+    /// Memory consumed by the stage, in bytes.
+    pub memory_bytes: u64,
+}
+
+/// Publishes a [`StageTraced`] event for each completed stage.
+pub(crate) struct Tracer<'a> {
+    env: &'a Env,
+    cpu_instructions: u64,
+    memory_bytes: u64,
+}
+
+impl<'a> Tracer<'a> {
+    /// Starts tracing from the budget consumed so far.
+    pub(crate) fn start(env: &'a Env) -> Self {
+        let (cpu_instructions, memory_bytes) = consumed(env);
+        Self {
+            env,
+            cpu_instructions,
+            memory_bytes,
+        }
+    }
+
+    /// Publishes the budget consumed since the previous stage completed.
+    pub(crate) fn stage(&mut self, stage: &str) {
+        let (cpu_instructions, memory_bytes) = consumed(self.env);
+        let event = StageTraced {
+            stage: Symbol::new(self.env, stage),
+            cpu_instructions: cpu_instructions - self.cpu_instructions,
+            memory_bytes: memory_bytes - self.memory_bytes,
+        };
+        event.publish(self.env);
+        // Read again after publishing, so the next stage does not include the event.
+        (self.cpu_instructions, self.memory_bytes) = consumed(self.env);
+    }
+}
+
+/// Returns the CPU instructions and memory consumed so far.
+fn consumed(env: &Env) -> (u64, u64) {
+    let budget = env.cost_estimate().budget();
+    (budget.cpu_instruction_cost(), budget.memory_bytes_cost())
+}
@@ -1,48 +1,148 @@
-use core::array;
-
 use soroban_sdk::{
-    Bytes, BytesN, Env, contracttype,
+    Bytes, BytesN, Env, String, contractevent, contracttype,
     crypto::bn254::{Bn254G1Affine as G1Affine, Bn254G2Affine as G2Affine},
 };
 
+use risc0_constants::SELECTOR_SIZE;
 use risc0_interface::VerifierError;
 
-const SELECTOR_SIZE: usize = 4;
-const FIELD_ELEMENT_SIZE: usize = 32;
-const G1_SIZE: usize = FIELD_ELEMENT_SIZE * 2; // x, y
-const G2_SIZE: usize = FIELD_ELEMENT_SIZE * 4; // x_0, x_1, y_0, y_1
-const PROOF_SIZE: usize = G1_SIZE + G2_SIZE + G1_SIZE; // a, b, c
-const SEAL_SIZE: usize = SELECTOR_SIZE + PROOF_SIZE;
+use crate::bn254::FIELD_ELEMENT_SIZE;
+
+/// Size of an uncompressed G1 point, `x || y`.
+pub const G1_SIZE: usize = FIELD_ELEMENT_SIZE * 2;
+/// Size of an uncompressed G2 point, `x.c1 || x.c0 || y.c1 || y.c0`.
+pub const G2_SIZE: usize = FIELD_ELEMENT_SIZE * 4;
+/// Size of a Groth16 proof, `A || B || C`.
+pub const PROOF_SIZE: usize = G1_SIZE + G2_SIZE + G1_SIZE;
+/// Size of a seal, `selector || proof`.
+///
+/// Seals of any other length fail with [`VerifierError::MalformedSeal`] (unless a permissive
+/// [`SealDecoding`] normalizes them first), so encoders can reject them before submitting.
+pub const SEAL_SIZE: usize = SELECTOR_SIZE + PROOF_SIZE;
+
+/// `(p - 1) / 2` for the BN254 base field modulus `p`, in big-endian bytes.
+///
+/// A field element `y` is in the lower half when `y <= (p - 1) / 2`; exactly one of `y` and
+/// `p - y` satisfies this for any non-zero `y`.
+const HALF_FIELD_MODULUS: [u8; FIELD_ELEMENT_SIZE] = [
+    0x18, 0x32, 0x27, 0x39, 0x70, 0x98, 0xd0, 0x14, 0xdc, 0x28, 0x22, 0xdb, 0x40, 0xc0, 0xac, 0x2e,
+    0xcb, 0xc0, 0xb5, 0x48, 0xb4, 0x38, 0xe5, 0x46, 0x9e, 0x10, 0x46, 0x0b, 0x6c, 0x3e, 0x7e, 0xa3,
+];
+
+/// Build metadata embedded in the contract, see
+/// [`RiscZeroGroth16Verifier::build_info`](crate::RiscZeroGroth16Verifier::build_info).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BuildInfo {
+    /// Git commit the contract was built from, suffixed with `-dirty` for uncommitted changes.
+    pub git_commit: String,
+    /// Output of `rustc --version` for the compiler that built the contract.
+    pub rustc_version: String,
+    /// SHA-256 digest of the `parameters.json` the verification key was generated from.
+    pub parameters_digest: BytesN<32>,
+    /// Whether the contract was built with the `audit` feature's invariant assertions.
+    pub audit: bool,
+}
+
+/// Deprecation state of the verifier, see
+/// [`RiscZeroGroth16Verifier::deprecate`](crate::RiscZeroGroth16Verifier::deprecate).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Deprecation {
+    /// Ledger sequence at which the verifier was deprecated.
+    pub since_ledger: u32,
+    /// Ledger sequence from which verification fails, or `None` to keep verifying.
+    pub sunset_ledger: Option<u32>,
+}
+
+/// Event emitted when the admin deprecates the verifier.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deprecated {
+    /// Ledger sequence at which the verifier was deprecated.
+    pub since_ledger: u32,
+    /// Ledger sequence from which verification fails, if any.
+    pub sunset_ledger: Option<u32>,
+}
+
+/// Warning event emitted by every verification on a deprecated verifier before its sunset.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeprecatedVerifierUsed {
+    /// Ledger sequence from which verification fails, if any.
+    pub sunset_ledger: Option<u32>,
+}
+
+/// How the verifier decodes seals, see
+/// [`RiscZeroGroth16Verifier::set_seal_decoding`](crate::RiscZeroGroth16Verifier::set_seal_decoding).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum SealDecoding {
+    /// Only the native seal layout is accepted.
+    Strict,
+    /// Seals in any format detected by [`seal_format`](crate::seal_format) are normalized to
+    /// the native layout first.
+    Permissive,
+}
+
+/// Control root of another RISC Zero release, see
+/// [`RiscZeroGroth16Verifier::set_known_control_roots`](crate::RiscZeroGroth16Verifier::set_known_control_roots).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct KnownControlRoot {
+    /// RISC Zero version the control root belongs to.
+    pub version: String,
+    /// The control root, in the byte order of `parameters.json`.
+    pub control_root: BytesN<32>,
+}
+
+/// Event emitted when a proof fails to verify, but would verify under the control root of
+/// another known release.
+///
+/// The call still fails with [`VerifierError::InvalidProof`], so the event is only visible in
+/// the diagnostic events of the failed invocation (e.g. when simulating). It indicates that
+/// the receipt was proven with a RISC Zero version other than the one this verifier embeds.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LikelyVersionMismatch {
+    /// RISC Zero version whose control root the proof verifies under.
+    pub version: String,
+    /// The matching control root.
+    pub control_root: BytesN<32>,
+}
 
-/// Groth16 verification key for BN254 curve.
+/// Groth16 verification key for BN254 curve, without its IC points.
 ///
-/// Contains the public parameters needed to verify a Groth16 proof:
-/// - `alpha`, `beta`, `gamma`, `delta`: Fixed elliptic curve points from the trusted setup
-/// - `ic`: Array of G1 points used for computing the public input component
+/// Contains the fixed elliptic curve points from the trusted setup used in the pairing
+/// check: `alpha`, `beta`, `gamma` and `delta`. The IC points, one per public input plus one,
+/// stay encoded in [`VerificationKeyBytes::ic`] and are decoded as the public inputs are
+/// folded in, see [`msm`](crate::msm).
 ///
-/// This structure uses arkworks types internally and is not serializable for contract storage.
+/// This structure uses Soroban host types and is not serializable for contract storage.
 #[derive(Clone)]
 pub struct VerificationKey {
     pub alpha: G1Affine,
     pub beta: G2Affine,
     pub gamma: G2Affine,
     pub delta: G2Affine,
-    pub ic: [G1Affine; 6],
 }
 
 /// Byte-oriented version of the verification key generated at build time.
 ///
-/// Soroban's BN254 affine types are not `const` constructible, so we emit the
-/// key as raw byte arrays in `build.rs` and reconstruct the affine points at
-/// runtime inside the contract via [`verification_key`]. This keeps the key
-/// embeddable with `include!` while still avoiding any serialization support on
-/// the `VerificationKey` itself.
+/// Soroban's BN254 affine types are not `const` constructible, so the key is embedded as the
+/// raw byte arrays of `risc0-stellar-params` and the affine points are reconstructed at runtime
+/// inside the contract via [`verification_key`]. This keeps the key a `const` while still
+/// avoiding any serialization support on the `VerificationKey` itself.
+///
+/// The decoded key is deliberately not cached in instance storage: the instance is loaded on
+/// every invocation, and holding the key there costs each verification more than decoding it
+/// (see `budget_verification_key_decode_beats_instance_storage`).
 pub struct VerificationKeyBytes {
     pub alpha: [u8; G1_SIZE],
     pub beta: [u8; G2_SIZE],
     pub gamma: [u8; G2_SIZE],
     pub delta: [u8; G2_SIZE],
-    pub ic: [[u8; G1_SIZE]; 6],
+    pub ic: &'static [[u8; G1_SIZE]],
 }
 
 impl VerificationKeyBytes {
@@ -52,7 +152,6 @@ impl VerificationKeyBytes {
             beta: G2Affine::from_array(env, &self.beta),
             gamma: G2Affine::from_array(env, &self.gamma),
             delta: G2Affine::from_array(env, &self.delta),
-            ic: array::from_fn(|i| G1Affine::from_array(env, &self.ic[i])),
         }
     }
 }
@@ -70,6 +169,52 @@ pub struct Groth16Proof {
     pub c: G1Affine,
 }
 
+impl Groth16Proof {
+    /// Returns whether the proof is in canonical form.
+    ///
+    /// Groth16 proofs are malleable: `(-A, -B, C)` verifies whenever `(A, B, C)` does. The
+    /// canonical form of the pair is the one where the y-coordinate of `A` is in the lower half
+    /// of the base field, so exactly one of the two mirrored proofs is canonical.
+    ///
+    /// Canonical proofs are not unique: `(r⁻¹·A, r·B, C)` verifies for any nonzero scalar `r`,
+    /// and some of those re-randomized proofs are canonical as well.
+    pub fn is_canonical(&self) -> bool {
+        let a = self.a.to_array();
+        a[FIELD_ELEMENT_SIZE..] <= HALF_FIELD_MODULUS[..]
+    }
+
+    /// Decodes `A || B || C` from a buffer of exactly [`PROOF_SIZE`] bytes.
+    ///
+    /// Decoding copies the seal into a stack buffer once and only creates host objects for the
+    /// points, instead of slicing the seal into intermediate `Bytes` objects.
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::MalformedSeal`] - The buffer is not [`PROOF_SIZE`] bytes long
+    fn from_slice(env: &Env, bytes: &[u8]) -> Result<Self, VerifierError> {
+        let (a, rest) = bytes
+            .split_at_checked(G1_SIZE)
+            .ok_or(VerifierError::MalformedSeal)?;
+        let (b, c) = rest
+            .split_at_checked(G2_SIZE)
+            .ok_or(VerifierError::MalformedSeal)?;
+        Ok(Self {
+            a: G1Affine::from_array(env, &array_from(a)?),
+            b: G2Affine::from_array(env, &array_from(b)?),
+            c: G1Affine::from_array(env, &array_from(c)?),
+        })
+    }
+
+    /// Encodes the proof as `A || B || C`, the inverse of [`TryFrom<Bytes>`].
+    pub fn to_bytes(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.extend_from_array(&self.a.to_array());
+        bytes.extend_from_array(&self.b.to_array());
+        bytes.extend_from_array(&self.c.to_array());
+        bytes
+    }
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct Groth16Seal {
@@ -77,6 +222,46 @@ pub struct Groth16Seal {
     pub proof: Groth16Proof,
 }
 
+/// Event emitted when a seal with this verifier's selector has the wrong length.
+///
+/// The call still fails with [`VerifierError::MalformedSeal`], so the event is only visible in
+/// the diagnostic events of the failed invocation (e.g. when simulating). `actual < expected`
+/// indicates a truncated seal and `actual > expected` a padded one.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SealLengthMismatch {
+    pub expected: u32,
+    pub actual: u32,
+}
+
+impl Groth16Seal {
+    /// Decodes a seal, publishing [`SealLengthMismatch`] if its length is wrong.
+    ///
+    /// Behaves like [`TryFrom<Bytes>`], which reports every length error as a bare
+    /// [`VerifierError::MalformedSeal`].
+    pub fn decode(env: &Env, value: Bytes) -> Result<Self, VerifierError> {
+        if value.len() != SEAL_SIZE as u32 {
+            SealLengthMismatch {
+                expected: SEAL_SIZE as u32,
+                actual: value.len(),
+            }
+            .publish(env);
+            return Err(VerifierError::MalformedSeal);
+        }
+        Self::try_from(value)
+    }
+
+    /// Encodes the seal as `selector || proof`, the inverse of [`TryFrom<Bytes>`].
+    ///
+    /// Lets tests and tooling build seals programmatically, e.g. from a proof modified to
+    /// exercise a failure path.
+    pub fn to_bytes(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::from(&self.selector);
+        bytes.append(&self.proof.to_bytes(env));
+        bytes
+    }
+}
+
 impl TryFrom<Bytes> for Groth16Seal {
     type Error = VerifierError;
 
@@ -85,14 +270,17 @@ impl TryFrom<Bytes> for Groth16Seal {
             return Err(VerifierError::MalformedSeal);
         }
 
-        let selector = value
-            .slice(0..SELECTOR_SIZE as u32)
-            .try_into()
-            .map_err(|_| VerifierError::MalformedSeal)?;
-
-        let proof = value.slice(SELECTOR_SIZE as u32..).try_into()?;
+        let mut bytes = [0u8; SEAL_SIZE];
+        value.copy_into_slice(&mut bytes);
+        let (selector, proof) = bytes
+            .split_at_checked(SELECTOR_SIZE)
+            .ok_or(VerifierError::MalformedSeal)?;
 
-        Ok(Self { selector, proof })
+        let env = value.env();
+        Ok(Self {
+            selector: BytesN::from_array(env, &array_from(selector)?),
+            proof: Groth16Proof::from_slice(env, proof)?,
+        })
     }
 }
 
@@ -104,25 +292,17 @@ impl TryFrom<Bytes> for Groth16Proof {
             return Err(VerifierError::MalformedSeal);
         }
 
-        let a = G1Affine::from_bytes(
-            value
-                .slice(0..G1_SIZE as u32)
-                .try_into()
-                .map_err(|_| VerifierError::MalformedSeal)?,
-        );
-        let b = G2Affine::from_bytes(
-            value
-                .slice(G1_SIZE as u32..G1_SIZE as u32 + G2_SIZE as u32)
-                .try_into()
-                .map_err(|_| VerifierError::MalformedSeal)?,
-        );
-        let c = G1Affine::from_bytes(
-            value
-                .slice(G1_SIZE as u32 + G2_SIZE as u32..)
-                .try_into()
-                .map_err(|_| VerifierError::MalformedSeal)?,
-        );
-
-        Ok(Self { a, b, c })
+        let mut bytes = [0u8; PROOF_SIZE];
+        value.copy_into_slice(&mut bytes);
+        Self::from_slice(value.env(), &bytes)
     }
 }
+
+/// Copies a slice of exactly `N` bytes into an array.
+///
+/// # Errors
+///
+/// - [`VerifierError::MalformedSeal`] - The slice is not `N` bytes long
+fn array_from<const N: usize>(bytes: &[u8]) -> Result<[u8; N], VerifierError> {
+    bytes.try_into().map_err(|_| VerifierError::MalformedSeal)
+}
@@ -0,0 +1,218 @@
+#![no_std]
+
+use risc0_interface::{ImageMetadata, RiscZeroImageRegistryInterface};
+use soroban_sdk::{
+    Address, BytesN, Env, contract, contracterror, contractevent, contractimpl, contracttype,
+};
+
+#[cfg(test)]
+mod test;
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+const IMAGE_EXTEND_AMOUNT: u32 = 90 * DAY_IN_LEDGERS;
+const IMAGE_TTL_THRESHOLD: u32 = IMAGE_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+
+const ZERO_DIGEST: [u8; 32] = [0u8; 32];
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    /// Registration of an image id.
+    Image(BytesN<32>),
+}
+
+/// Stored registration of an image id.
+#[contracttype]
+#[derive(Clone)]
+struct Registration {
+    owner: Address,
+    metadata: ImageMetadata,
+}
+
+/// Errors returned by the image registry (registry range `700..=799`).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ImageRegistryError {
+    /// The image id is already registered.
+    ImageAlreadyRegistered = 700,
+    /// The image id is not registered.
+    ImageNotRegistered = 701,
+    /// The image id is all zeros.
+    ZeroImageId = 702,
+}
+
+/// Event emitted when an image id is registered.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageRegistered {
+    /// The registered image id.
+    #[topic]
+    pub image_id: BytesN<32>,
+    /// Owner of the registration.
+    pub owner: Address,
+    /// The registered metadata.
+    pub metadata: ImageMetadata,
+}
+
+/// Event emitted when the owner updates the metadata of an image id.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageUpdated {
+    /// The updated image id.
+    #[topic]
+    pub image_id: BytesN<32>,
+    /// The new metadata.
+    pub metadata: ImageMetadata,
+}
+
+/// Event emitted when the registration of an image id changes owner.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageOwnershipTransferred {
+    /// The transferred image id.
+    #[topic]
+    pub image_id: BytesN<32>,
+    /// Previous owner.
+    pub from: Address,
+    /// New owner.
+    pub to: Address,
+}
+
+/// Registry of image ids to human-readable [`ImageMetadata`].
+///
+/// Any account can register an image id that is not yet registered and becomes the owner of
+/// the registration; only the owner can update its metadata or transfer it. The first
+/// registration of an image id wins, so developers should register their programs before
+/// publishing their image ids.
+///
+/// Registrations are informational: they are read by explorers and by the router to annotate
+/// verifications, and never affect whether a proof verifies. The registry has no admin and is
+/// not upgradeable, so no party other than the owners can change a registration.
+#[contract]
+pub struct ImageRegistry;
+
+#[contractimpl]
+impl ImageRegistry {
+    /// Registers `metadata` for `image_id`, owned by `owner`. Requires the owner's
+    /// authorization.
+    ///
+    /// # Errors
+    ///
+    /// - [`ImageRegistryError::ZeroImageId`] - `image_id` is all zeros
+    /// - [`ImageRegistryError::ImageAlreadyRegistered`] - `image_id` is already registered
+    pub fn register(
+        env: Env,
+        owner: Address,
+        image_id: BytesN<32>,
+        metadata: ImageMetadata,
+    ) -> Result<(), ImageRegistryError> {
+        owner.require_auth();
+
+        if image_id.to_array() == ZERO_DIGEST {
+            return Err(ImageRegistryError::ZeroImageId);
+        }
+        let key = DataKey::Image(image_id.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(ImageRegistryError::ImageAlreadyRegistered);
+        }
+
+        write_registration(
+            &env,
+            &key,
+            &Registration {
+                owner: owner.clone(),
+                metadata: metadata.clone(),
+            },
+        );
+        ImageRegistered {
+            image_id,
+            owner,
+            metadata,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Replaces the metadata of `image_id`. Requires the owner's authorization.
+    ///
+    /// # Errors
+    ///
+    /// - [`ImageRegistryError::ImageNotRegistered`] - `image_id` is not registered
+    pub fn update(
+        env: Env,
+        image_id: BytesN<32>,
+        metadata: ImageMetadata,
+    ) -> Result<(), ImageRegistryError> {
+        let key = DataKey::Image(image_id.clone());
+        let mut registration =
+            read_registration(&env, &key).ok_or(ImageRegistryError::ImageNotRegistered)?;
+        registration.owner.require_auth();
+
+        registration.metadata = metadata.clone();
+        write_registration(&env, &key, &registration);
+        ImageUpdated { image_id, metadata }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Transfers the registration of `image_id` to `new_owner`. Requires the current owner's
+    /// authorization.
+    ///
+    /// # Errors
+    ///
+    /// - [`ImageRegistryError::ImageNotRegistered`] - `image_id` is not registered
+    pub fn transfer_ownership(
+        env: Env,
+        image_id: BytesN<32>,
+        new_owner: Address,
+    ) -> Result<(), ImageRegistryError> {
+        let key = DataKey::Image(image_id.clone());
+        let mut registration =
+            read_registration(&env, &key).ok_or(ImageRegistryError::ImageNotRegistered)?;
+        registration.owner.require_auth();
+
+        let from = registration.owner;
+        registration.owner = new_owner.clone();
+        write_registration(&env, &key, &registration);
+        ImageOwnershipTransferred {
+            image_id,
+            from,
+            to: new_owner,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+}
+
+#[contractimpl]
+impl RiscZeroImageRegistryInterface for ImageRegistry {
+    /// Returns the metadata registered for the image id.
+    fn image(env: Env, image_id: BytesN<32>) -> Option<ImageMetadata> {
+        read_registration(&env, &DataKey::Image(image_id)).map(|registration| registration.metadata)
+    }
+
+    /// Returns the owner of the registration of the image id.
+    fn owner(env: Env, image_id: BytesN<32>) -> Option<Address> {
+        read_registration(&env, &DataKey::Image(image_id)).map(|registration| registration.owner)
+    }
+}
+
+/// Reads a registration and refreshes its TTL when present.
+fn read_registration(env: &Env, key: &DataKey) -> Option<Registration> {
+    env.storage().persistent().get(key).inspect(|_| {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, IMAGE_TTL_THRESHOLD, IMAGE_EXTEND_AMOUNT);
+    })
+}
+
+/// Stores a registration and refreshes its TTL.
+fn write_registration(env: &Env, key: &DataKey, registration: &Registration) {
+    env.storage().persistent().set(key, registration);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, IMAGE_TTL_THRESHOLD, IMAGE_EXTEND_AMOUNT);
+}
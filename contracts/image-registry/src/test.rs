@@ -0,0 +1,195 @@
+extern crate std;
+
+use risc0_interface::ImageMetadata;
+use soroban_sdk::{
+    Address, BytesN, Env, Event, String,
+    testutils::{Address as _, Events as _},
+};
+
+use crate::{
+    ImageOwnershipTransferred, ImageRegistered, ImageRegistry, ImageRegistryClient,
+    ImageRegistryError, ImageUpdated,
+};
+
+fn setup() -> (Env, ImageRegistryClient<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(ImageRegistry, ());
+    let client = ImageRegistryClient::new(&env, &contract_id);
+    (env, client)
+}
+
+fn image_id(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[0xAA; 32])
+}
+
+fn metadata(env: &Env, version: &str) -> ImageMetadata {
+    ImageMetadata {
+        name: String::from_str(env, "airdrop-guest"),
+        version: String::from_str(env, version),
+        source_url_digest: BytesN::from_array(env, &[0x01; 32]),
+    }
+}
+
+#[test]
+fn register_stores_metadata_and_owner() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let image_id = image_id(&env);
+    assert_eq!(client.image(&image_id), None);
+    assert_eq!(client.owner(&image_id), None);
+
+    client.register(&owner, &image_id, &metadata(&env, "1.0.0"));
+
+    assert_eq!(client.image(&image_id), Some(metadata(&env, "1.0.0")));
+    assert_eq!(client.owner(&image_id), Some(owner));
+}
+
+#[test]
+fn register_emits_event() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let image_id = image_id(&env);
+
+    client.register(&owner, &image_id, &metadata(&env, "1.0.0"));
+
+    let event = ImageRegistered {
+        image_id,
+        owner,
+        metadata: metadata(&env, "1.0.0"),
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn register_rejects_registered_image() {
+    let (env, client) = setup();
+    let image_id = image_id(&env);
+    client.register(
+        &Address::generate(&env),
+        &image_id,
+        &metadata(&env, "1.0.0"),
+    );
+
+    let result = client.try_register(
+        &Address::generate(&env),
+        &image_id,
+        &metadata(&env, "2.0.0"),
+    );
+
+    assert_eq!(result, Err(Ok(ImageRegistryError::ImageAlreadyRegistered)));
+    assert_eq!(client.image(&image_id), Some(metadata(&env, "1.0.0")));
+}
+
+#[test]
+fn register_rejects_zero_image_id() {
+    let (env, client) = setup();
+    let zero_image_id = BytesN::from_array(&env, &[0u8; 32]);
+
+    let result = client.try_register(
+        &Address::generate(&env),
+        &zero_image_id,
+        &metadata(&env, "1.0.0"),
+    );
+
+    assert_eq!(result, Err(Ok(ImageRegistryError::ZeroImageId)));
+}
+
+#[test]
+#[should_panic]
+fn register_requires_owner_auth() {
+    let (env, client) = setup();
+    env.set_auths(&[]);
+
+    client.register(
+        &Address::generate(&env),
+        &image_id(&env),
+        &metadata(&env, "1.0.0"),
+    );
+}
+
+#[test]
+fn update_replaces_metadata() {
+    let (env, client) = setup();
+    let image_id = image_id(&env);
+    client.register(
+        &Address::generate(&env),
+        &image_id,
+        &metadata(&env, "1.0.0"),
+    );
+
+    client.update(&image_id, &metadata(&env, "1.1.0"));
+
+    let event = ImageUpdated {
+        image_id: image_id.clone(),
+        metadata: metadata(&env, "1.1.0"),
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+    assert_eq!(client.image(&image_id), Some(metadata(&env, "1.1.0")));
+}
+
+#[test]
+fn update_rejects_unregistered_image() {
+    let (env, client) = setup();
+
+    let result = client.try_update(&image_id(&env), &metadata(&env, "1.0.0"));
+
+    assert_eq!(result, Err(Ok(ImageRegistryError::ImageNotRegistered)));
+}
+
+#[test]
+#[should_panic]
+fn update_requires_owner_auth() {
+    let (env, client) = setup();
+    let image_id = image_id(&env);
+    client.register(
+        &Address::generate(&env),
+        &image_id,
+        &metadata(&env, "1.0.0"),
+    );
+    env.set_auths(&[]);
+
+    client.update(&image_id, &metadata(&env, "1.1.0"));
+}
+
+#[test]
+fn transfer_ownership_changes_owner() {
+    let (env, client) = setup();
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let image_id = image_id(&env);
+    client.register(&owner, &image_id, &metadata(&env, "1.0.0"));
+
+    client.transfer_ownership(&image_id, &new_owner);
+
+    let event = ImageOwnershipTransferred {
+        image_id: image_id.clone(),
+        from: owner,
+        to: new_owner.clone(),
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+    assert_eq!(client.owner(&image_id), Some(new_owner));
+}
+
+#[test]
+#[should_panic]
+fn transfer_ownership_requires_owner_auth() {
+    let (env, client) = setup();
+    let image_id = image_id(&env);
+    client.register(
+        &Address::generate(&env),
+        &image_id,
+        &metadata(&env, "1.0.0"),
+    );
+    env.set_auths(&[]);
+
+    client.transfer_ownership(&image_id, &Address::generate(&env));
+}
+
+#[test]
+fn error_codes_are_stable() {
+    // Part of the public ABI, see the error registry in `risc0-interface`.
+    assert_eq!(ImageRegistryError::ImageAlreadyRegistered as u32, 700);
+    assert_eq!(ImageRegistryError::ImageNotRegistered as u32, 701);
+    assert_eq!(ImageRegistryError::ZeroImageId as u32, 702);
+}
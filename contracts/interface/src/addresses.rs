@@ -0,0 +1,90 @@
+//! # Deployment Address Book
+//!
+//! Official deployments of the verifier contracts per Stellar network, for host-side tooling
+//! and integrators. Prefer resolving contracts from here over hard-coding contract IDs, which go
+//! stale as verifier versions are added and removed.
+//!
+//! Entries are added when a deployment is announced, and removed verifiers are kept with
+//! `removed: true` so historical receipts can still be attributed. Networks without an
+//! official deployment have empty entries.
+
+/// A Stellar network with official deployments.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Network {
+    /// Stellar Testnet.
+    Testnet,
+    /// Stellar Futurenet.
+    Futurenet,
+    /// Stellar Mainnet (the public network).
+    Mainnet,
+}
+
+/// A verifier contract deployed behind the router.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct VerifierDeployment {
+    /// Selector the verifier is registered under in the router.
+    pub selector: [u8; 4],
+    /// Contract ID (`C...` strkey) of the verifier.
+    pub contract_id: &'static str,
+    /// Contract ID (`C...` strkey) of the emergency stop wrapping the verifier.
+    pub estop_contract_id: &'static str,
+    /// Whether the selector was removed from the router.
+    pub removed: bool,
+}
+
+/// The official deployments on one network.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct NetworkDeployment {
+    /// Contract ID (`C...` strkey) of the router, if deployed.
+    pub router: Option<&'static str>,
+    /// Verifiers registered in the router.
+    pub verifiers: &'static [VerifierDeployment],
+}
+
+/// Official deployments on Stellar Testnet.
+pub const TESTNET: NetworkDeployment = NetworkDeployment {
+    router: None,
+    verifiers: &[],
+};
+
+/// Official deployments on Stellar Futurenet.
+pub const FUTURENET: NetworkDeployment = NetworkDeployment {
+    router: None,
+    verifiers: &[],
+};
+
+/// Official deployments on Stellar Mainnet.
+pub const MAINNET: NetworkDeployment = NetworkDeployment {
+    router: None,
+    verifiers: &[],
+};
+
+impl Network {
+    /// All networks with an address book entry.
+    pub const ALL: [Network; 3] = [Network::Testnet, Network::Futurenet, Network::Mainnet];
+
+    /// Returns the network passphrase.
+    pub const fn passphrase(self) -> &'static str {
+        match self {
+            Network::Testnet => "Test SDF Network ; September 2015",
+            Network::Futurenet => "Test SDF Future Network ; October 2022",
+            Network::Mainnet => "Public Global Stellar Network ; September 2015",
+        }
+    }
+
+    /// Returns the official deployments on this network.
+    pub const fn deployment(self) -> &'static NetworkDeployment {
+        match self {
+            Network::Testnet => &TESTNET,
+            Network::Futurenet => &FUTURENET,
+            Network::Mainnet => &MAINNET,
+        }
+    }
+}
+
+impl NetworkDeployment {
+    /// Returns the verifier registered under `selector`, including removed ones.
+    pub fn verifier(&self, selector: [u8; 4]) -> Option<&'static VerifierDeployment> {
+        self.verifiers.iter().find(|v| v.selector == selector)
+    }
+}
@@ -0,0 +1,54 @@
+//! # Alert Event Topics
+//!
+//! Standardized topics of the events that off-chain alerting pipelines subscribe to. Alerts
+//! are published with [`ALERT_TOPIC`] and the kind of alert as their first two topics, in
+//! place of the event name, followed by the event's own topics:
+//!
+//! ```text
+//! ["risc0_alert", "<kind>", <event topics>...]
+//! ```
+//!
+//! A pipeline subscribes to every alert of the stack with a filter on the first topic, or to
+//! one kind of alert with a filter on the first two, without parsing event bodies. The
+//! contract that published an alert tells apart the events of a kind.
+//!
+//! | Kind                          | Events                                                        |
+//! |-------------------------------|---------------------------------------------------------------|
+//! | [`ESTOP_ALERT`]               | `EmergencyStopped` (emergency stop)                           |
+//! | [`PAUSE_ALERT`]               | `TemporaryPause` (emergency stop), `SelectorTripped` (router) |
+//! | [`RESUME_ALERT`]              | `SelectorResumed` (router)                                    |
+//! | [`VERIFICATION_FAILED_ALERT`] | `VerifierCallFailed` (router)                                 |
+//!
+//! Alert events are declared with `#[contractevent(topics = ["risc0_alert", "<kind>"])]`, the
+//! most prefix topics an event can have, so the string literals in the macro must match the
+//! topics below.
+
+use soroban_sdk::{Env, Symbol, TryFromVal, Val, Vec};
+
+/// First topic of every alert.
+pub const ALERT_TOPIC: &str = "risc0_alert";
+
+/// Kind of the alerts published when verification is permanently stopped.
+pub const ESTOP_ALERT: &str = "estop";
+
+/// Kind of the alerts published when verification, or a selector, is paused until further
+/// action or for a while.
+pub const PAUSE_ALERT: &str = "pause";
+
+/// Kind of the alerts published when a paused selector is resumed.
+pub const RESUME_ALERT: &str = "resume";
+
+/// Kind of the alerts published when a verifier fails a verification.
+pub const VERIFICATION_FAILED_ALERT: &str = "verify_failed";
+
+/// Returns whether `topics`, the topics of an event, mark it as an alert of `kind`.
+pub fn is_alert(env: &Env, topics: &Vec<Val>, kind: &str) -> bool {
+    topics.len() >= 2
+        && [ALERT_TOPIC, kind]
+            .into_iter()
+            .zip(topics.iter())
+            .all(|(expected, topic)| {
+                Symbol::try_from_val(env, &topic)
+                    .is_ok_and(|topic| topic == Symbol::new(env, expected))
+            })
+}
@@ -0,0 +1,100 @@
+//! Last-failure records for debugging verification in tests.
+//!
+//! A failed verification only surfaces as an error code, which says little about which seal
+//! or claim failed when a test drives several through a router and its verifiers. Contracts
+//! built with the `testutils` feature record the context of their last failure with
+//! [`record_result`], and expose it through a `last_error` entrypoint backed by
+//! [`last_failure`].
+//!
+//! Failed invocations roll back all of their storage writes, temporary entries included, so
+//! the records are kept in thread-local memory instead. Contracts run natively in tests, in
+//! the thread of the test, so each test sees the failures of its own invocations. Records are
+//! keyed by contract address and are never cleared, so the record of a contract is only
+//! meaningful after an invocation known to have failed.
+
+extern crate std;
+
+use core::cell::RefCell;
+use std::{thread_local, vec::Vec};
+
+use soroban_sdk::{Bytes, BytesN, Env, contracttype};
+
+use crate::{VerifierError, extract_selector};
+
+/// Length of a contract address strkey.
+const STRKEY_SIZE: usize = 56;
+
+/// The context of the last failed verification of a contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationFailure {
+    /// The selector of the seal, `None` if the seal is shorter than a selector.
+    pub selector: Option<BytesN<4>>,
+    /// The claim digest being verified.
+    pub claim_digest: BytesN<32>,
+    /// The [`VerifierError`] code the verification failed with.
+    pub error: u32,
+}
+
+/// [`VerificationFailure`] detached from any [`Env`], so that it outlives the invocation.
+struct Record {
+    contract: [u8; STRKEY_SIZE],
+    selector: Option<[u8; 4]>,
+    claim_digest: [u8; 32],
+    error: u32,
+}
+
+thread_local! {
+    static RECORDS: RefCell<Vec<Record>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Returns the strkey of the current contract.
+fn current_contract(env: &Env) -> [u8; STRKEY_SIZE] {
+    let mut strkey = [0u8; STRKEY_SIZE];
+    env.current_contract_address()
+        .to_string()
+        .copy_into_slice(&mut strkey);
+    strkey
+}
+
+/// Records `result` of verifying `claim_digest` with `seal` as the last failure of the current
+/// contract, if it is an error.
+pub fn record_result(
+    env: &Env,
+    seal: &Bytes,
+    claim_digest: &BytesN<32>,
+    result: Result<(), VerifierError>,
+) {
+    let Err(error) = result else {
+        return;
+    };
+    let record = Record {
+        contract: current_contract(env),
+        selector: extract_selector(seal)
+            .ok()
+            .map(|selector| selector.to_array()),
+        claim_digest: claim_digest.to_array(),
+        error: error as u32,
+    };
+    RECORDS.with_borrow_mut(|records| {
+        records.retain(|r| r.contract != record.contract);
+        records.push(record);
+    });
+}
+
+/// Returns the last failure recorded for the current contract.
+pub fn last_failure(env: &Env) -> Option<VerificationFailure> {
+    let contract = current_contract(env);
+    RECORDS.with_borrow(|records| {
+        records
+            .iter()
+            .find(|record| record.contract == contract)
+            .map(|record| VerificationFailure {
+                selector: record
+                    .selector
+                    .map(|selector| BytesN::from_array(env, &selector)),
+                claim_digest: BytesN::from_array(env, &record.claim_digest),
+                error: record.error,
+            })
+    })
+}
@@ -0,0 +1,60 @@
+//! # Soroban Hashing Adapter
+//!
+//! Bridges the `no_std` [`risc0_digest`] core to the Soroban host. The digest functions are
+//! written against the [`Sha256`] trait; [`HostSha256`] implements it by buffering the
+//! preimage in host [`Bytes`] and hashing it once with the host SHA-256 function, so contracts
+//! pay for a single host call per digest.
+
+use risc0_digest::{Digest, Sha256};
+use soroban_sdk::{Bytes, Env};
+
+/// [`Sha256`] implementation backed by the Soroban host.
+pub struct HostSha256 {
+    data: Bytes,
+}
+
+impl HostSha256 {
+    /// Creates an empty hasher bound to `env`.
+    pub fn new(env: &Env) -> Self {
+        Self {
+            data: Bytes::new(env),
+        }
+    }
+}
+
+impl Sha256 for HostSha256 {
+    fn update(&mut self, data: &[u8]) {
+        self.data.extend_from_slice(data);
+    }
+
+    fn finalize(self) -> Digest {
+        self.data.env().crypto().sha256(&self.data).to_array()
+    }
+}
+
+/// [`Sha256`] implementation backed by the Soroban host that leaves the hashed message in
+/// `preimage`, so the exact bytes behind a digest can be inspected.
+pub(crate) struct RecordingSha256<'a> {
+    preimage: &'a mut Bytes,
+}
+
+impl<'a> RecordingSha256<'a> {
+    /// Creates a hasher appending to `preimage`.
+    pub(crate) fn new(preimage: &'a mut Bytes) -> Self {
+        Self { preimage }
+    }
+}
+
+impl Sha256 for RecordingSha256<'_> {
+    fn update(&mut self, data: &[u8]) {
+        self.preimage.extend_from_slice(data);
+    }
+
+    fn finalize(self) -> Digest {
+        self.preimage
+            .env()
+            .crypto()
+            .sha256(self.preimage)
+            .to_array()
+    }
+}
@@ -0,0 +1,291 @@
+//! # Journal Binding
+//!
+//! Helpers to verify a receipt against the application values its guest committed, instead of
+//! a journal digest computed by hand. A contract that serializes its fields differently from
+//! the guest computes a different journal digest, so its proofs fail; worse, a contract that
+//! decodes a raw journal loosely may accept a proof for other data than it checks.
+//!
+//! [`JournalEncode`] reproduces the RISC Zero serde encoding used by `env::commit` in the
+//! guest: every value is written as little-endian 32-bit words.
+//!
+//! | Rust type in the guest      | Soroban type | Encoding                                     |
+//! |-----------------------------|--------------|----------------------------------------------|
+//! | `bool`, `u32`, `i32`        | same         | One word                                     |
+//! | `u64`, `i64`                | same         | Two words, low word first                    |
+//! | `u128`, `i128`              | same         | Four words, lowest word first                |
+//! | `[u8; N]`                   | `BytesN<N>`  | One word per byte                            |
+//! | `Vec<u8>`                   | `Bytes`      | Length word, then one word per byte          |
+//! | `[u8; 32]` (address digest) | `Address`    | SHA-256 of the address XDR, as a `[u8; 32]`  |
+//! | Tuples                      | Tuples       | Fields in order, without a length            |
+//!
+//! Soroban addresses have no guest counterpart, so guests commit the SHA-256 digest of the
+//! address XDR encoding instead, like the airdrop example's recipient binding.
+//!
+//! [`JournalDecode`] is the inverse, for contracts that receive the raw journal and read values
+//! out of it after verifying it with [`verify_raw`]. Addresses cannot be decoded from their
+//! digest: decode a `BytesN<32>` and compare it with the digest of the expected address.
+//!
+//! ```ignore
+//! // Guest: env::commit(&(recipient_digest, amount, nullifier));
+//! journal::verify(&client, &seal, &image_id, &(recipient, amount, nullifier));
+//! ```
+//!
+//! Journals too large to pass on-chain are committed as a Merkle root of their chunks instead,
+//! see [`chunks`].
+
+use soroban_sdk::{Address, Bytes, BytesN, Env, xdr::ToXdr};
+
+use crate::RiscZeroVerifierClient;
+
+pub mod chunks;
+
+/// A value with the journal encoding of the guest's `env::commit`.
+///
+/// See the [module documentation](self) for the encoding of each type.
+pub trait JournalEncode {
+    /// Appends the encoding of `self` to `journal`.
+    fn encode(&self, journal: &mut Bytes);
+}
+
+/// Returns the journal a guest commits with `env::commit(value)`.
+pub fn encode<T: JournalEncode>(env: &Env, value: &T) -> Bytes {
+    let mut journal = Bytes::new(env);
+    value.encode(&mut journal);
+    journal
+}
+
+/// Returns the journal digest of `env::commit(value)`, as expected by
+/// [`verify`](crate::RiscZeroVerifierInterface::verify).
+pub fn digest<T: JournalEncode>(env: &Env, value: &T) -> BytesN<32> {
+    env.crypto().sha256(&encode(env, value)).into()
+}
+
+/// Verifies that `seal` proves a successful execution of `image_id` whose guest committed
+/// exactly `value`.
+///
+/// Proof verification failures abort the invocation with the verifier's error.
+pub fn verify<T: JournalEncode>(
+    client: &RiscZeroVerifierClient,
+    seal: &Bytes,
+    image_id: &BytesN<32>,
+    value: &T,
+) {
+    client.verify(seal, image_id, &digest(&client.env, value));
+}
+
+/// Verifies that `seal` proves a successful execution of `image_id` that committed exactly the
+/// raw `journal`.
+///
+/// Proof verification failures abort the invocation with the verifier's error.
+pub fn verify_raw(
+    client: &RiscZeroVerifierClient,
+    seal: &Bytes,
+    image_id: &BytesN<32>,
+    journal: &Bytes,
+) {
+    let journal_digest: BytesN<32> = client.env.crypto().sha256(journal).into();
+    client.verify(seal, image_id, &journal_digest);
+}
+
+/// A value with the journal encoding of the guest's `env::commit`, decodable from a journal.
+///
+/// The inverse of [`JournalEncode`], for every type but [`Address`].
+pub trait JournalDecode: Sized {
+    /// Decodes a value from `journal` at `*offset`, and advances `offset` past it.
+    ///
+    /// Returns `None` if the journal ends early, or a word is out of range for its type.
+    fn decode(journal: &Bytes, offset: &mut u32) -> Option<Self>;
+}
+
+/// Returns the value a guest committed with `env::commit(value)`, or `None` if `journal` is not
+/// exactly the encoding of a `T`.
+pub fn decode<T: JournalDecode>(journal: &Bytes) -> Option<T> {
+    let mut offset = 0;
+    let value = T::decode(journal, &mut offset)?;
+    (offset == journal.len()).then_some(value)
+}
+
+/// Reads `N` bytes at `*offset` and advances `offset` past them.
+fn read_array<const N: usize>(journal: &Bytes, offset: &mut u32) -> Option<[u8; N]> {
+    let end = offset.checked_add(u32::try_from(N).ok()?)?;
+    if end > journal.len() {
+        return None;
+    }
+    let mut bytes = [0u8; N];
+    journal.slice(*offset..end).copy_into_slice(&mut bytes);
+    *offset = end;
+    Some(bytes)
+}
+
+/// Reads a little-endian 32-bit word.
+fn read_word(journal: &Bytes, offset: &mut u32) -> Option<u32> {
+    read_array(journal, offset).map(u32::from_le_bytes)
+}
+
+/// Reads a word holding a single byte.
+fn read_byte(journal: &Bytes, offset: &mut u32) -> Option<u8> {
+    u8::try_from(read_word(journal, offset)?).ok()
+}
+
+/// Appends `word` as a little-endian 32-bit word.
+fn push_word(journal: &mut Bytes, word: u32) {
+    journal.extend_from_array(&word.to_le_bytes());
+}
+
+impl JournalEncode for bool {
+    fn encode(&self, journal: &mut Bytes) {
+        push_word(journal, u32::from(*self));
+    }
+}
+
+impl JournalEncode for u32 {
+    fn encode(&self, journal: &mut Bytes) {
+        push_word(journal, *self);
+    }
+}
+
+impl JournalEncode for i32 {
+    fn encode(&self, journal: &mut Bytes) {
+        journal.extend_from_array(&self.to_le_bytes());
+    }
+}
+
+impl JournalEncode for u64 {
+    fn encode(&self, journal: &mut Bytes) {
+        journal.extend_from_array(&self.to_le_bytes());
+    }
+}
+
+impl JournalEncode for i64 {
+    fn encode(&self, journal: &mut Bytes) {
+        journal.extend_from_array(&self.to_le_bytes());
+    }
+}
+
+impl JournalEncode for u128 {
+    fn encode(&self, journal: &mut Bytes) {
+        journal.extend_from_array(&self.to_le_bytes());
+    }
+}
+
+impl JournalEncode for i128 {
+    fn encode(&self, journal: &mut Bytes) {
+        journal.extend_from_array(&self.to_le_bytes());
+    }
+}
+
+impl<const N: usize> JournalEncode for BytesN<N> {
+    fn encode(&self, journal: &mut Bytes) {
+        for byte in self.iter() {
+            push_word(journal, u32::from(byte));
+        }
+    }
+}
+
+impl JournalEncode for Bytes {
+    fn encode(&self, journal: &mut Bytes) {
+        push_word(journal, self.len());
+        for byte in self.iter() {
+            push_word(journal, u32::from(byte));
+        }
+    }
+}
+
+impl JournalEncode for Address {
+    fn encode(&self, journal: &mut Bytes) {
+        let env = self.env();
+        let digest: BytesN<32> = env.crypto().sha256(&self.clone().to_xdr(env)).into();
+        digest.encode(journal);
+    }
+}
+
+impl JournalDecode for bool {
+    fn decode(journal: &Bytes, offset: &mut u32) -> Option<Self> {
+        match read_word(journal, offset)? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl JournalDecode for u32 {
+    fn decode(journal: &Bytes, offset: &mut u32) -> Option<Self> {
+        read_word(journal, offset)
+    }
+}
+
+impl JournalDecode for i32 {
+    fn decode(journal: &Bytes, offset: &mut u32) -> Option<Self> {
+        read_array(journal, offset).map(i32::from_le_bytes)
+    }
+}
+
+impl JournalDecode for u64 {
+    fn decode(journal: &Bytes, offset: &mut u32) -> Option<Self> {
+        read_array(journal, offset).map(u64::from_le_bytes)
+    }
+}
+
+impl JournalDecode for i64 {
+    fn decode(journal: &Bytes, offset: &mut u32) -> Option<Self> {
+        read_array(journal, offset).map(i64::from_le_bytes)
+    }
+}
+
+impl JournalDecode for u128 {
+    fn decode(journal: &Bytes, offset: &mut u32) -> Option<Self> {
+        read_array(journal, offset).map(u128::from_le_bytes)
+    }
+}
+
+impl JournalDecode for i128 {
+    fn decode(journal: &Bytes, offset: &mut u32) -> Option<Self> {
+        read_array(journal, offset).map(i128::from_le_bytes)
+    }
+}
+
+impl<const N: usize> JournalDecode for BytesN<N> {
+    fn decode(journal: &Bytes, offset: &mut u32) -> Option<Self> {
+        let mut bytes = [0u8; N];
+        for byte in bytes.iter_mut() {
+            *byte = read_byte(journal, offset)?;
+        }
+        Some(BytesN::from_array(journal.env(), &bytes))
+    }
+}
+
+impl JournalDecode for Bytes {
+    fn decode(journal: &Bytes, offset: &mut u32) -> Option<Self> {
+        let len = read_word(journal, offset)?;
+        let mut bytes = Bytes::new(journal.env());
+        for _ in 0..len {
+            bytes.push_back(read_byte(journal, offset)?);
+        }
+        Some(bytes)
+    }
+}
+
+macro_rules! impl_journal_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: JournalEncode),+> JournalEncode for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn encode(&self, journal: &mut Bytes) {
+                let ($($name,)+) = self;
+                $($name.encode(journal);)+
+            }
+        }
+
+        impl<$($name: JournalDecode),+> JournalDecode for ($($name,)+) {
+            fn decode(journal: &Bytes, offset: &mut u32) -> Option<Self> {
+                Some(($($name::decode(journal, offset)?,)+))
+            }
+        }
+    };
+}
+
+impl_journal_for_tuple!(A);
+impl_journal_for_tuple!(A, B);
+impl_journal_for_tuple!(A, B, C);
+impl_journal_for_tuple!(A, B, C, D);
+impl_journal_for_tuple!(A, B, C, D, E);
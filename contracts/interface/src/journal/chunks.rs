@@ -0,0 +1,157 @@
+//! # Chunked Journals
+//!
+//! A guest with a journal too large to verify or pass on-chain commits a Merkle root of it
+//! instead, and contracts check only the chunks they consume against the proven root.
+//!
+//! The guest splits the journal into chunks of `chunk_size` bytes, the last one possibly
+//! shorter (an empty journal is a single empty chunk), and commits
+//! `env::commit(&(root, chunk_count))`, i.e. a [`ChunkedJournal`]. The root is that of the
+//! [`merkle`](crate::merkle) tree, with the indexed convention, where:
+//!
+//! - leaf `i` is `SHA-256(chunk_i)`, and the leaves are padded with zero digests up to the next
+//!   power of two
+//! - an inner node is `SHA-256(left || right)`
+//!
+//! The tree has [`depth`]`(chunk_count)` levels. [`verify_chunk`] requires paths of exactly
+//! that length, so an inner node cannot be passed off as a chunk.
+//!
+//! ```ignore
+//! // Guest: env::commit(&(root, chunk_count));
+//! journal::verify(&client, &seal, &image_id, &commitment);
+//! if !chunks::verify_chunk(&env, &commitment, index, &chunk, &path) { ... }
+//! ```
+//!
+//! [`commit`] and [`open`] compute the commitment and the paths from the full journal, as a
+//! reference for provers.
+
+use risc0_digest::ZERO_DIGEST;
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+use super::{JournalDecode, JournalEncode};
+use crate::merkle;
+
+/// The journal committed by a guest for a chunked journal.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChunkedJournal {
+    /// Root of the chunk tree.
+    pub root: BytesN<32>,
+    /// Number of chunks of the journal.
+    pub chunks: u32,
+}
+
+impl JournalEncode for ChunkedJournal {
+    fn encode(&self, journal: &mut Bytes) {
+        (self.root.clone(), self.chunks).encode(journal);
+    }
+}
+
+impl JournalDecode for ChunkedJournal {
+    fn decode(journal: &Bytes, offset: &mut u32) -> Option<Self> {
+        let (root, chunks) = JournalDecode::decode(journal, offset)?;
+        Some(Self { root, chunks })
+    }
+}
+
+/// Returns the depth of the tree of `chunks` chunks.
+pub fn depth(chunks: u32) -> u32 {
+    u32::BITS - chunks.saturating_sub(1).leading_zeros()
+}
+
+/// Returns whether `chunk` is chunk `index` of the journal committed to by `commitment`.
+pub fn verify_chunk(
+    env: &Env,
+    commitment: &ChunkedJournal,
+    index: u32,
+    chunk: &Bytes,
+    path: &Vec<BytesN<32>>,
+) -> bool {
+    if index >= commitment.chunks || path.len() != depth(commitment.chunks) {
+        return false;
+    }
+    let leaf: BytesN<32> = env.crypto().sha256(chunk).into();
+    merkle::verify_merkle_path(env, &commitment.root, &leaf, path, index)
+}
+
+/// Returns the commitment to `journal` split into chunks of `chunk_size` bytes.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is zero.
+pub fn commit(env: &Env, journal: &Bytes, chunk_size: u32) -> ChunkedJournal {
+    let levels = tree(env, journal, chunk_size);
+    ChunkedJournal {
+        root: levels.last_unchecked().get_unchecked(0),
+        chunks: chunk_count(journal, chunk_size),
+    }
+}
+
+/// Returns chunk `index` of `journal` split into chunks of `chunk_size` bytes and its path, or
+/// `None` if the journal has no such chunk.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is zero.
+pub fn open(
+    env: &Env,
+    journal: &Bytes,
+    chunk_size: u32,
+    index: u32,
+) -> Option<(Bytes, Vec<BytesN<32>>)> {
+    if index >= chunk_count(journal, chunk_size) {
+        return None;
+    }
+
+    let levels = tree(env, journal, chunk_size);
+    let mut path = Vec::new(env);
+    for height in 0..levels.len() - 1 {
+        path.push_back(
+            levels
+                .get_unchecked(height)
+                .get_unchecked((index >> height) ^ 1),
+        );
+    }
+    Some((chunk(journal, chunk_size, index), path))
+}
+
+/// Returns the number of chunks of `journal`.
+fn chunk_count(journal: &Bytes, chunk_size: u32) -> u32 {
+    assert!(chunk_size > 0, "chunk size must be positive");
+    journal.len().div_ceil(chunk_size).max(1)
+}
+
+/// Returns chunk `index` of `journal`.
+fn chunk(journal: &Bytes, chunk_size: u32, index: u32) -> Bytes {
+    let start = index * chunk_size;
+    journal.slice(start..journal.len().min(start + chunk_size))
+}
+
+/// Returns the levels of the chunk tree, from the padded leaves up to the root.
+fn tree(env: &Env, journal: &Bytes, chunk_size: u32) -> Vec<Vec<BytesN<32>>> {
+    let chunks = chunk_count(journal, chunk_size);
+    let mut level = Vec::new(env);
+    for index in 0..1 << depth(chunks) {
+        level.push_back(if index < chunks {
+            env.crypto()
+                .sha256(&chunk(journal, chunk_size, index))
+                .into()
+        } else {
+            BytesN::from_array(env, &ZERO_DIGEST)
+        });
+    }
+
+    let mut levels = Vec::new(env);
+    while level.len() > 1 {
+        let mut parent = Vec::new(env);
+        for index in (0..level.len()).step_by(2) {
+            parent.push_back(merkle::hash_pair(
+                env,
+                &level.get_unchecked(index),
+                &level.get_unchecked(index + 1),
+            ));
+        }
+        levels.push_back(level);
+        level = parent;
+    }
+    levels.push_back(level);
+    levels
+}
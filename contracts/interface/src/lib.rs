@@ -5,25 +5,136 @@
 //! ## Core Components
 //!
 //! - [`Receipt`]: Contains a seal (cryptographic proof) and a claim digest
+//! - [`ReceiptEnvelope`]: Versioned container of a receipt and its journal
 //! - [`RiscZeroVerifierInterface`]: Verifier contract interface
+//! - [`RiscZeroVerifierInfo`]: Verifier introspection, used for health checks
 //! - [`RiscZeroVerifierRouterInterface`]: Router contract interface
+//! - [`RiscZeroImageRegistryInterface`]: Image registry contract interface
+//! - [`roles`]: Role identifiers shared by the administrative contracts
+//! - [`alerts`]: Standardized topics of the events alerting pipelines subscribe to
+//! - [`upgrade`]: Versioned upgrades for the administrative contracts
+//! - [`extract_selector`]: Reads the verifier selector from a seal without decoding the proof
+//! - [`journal`]: Verifies receipts against the application values committed by the guest
+//! - [`merkle`]: SHA-256 Merkle inclusion proofs, for set membership and batched receipts
+//! - `addresses` (feature `addresses`): Official contract IDs per network, for host-side tooling
+//! - `debug` (feature `testutils`): Last verification failure of a contract, for debugging tests
+//! - [`HostSha256`]: Host-backed hasher for the shared `risc0-digest` core, which computes the
+//!   same claim digests in contracts and in RISC Zero guest programs
+//!
+//! ## Integrating
+//!
+//! Applications should integrate against a single address implementing
+//! [`RiscZeroVerifierInterface`]: an emergency stop wrapping the router. The router dispatches
+//! each seal to the verifier of its proof system version by selector, so new versions are added
+//! without changing the integration address, and the emergency stop lets a guardian halt all
+//! verification at once. Both are governed through their admin, typically a timelock.
+//! Router-specific entrypoints, such as revocation-aware variants, are called on the router
+//! directly.
+//!
+//! ## Error Codes
+//!
+//! Contract error codes share a single numeric namespace across the workspace, so a code
+//! observed by a downstream contract identifies its origin unambiguously:
+//!
+//! | Range         | Owner                                             |
+//! |---------------|---------------------------------------------------|
+//! | `0..=99`      | Generic verifier errors ([`VerifierError`])       |
+//! | `100..=199`   | Groth16 verifier errors ([`VerifierError`])       |
+//! | `200..=299`   | Router errors ([`VerifierError`])                 |
+//! | `300..=399`   | Emergency stop errors                             |
+//! | `400..=499`   | Steel example errors                              |
+//! | `500..=599`   | Airdrop example errors                            |
+//! | `600..=699`   | Timelock errors                                   |
+//! | `700..=799`   | Image registry errors                             |
+//! | `800..=899`   | Verification fees example errors                  |
+//! | `1000..`      | Reserved for OpenZeppelin `stellar-contracts`     |
+//!
+//! Numeric values are part of the public ABI. Existing codes must never be renumbered or
+//! reused; new variants take the next free code in their owner's range. Each crate pins its
+//! codes in a test, together with the retired codes that must stay unassigned.
+//!
+//! Codes that predate the registry keep their values where they can: [`VerifierError`] codes
+//! `0..=9` keep their original variants, so some Groth16 and router errors sit in the generic
+//! range, and `100` and `200..=203` stay unassigned. The emergency stop and timelock errors
+//! moved once, when the registry was introduced, because their old codes collided with the
+//! [`VerifierError`] and OpenZeppelin codes the same contracts return: emergency stop `1`, `5`,
+//! `1001` and `1002` became `300..=303`, and timelock `5000` became `600`. Downstream contracts
+//! matching on the old codes must be updated; the old codes are never assigned to these
+//! contracts' errors again.
 
 #![no_std]
+#![cfg_attr(
+    all(feature = "panic-audit", not(test)),
+    deny(
+        clippy::expect_used,
+        clippy::indexing_slicing,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented,
+        clippy::unreachable,
+        clippy::unwrap_used
+    )
+)]
 
-use soroban_sdk::{Address, Bytes, BytesN, Env, contractclient};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec, contractclient, contracttrait};
 
 // Re-export types at crate root for convenience
+pub use hash::HostSha256;
 pub use types::{
-    ExitCode, Output, Receipt, ReceiptClaim, SystemExitCode, VerifierEntry, VerifierError,
+    Assumption, Assumptions, CircuitBreaker, ConsumerConfig, CostEstimate, ExitCode, ImageMetadata,
+    Output, RateLimit, Receipt, ReceiptClaim, ReceiptEnvelope, SystemExitCode, VerifierEntry,
+    VerifierError, ensure_nonzero_claim_digest, ensure_nonzero_image_id,
 };
 
+#[cfg(feature = "addresses")]
+pub mod addresses;
+pub mod alerts;
+#[cfg(any(test, feature = "testutils"))]
+pub mod debug;
+mod hash;
+pub mod journal;
+pub mod merkle;
+pub mod roles;
+#[cfg(test)]
+mod test;
 mod types;
+pub mod upgrade;
+
+/// Size in bytes of the verifier selector prefixing every seal.
+///
+/// Mirrors [`risc0_constants::SELECTOR_SIZE`] as a `u32`, the length type of Soroban [`Bytes`].
+#[allow(clippy::cast_possible_truncation)]
+pub const SELECTOR_SIZE: u32 = risc0_constants::SELECTOR_SIZE as u32;
+
+/// Extracts the verifier selector from the first [`SELECTOR_SIZE`] bytes of a seal.
+///
+/// Only the prefix is read; the remaining bytes are not decoded, so this is cheap enough to
+/// pre-route or log a seal before handing it to a verifier.
+///
+/// # Errors
+///
+/// - [`VerifierError::MalformedSeal`] - The seal is shorter than the selector
+pub fn extract_selector(seal: &Bytes) -> Result<BytesN<4>, VerifierError> {
+    if seal.len() < SELECTOR_SIZE {
+        return Err(VerifierError::MalformedSeal);
+    }
+    seal.slice(0..SELECTOR_SIZE)
+        .try_into()
+        .map_err(|_| VerifierError::MalformedSeal)
+}
 
 /// Verifier interface for RISC Zero zkVM receipts of execution.
 ///
 /// This trait defines the standard interface that all RISC Zero verifier contracts must
 /// implement on Soroban. Currently, only the Groth16 proof system is supported.
-#[contractclient(name = "RiscZeroVerifierClient")]
+///
+/// Implement it with `#[contractimpl(contracttrait)]` so that the default
+/// [`verify`](Self::verify), derived from [`verify_integrity`](Self::verify_integrity), is
+/// exported as well. The default builds the claim with
+/// [`POST_STATE_DIGEST_HALTED`](ReceiptClaim::POST_STATE_DIGEST_HALTED); verifiers should only
+/// override `verify` to forward it elsewhere, as the emergency stop does, or to build the claim
+/// with the post-state digest of the zkVM release they verify, as the Groth16 verifier does.
+#[contracttrait(client_name = "RiscZeroVerifierClient")]
 pub trait RiscZeroVerifierInterface {
     /// The cryptographic proof system used by this verifier (e.g., Groth16).
     type Proof;
@@ -47,9 +158,11 @@ pub trait RiscZeroVerifierInterface {
     ///
     /// # Verification Process
     ///
-    /// 1. Constructs a `ReceiptClaim` using the provided image ID and journal digest
-    /// 2. Computes the claim digest according to RISC Zero's specification
-    /// 3. Verifies the seal is a valid cryptographic proof for this claim
+    /// 1. Rejects the zero image id, see [`ensure_nonzero_image_id`]
+    /// 2. Constructs a `ReceiptClaim` using the provided image ID and journal digest
+    /// 3. Computes the claim digest according to RISC Zero's specification
+    /// 4. Verifies the seal is a valid cryptographic proof for this claim with
+    ///    [`verify_integrity`](Self::verify_integrity)
     ///
     /// # Returns
     ///
@@ -59,6 +172,7 @@ pub trait RiscZeroVerifierInterface {
     /// # Errors
     ///
     /// Returns an error if any of the following occur:
+    /// - [`VerifierError::ZeroImageId`] - The image id is all zeros
     /// - [`VerifierError::MalformedSeal`] - The seal is malformed or cannot be decoded
     /// - [`VerifierError::InvalidSelector`] - The selector in the seal doesn't match this verifier
     /// - [`VerifierError::MalformedPublicInputs`] - The public inputs are invalid
@@ -80,7 +194,11 @@ pub trait RiscZeroVerifierInterface {
         seal: Bytes,
         image_id: BytesN<32>,
         journal: BytesN<32>,
-    ) -> Result<(), VerifierError>;
+    ) -> Result<(), VerifierError> {
+        ensure_nonzero_image_id(&image_id)?;
+        let receipt = Receipt::from_execution(&env, seal, image_id, journal);
+        Self::verify_integrity(env, receipt)
+    }
 
     /// Verifies a full RISC Zero receipt with arbitrary claim parameters.
     ///
@@ -130,30 +248,71 @@ pub trait RiscZeroVerifierInterface {
     /// # Examples
     ///
     /// ```ignore
-    /// use risc0_verifier_interface::{Receipt, ReceiptClaim};
+    /// use risc0_verifier_interface::Receipt;
     ///
     /// // Create a receipt with the correct claim digest
-    /// let claim = ReceiptClaim::new(&env, image_id, journal_digest);
-    /// let receipt = Receipt {
-    ///     seal: seal,
-    ///     claim_digest: claim.digest(&env),
-    /// };
+    /// let receipt = Receipt::from_execution(&env, seal, image_id, journal_digest);
     ///
     /// // Verify the full receipt
     /// verifier.verify_integrity(&env, receipt)?; // Returns Result<(), VerifierError>
     /// ```
     fn verify_integrity(env: Env, receipt: Receipt) -> Result<(), VerifierError>;
+
+    /// Verifies that a single seal proves every claim in `claims`.
+    ///
+    /// This is the entrypoint for aggregated proof systems, where one seal attests to several
+    /// claim digests at once. Applications written against it keep the same ABI whether the
+    /// underlying verifier aggregates or not.
+    ///
+    /// Proof systems that prove exactly one claim per seal (e.g. Groth16) accept a single
+    /// claim, which is equivalent to [`verify_integrity`](Self::verify_integrity), and reject
+    /// any other number of claims.
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::UnsupportedAggregation`] - The verifier cannot prove this number of
+    ///   claims with one seal
+    /// - Any error returned by [`verify_integrity`](Self::verify_integrity)
+    fn verify_many(env: Env, seal: Bytes, claims: Vec<BytesN<32>>) -> Result<(), VerifierError>;
+}
+
+/// Introspection interface of a verifier contract.
+///
+/// Every verifier (and every wrapper forwarding to one) exposes the selector of the seals it
+/// accepts, which lets the router check that a verifier is registered under the right selector
+/// and still reachable, and the post-state digest its claims commit to, which the router builds
+/// claims with.
+#[contractclient(name = "RiscZeroVerifierInfoClient")]
+pub trait RiscZeroVerifierInfo {
+    /// Returns the selector of the seals accepted by the verifier.
+    fn selector(env: Env) -> Result<BytesN<4>, VerifierError>;
+
+    /// Returns the post-state digest of a halted execution in the verifier's zkVM release,
+    /// see [`ReceiptClaim::with_post_state_digest`].
+    fn post_state_digest(env: Env) -> BytesN<32>;
 }
 
 /// Router interface for a `RiscZeroVerifierRouter` contract.
 ///
 /// This interface exposes verification entrypoints alongside read-only routing helpers.
+///
+/// Verification entrypoints forward the [`VerifierError`] returned by the selected verifier.
+/// Failures that are not a [`VerifierError`], such as an emergency stop's pause error or a
+/// trap, are reported as [`VerifierError::VerifierUnavailable`]. Verifications dispatched to a
+/// selector paused by the router's circuit breaker fail with [`VerifierError::SelectorPaused`].
 #[contractclient(name = "RiscZeroVerifierRouterClient")]
 pub trait RiscZeroVerifierRouterInterface {
     /// Verifies a receipt from its components using the selector embedded in the seal.
     ///
     /// The router uses the first 4 bytes of the seal as a selector to dispatch to the
-    /// appropriate verifier.
+    /// appropriate verifier. Fails with [`VerifierError::ZeroImageId`] for the zero image id,
+    /// and with [`VerifierError::ClaimRevoked`] if the image id or the resulting claim digest
+    /// was revoked.
+    ///
+    /// Claims are built with the post-state digest the verifier reports through
+    /// [`RiscZeroVerifierInfo::post_state_digest`], so the router checks revocations of, and
+    /// returns, the claim the verifier proves. A verifier that does not report one fails with
+    /// [`VerifierError::VerifierUnavailable`].
     fn verify(
         env: Env,
         seal: Bytes,
@@ -161,9 +320,176 @@ pub trait RiscZeroVerifierRouterInterface {
         journal: BytesN<32>,
     ) -> Result<(), VerifierError>;
 
+    /// Verifies a receipt from its components like [`verify`](Self::verify), with `verifier`
+    /// instead of the verifier of the seal's selector.
+    ///
+    /// Callers that already know the verifier of their proofs skip the selector lookup, while
+    /// keeping the router's policies: `verifier` must be the active verifier of a selector, so
+    /// removed verifiers stay unreachable and the emergency stop wrapping a registered verifier
+    /// is not bypassed, and revocations apply as in [`verify`](Self::verify).
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::VerifierNotRegistered`] - `verifier` is not the active verifier of
+    ///   any selector
+    /// - Any error returned by [`verify`](Self::verify), except the selector lookup errors
+    fn verify_with(
+        env: Env,
+        verifier: Address,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> Result<(), VerifierError>;
+
+    /// Verifies a receipt from its components like [`verify`](Self::verify), returning the
+    /// claim digest it verified.
+    ///
+    /// Calling contracts that store or bind the verified claim (e.g. as a nullifier) can use
+    /// the returned digest instead of recomputing the tagged hash of the claim, which commits to
+    /// the verifier's post-state digest. The digest is computed once and forwarded to the
+    /// verifier's [`verify_integrity`](RiscZeroVerifierInterface::verify_integrity), so the
+    /// verifier does not hash the claim again either.
+    fn verify_returning_digest(
+        env: Env,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> Result<BytesN<32>, VerifierError>;
+
+    /// Verifies a receipt from its components like [`verify`](Self::verify), for a guest that
+    /// halted with `user_exit_code`.
+    ///
+    /// The claim is built like [`ReceiptClaim::with_user_exit_code`], with the post-state digest
+    /// of the selector's verifier (see [`verify`](Self::verify)), so guests that signal a
+    /// result status through their exit code can use this convenience path instead of
+    /// constructing the claim manually for [`verify_integrity`](Self::verify_integrity).
+    fn verify_with_exit_code(
+        env: Env,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+        user_exit_code: u32,
+    ) -> Result<(), VerifierError>;
+
     /// Verifies receipt integrity using the selector embedded in the seal.
+    ///
+    /// Fails with [`VerifierError::ClaimRevoked`] if the claim digest was revoked. Like the
+    /// verifiers, the router accepts the zero claim digest, so an emergency stop in front of
+    /// it can check proofs of exploit; see [`ensure_nonzero_claim_digest`].
     fn verify_integrity(env: Env, receipt: Receipt) -> Result<(), VerifierError>;
 
+    /// Verifies the receipt of an envelope like [`verify_integrity`](Self::verify_integrity).
+    ///
+    /// The envelope's journal, if any, is not checked; consumers bind it to their image id with
+    /// [`ReceiptEnvelope::commits_journal`], passing the verifier's post-state digest.
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::UnsupportedEnvelopeVersion`] /
+    ///   [`VerifierError::UnsupportedProofSystem`] - The envelope is not one this router
+    ///   understands, see [`ReceiptEnvelope::receipt`]
+    /// - [`VerifierError::ZeroClaimDigest`] - The receipt's claim digest is zero
+    /// - Any error returned by [`verify_integrity`](Self::verify_integrity)
+    fn verify_envelope(env: Env, envelope: ReceiptEnvelope) -> Result<(), VerifierError>;
+
+    /// Verifies receipt integrity like [`verify_integrity`](Self::verify_integrity), after
+    /// checking that `receipt.claim_digest` is the digest of a successful execution of
+    /// `image_id` committing `journal`.
+    ///
+    /// The claim digest is recomputed like [`ReceiptClaim::new`], with the post-state digest of
+    /// the selector's verifier, before the verifier is called,
+    /// so a mis-computed digest fails early with [`VerifierError::ClaimDigestMismatch`] instead
+    /// of an opaque [`VerifierError::InvalidProof`]. The image id is checked like in
+    /// [`verify`](Self::verify).
+    fn verify_integrity_with_claim(
+        env: Env,
+        receipt: Receipt,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> Result<(), VerifierError>;
+
+    /// Verifies that a single seal proves every claim in `claims`, using the selector embedded
+    /// in the seal.
+    ///
+    /// Fails with [`VerifierError::ZeroClaimDigest`] or [`VerifierError::ClaimRevoked`] if any
+    /// of the claim digests is zero or was revoked.
+    fn verify_many(env: Env, seal: Bytes, claims: Vec<BytesN<32>>) -> Result<(), VerifierError>;
+
+    /// Verifies a conditional receipt together with the receipts discharging its assumptions.
+    ///
+    /// The conditional receipt attests to a successful execution of `image_id` committing
+    /// `journal`, whose output depends on one assumption per entry of `assumption_receipts`, in
+    /// order. The assumptions digest is recomputed on-chain from the claim digests of
+    /// `assumption_receipts` (each with a zero control root), so the conditional seal only
+    /// verifies if its assumptions are exactly the given receipts. Every assumption receipt is
+    /// then verified through the router as well.
+    ///
+    /// With an empty `assumption_receipts`, this is equivalent to [`verify`](Self::verify).
+    fn verify_conditional(
+        env: Env,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+        assumption_receipts: Vec<Receipt>,
+    ) -> Result<(), VerifierError>;
+
+    /// Verifies a receipt from its raw journal, enforcing the journal prefix configured by
+    /// `consumer`.
+    ///
+    /// The journal must start with [`ConsumerConfig::journal_prefix`]; it is then hashed and
+    /// verified like [`verify`](Self::verify). This lets application contracts whose guests
+    /// commit a fixed-prefix journal (e.g. `version || payload`) reject journals of another
+    /// schema at the verification layer.
+    ///
+    /// The journal stays a host object throughout: the prefix is compared and the journal
+    /// hashed by host functions, without copying it into contract memory, so multi-kilobyte
+    /// journals are only bounded by the transaction size limits.
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::ConsumerNotConfigured`] - `consumer` has no configuration
+    /// - [`VerifierError::JournalPrefixMismatch`] - The journal does not start with the
+    ///   configured prefix
+    /// - Any error returned by [`verify`](Self::verify)
+    fn verify_with_journal_prefix(
+        env: Env,
+        consumer: Address,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: Bytes,
+    ) -> Result<(), VerifierError>;
+
+    /// Verifies a receipt from its components like [`verify`](Self::verify), on behalf of
+    /// `caller`. Requires the caller's authorization.
+    ///
+    /// When the router has a [`RateLimit`], each successful verification counts against the
+    /// caller's window, so a relayer sponsoring verifications can route them through this
+    /// entrypoint to bound what each account consumes. Failed verifications roll back and do
+    /// not count; relayers are expected to simulate before submitting.
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::RateLimitExceeded`] - `caller` exhausted its current window
+    /// - Any error returned by [`verify`](Self::verify)
+    fn verify_as(
+        env: Env,
+        caller: Address,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> Result<(), VerifierError>;
+
+    /// Returns the per-caller rate limit of [`verify_as`](Self::verify_as), or `None` if rate
+    /// limiting is disabled.
+    fn rate_limit(env: Env) -> Option<RateLimit>;
+
+    /// Sets the configuration of `consumer`, replacing any previous one. Requires the
+    /// consumer's authorization.
+    fn set_consumer_config(env: Env, consumer: Address, config: ConsumerConfig);
+
+    /// Returns the configuration of `consumer`, or `None` if it never set one.
+    fn consumer_config(env: Env, consumer: Address) -> Option<ConsumerConfig>;
+
     /// Returns the raw verifier entry for a selector.
     ///
     /// `None` indicates the selector has never been set.
@@ -174,4 +500,46 @@ pub trait RiscZeroVerifierRouterInterface {
 
     /// Returns the verifier address for the selector stored in the seal prefix.
     fn get_verifier_from_seal(env: Env, seal: Bytes) -> Result<Address, VerifierError>;
+
+    /// Checks that the verifier registered for `selector` is reachable and reports `selector`.
+    ///
+    /// This performs a cheap dry run against the verifier through
+    /// [`RiscZeroVerifierInfo::selector`], so monitoring can detect a mis-registered or stopped
+    /// verifier before user transactions fail.
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::SelectorUnknown`] / [`VerifierError::SelectorRemoved`] - No active
+    ///   verifier is registered for `selector`
+    /// - [`VerifierError::VerifierUnavailable`] - The verifier call failed, e.g. because the
+    ///   verifier is behind a triggered emergency stop
+    /// - [`VerifierError::InvalidSelector`] - The verifier reports a different selector
+    fn healthcheck(env: Env, selector: BytesN<4>) -> Result<(), VerifierError>;
+
+    /// Returns the estimated cost of a verification through the verifier registered for
+    /// `selector`.
+    ///
+    /// The estimate is a static value registered by the operator, see [`CostEstimate`]. Wallets
+    /// and dapps can read it to size the resource footprint of a transaction before
+    /// simulating it. Returns `None` if no estimate was registered for `selector`, or if its
+    /// verifier was removed.
+    fn estimate_cost(env: Env, selector: BytesN<4>) -> Option<CostEstimate>;
+
+    /// Returns the selector stored in the seal prefix, without resolving its verifier.
+    ///
+    /// See [`extract_selector`](crate::extract_selector).
+    fn extract_selector(env: Env, seal: Bytes) -> Result<BytesN<4>, VerifierError>;
+}
+
+/// Read interface of an image registry contract, which maps image ids to [`ImageMetadata`].
+///
+/// Registrations are owned by the account that made them; only the owner can update or
+/// transfer them.
+#[contractclient(name = "RiscZeroImageRegistryClient")]
+pub trait RiscZeroImageRegistryInterface {
+    /// Returns the metadata registered for `image_id`, or `None` if it is not registered.
+    fn image(env: Env, image_id: BytesN<32>) -> Option<ImageMetadata>;
+
+    /// Returns the owner of the registration of `image_id`, or `None` if it is not registered.
+    fn owner(env: Env, image_id: BytesN<32>) -> Option<Address>;
 }
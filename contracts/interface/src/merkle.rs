@@ -0,0 +1,75 @@
+//! # Merkle Proofs
+//!
+//! Verification of SHA-256 Merkle inclusion proofs, hashed with the host SHA-256 function.
+//! An inner node is `SHA-256(left || right)` and `path` lists the siblings from the leaf up to
+//! the root. Two conventions are supported, matching how the tree was built off-chain:
+//!
+//! - Indexed ([`verify_merkle_path`]): bit `i` of the leaf index tells whether the node at
+//!   height `i` is a right child (`1`) or a left child (`0`). The proof binds the leaf's
+//!   position, e.g. for trees of sequential entries.
+//! - Sorted pairs ([`verify_sorted_merkle_path`]): each pair is ordered before hashing, the
+//!   smaller digest first, as in OpenZeppelin's `MerkleProof`. The proof needs no index, but
+//!   does not bind a position.
+//!
+//! Leaves are taken as given. To rule out inner nodes being passed as leaves, trees should hash
+//! their leaves differently from inner nodes, e.g. with a domain prefix.
+
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+/// Returns `SHA-256(left || right)`.
+pub fn hash_pair(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &left.to_array());
+    preimage.append(&Bytes::from(right));
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Returns [`hash_pair`] of `a` and `b`, the smaller digest first.
+pub fn hash_sorted_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    if a <= b {
+        hash_pair(env, a, b)
+    } else {
+        hash_pair(env, b, a)
+    }
+}
+
+/// Returns whether `path` proves that `leaf` is at position `index` of the tree with `root`,
+/// with the indexed convention.
+///
+/// `index` must fit in `path.len()` bits: a higher bit set would let several indices prove
+/// the same leaf. Paths longer than the 32 bits of `index` are rejected.
+pub fn verify_merkle_path(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    path: &Vec<BytesN<32>>,
+    index: u32,
+) -> bool {
+    if path.len() > u32::BITS || index.checked_shr(path.len()).unwrap_or(0) != 0 {
+        return false;
+    }
+
+    let mut node = leaf.clone();
+    for (height, sibling) in path.iter().enumerate() {
+        node = if index >> height & 1 == 0 {
+            hash_pair(env, &node, &sibling)
+        } else {
+            hash_pair(env, &sibling, &node)
+        };
+    }
+    node == *root
+}
+
+/// Returns whether `path` proves that `leaf` is in the tree with `root`, with the sorted-pair
+/// convention.
+pub fn verify_sorted_merkle_path(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    path: &Vec<BytesN<32>>,
+) -> bool {
+    let mut node = leaf.clone();
+    for sibling in path.iter() {
+        node = hash_sorted_pair(env, &node, &sibling);
+    }
+    node == *root
+}
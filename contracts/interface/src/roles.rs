@@ -0,0 +1,26 @@
+//! # Administrative Roles
+//!
+//! Role identifiers shared by the router and emergency-stop contracts. Both contracts store
+//! role membership with OpenZeppelin's `access_control` module, which requires the caller's
+//! `require_auth` on every privileged call and emits a `RoleGranted` / `RoleRevoked` event on
+//! each membership change.
+//!
+//! ## Roles
+//!
+//! - **Admin**: The `access_control` admin. Grants and revokes the other roles but performs no
+//!   operational action itself
+//! - **Guardian** ([`GUARDIAN_ROLE`]): Emergency actions that only reduce what can be verified
+//!   (removing a router selector, triggering the emergency stop)
+//! - **Operator** ([`OPERATOR_ROLE`]): Routine operations that extend what can be verified
+//!   (registering a verifier for a new selector), and feeding the router's circuit breaker
+//!
+//! Privileged entrypoints are gated with `#[only_role(caller, "<role>")]`, so the string
+//! literal in the macro must match the symbol below.
+
+use soroban_sdk::{Symbol, symbol_short};
+
+/// Role for accounts allowed to take emergency actions.
+pub const GUARDIAN_ROLE: Symbol = symbol_short!("guardian");
+
+/// Role for accounts allowed to perform routine operations.
+pub const OPERATOR_ROLE: Symbol = symbol_short!("operator");
@@ -0,0 +1,732 @@
+use risc0_digest::ZERO_DIGEST;
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, IntoVal, Symbol, Vec, testutils::Address as _, vec, xdr::ToXdr,
+};
+
+use crate::{
+    Assumption, Assumptions, ExitCode, HostSha256, Output, Receipt, ReceiptClaim, ReceiptEnvelope,
+    SystemExitCode, VerifierError, alerts, ensure_nonzero_claim_digest, ensure_nonzero_image_id,
+    extract_selector, journal, merkle,
+};
+
+/// Pins the numeric [`VerifierError`] codes, which are part of the public ABI.
+///
+/// Downstream contracts match on these values. If this test fails, restore the previous code
+/// instead of updating the expectation.
+#[test]
+fn test_verifier_error_codes_are_stable() {
+    let codes = [
+        (VerifierError::InvalidProof, 0),
+        (VerifierError::MalformedPublicInputs, 1),
+        (VerifierError::MalformedSeal, 2),
+        (VerifierError::InvalidSelector, 3),
+        (VerifierError::AlreadyInitialized, 4),
+        (VerifierError::SelectorRemoved, 5),
+        (VerifierError::SelectorInUse, 6),
+        (VerifierError::SelectorUnknown, 7),
+        (VerifierError::ClaimRevoked, 8),
+        (VerifierError::UnsupportedAggregation, 9),
+        (VerifierError::UnsupportedSchemaVersion, 10),
+        (VerifierError::UnsupportedEnvelopeVersion, 11),
+        (VerifierError::UnsupportedProofSystem, 12),
+        (VerifierError::MalformedJournal, 13),
+        (VerifierError::VersionNotIncreasing, 14),
+        (VerifierError::ZeroClaimDigest, 15),
+        (VerifierError::ZeroImageId, 16),
+        (VerifierError::Deprecated, 17),
+        (VerifierError::NonCanonicalProof, 101),
+        (VerifierError::VerifierUnavailable, 204),
+        (VerifierError::ConsumerNotConfigured, 205),
+        (VerifierError::JournalPrefixMismatch, 206),
+        (VerifierError::ClaimDigestMismatch, 207),
+        (VerifierError::RateLimitExceeded, 208),
+        (VerifierError::VerifierNotRegistered, 209),
+        (VerifierError::SelectorPaused, 210),
+        (VerifierError::RegistryFinalized, 211),
+        (VerifierError::QuorumRequired, 212),
+        (VerifierError::QuorumNotMet, 213),
+        (VerifierError::SelectorExpired, 214),
+    ];
+
+    for (error, code) in codes {
+        assert_eq!(error as u32, code, "{error:?}");
+    }
+}
+
+/// Retired [`VerifierError`] codes must never be reassigned, or downstream contracts still
+/// matching on them would misroute the new error.
+#[test]
+fn test_retired_verifier_error_codes_stay_unassigned() {
+    for code in [100, 200, 201, 202, 203] {
+        let error = soroban_sdk::Error::from_contract_error(code);
+        assert!(VerifierError::try_from(error).is_err(), "code {code}");
+    }
+}
+
+#[test]
+fn test_extract_selector() {
+    let env = Env::default();
+    let seal = Bytes::from_slice(&env, &[0xDE, 0xAD, 0xBE, 0xEF, 0x01, 0x02]);
+
+    assert_eq!(
+        extract_selector(&seal),
+        Ok(BytesN::from_array(&env, &[0xDE, 0xAD, 0xBE, 0xEF]))
+    );
+}
+
+#[test]
+fn test_extract_selector_short_seal() {
+    let env = Env::default();
+    let seal = Bytes::from_slice(&env, &[0xDE, 0xAD, 0xBE]);
+
+    assert_eq!(extract_selector(&seal), Err(VerifierError::MalformedSeal));
+}
+
+#[test]
+fn test_assumption_digest() {
+    let env = Env::default();
+    let assumption = Assumption::new(
+        BytesN::from_array(&env, &[1u8; 32]),
+        BytesN::from_array(&env, &[2u8; 32]),
+    );
+
+    let mut preimage = Bytes::from_array(&env, &risc0_digest::ASSUMPTION_TAG_DIGEST);
+    preimage.extend_from_array(&[1u8; 32]);
+    preimage.extend_from_array(&[2u8; 32]);
+    preimage.extend_from_array(&[2, 0]);
+    assert_eq!(
+        assumption.digest(&env),
+        BytesN::from(env.crypto().sha256(&preimage))
+    );
+}
+
+#[test]
+fn test_assumptions_cons_list() {
+    let env = Env::default();
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let a = Assumption::new(BytesN::from_array(&env, &[1u8; 32]), zero.clone());
+    let b = Assumption::new(BytesN::from_array(&env, &[2u8; 32]), zero.clone());
+
+    assert_eq!(Assumptions::new(&env).digest(), &zero);
+    assert_eq!(
+        Assumptions::from_assumptions(&env, &Vec::new(&env)),
+        Assumptions::new(&env)
+    );
+
+    // `add` prepends, so adding `b` then `a` builds the list `[a, b]`.
+    let mut assumptions = Assumptions::new(&env);
+    assumptions.add(&env, &b);
+    assumptions.add(&env, &a);
+    assert_eq!(
+        assumptions,
+        Assumptions::from_assumptions(&env, &vec![&env, a.clone(), b.clone()])
+    );
+
+    let tail = risc0_digest::tagged_list_cons(
+        HostSha256::new(&env),
+        &risc0_digest::ASSUMPTIONS_TAG_DIGEST,
+        &b.digest(&env).to_array(),
+        &ZERO_DIGEST,
+    );
+    let expected = risc0_digest::tagged_list_cons(
+        HostSha256::new(&env),
+        &risc0_digest::ASSUMPTIONS_TAG_DIGEST,
+        &a.digest(&env).to_array(),
+        &tail,
+    );
+    assert_eq!(assumptions.digest().to_array(), expected);
+}
+
+#[test]
+fn test_output_new_matches_standard_claim() {
+    let env = Env::default();
+    let image_id = BytesN::from_array(&env, &[7u8; 32]);
+    let journal_digest = BytesN::from_array(&env, &[9u8; 32]);
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+
+    let output = Output::new(journal_digest.clone(), zero.clone());
+    assert_eq!(output.journal_digest(), &journal_digest);
+    assert_eq!(output.assumptions_digest(), &zero);
+
+    let claim = ReceiptClaim::from_parts(
+        image_id.clone(),
+        BytesN::from_array(&env, &ReceiptClaim::POST_STATE_DIGEST_HALTED),
+        ExitCode::new(SystemExitCode::Halted, 0),
+        zero,
+        output.digest(&env),
+    );
+    assert_eq!(
+        claim.digest(&env),
+        ReceiptClaim::new(&env, image_id, journal_digest).digest(&env)
+    );
+}
+
+#[test]
+fn test_receipt_claim_getters() {
+    let env = Env::default();
+    let image_id = BytesN::from_array(&env, &[7u8; 32]);
+    let journal_digest = BytesN::from_array(&env, &[9u8; 32]);
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+
+    let claim = ReceiptClaim::new(&env, image_id.clone(), journal_digest.clone());
+
+    assert_eq!(claim.pre_state_digest(), &image_id);
+    assert_eq!(
+        claim.post_state_digest().to_array(),
+        ReceiptClaim::POST_STATE_DIGEST_HALTED
+    );
+    assert_eq!(claim.exit_code().system(), SystemExitCode::Halted);
+    assert_eq!(claim.exit_code().user(), 0);
+    assert_eq!(claim.input(), &zero);
+    assert_eq!(
+        claim.output(),
+        &Output::new(journal_digest, zero).digest(&env)
+    );
+}
+
+#[test]
+fn test_receipt_claim_with_user_exit_code() {
+    let env = Env::default();
+    let image_id = BytesN::from_array(&env, &[7u8; 32]);
+    let journal_digest = BytesN::from_array(&env, &[9u8; 32]);
+
+    let claim =
+        ReceiptClaim::with_user_exit_code(&env, image_id.clone(), journal_digest.clone(), 1);
+    assert_eq!(claim.exit_code().system(), SystemExitCode::Halted);
+    assert_eq!(claim.exit_code().user(), 1);
+
+    let standard = ReceiptClaim::new(&env, image_id.clone(), journal_digest.clone());
+    assert_ne!(claim.digest(&env), standard.digest(&env));
+
+    let claim = ReceiptClaim::with_user_exit_code(&env, image_id, journal_digest, 0);
+    assert_eq!(claim.digest(&env), standard.digest(&env));
+}
+
+#[test]
+fn test_receipt_claim_with_post_state_digest() {
+    let env = Env::default();
+    let image_id = BytesN::from_array(&env, &[7u8; 32]);
+    let journal_digest = BytesN::from_array(&env, &[9u8; 32]);
+    let standard = ReceiptClaim::new(&env, image_id.clone(), journal_digest.clone());
+
+    let halted = BytesN::from_array(&env, &ReceiptClaim::POST_STATE_DIGEST_HALTED);
+    let claim = ReceiptClaim::with_post_state_digest(
+        &env,
+        image_id.clone(),
+        journal_digest.clone(),
+        halted,
+        0,
+    );
+    assert_eq!(claim.digest(&env), standard.digest(&env));
+
+    let other = BytesN::from_array(&env, &[1u8; 32]);
+    let claim =
+        ReceiptClaim::with_post_state_digest(&env, image_id, journal_digest, other.clone(), 0);
+    assert_eq!(claim.post_state_digest(), &other);
+    assert_ne!(claim.digest(&env), standard.digest(&env));
+}
+
+/// Like the RISC Zero reference, the digest encodes each exit code as a full little-endian
+/// word, so codes sharing a low byte do not collide.
+#[test]
+fn test_receipt_claim_digest_commits_full_user_exit_code() {
+    let env = Env::default();
+    let image_id = BytesN::from_array(&env, &[7u8; 32]);
+    let journal_digest = BytesN::from_array(&env, &[9u8; 32]);
+    let digest = |user_exit_code: u32| {
+        ReceiptClaim::with_user_exit_code(
+            &env,
+            image_id.clone(),
+            journal_digest.clone(),
+            user_exit_code,
+        )
+        .digest(&env)
+    };
+
+    assert_ne!(digest(257), digest(1));
+    assert_ne!(digest(0x0102_0305), digest(0x05));
+    assert_ne!(digest(0x05), digest(0x06));
+}
+
+#[test]
+fn test_receipt_from_execution_uses_standard_claim() {
+    let env = Env::default();
+    let seal = Bytes::from_array(&env, &[1, 2, 3, 4]);
+    let image_id = BytesN::from_array(&env, &[7u8; 32]);
+    let journal_digest = BytesN::from_array(&env, &[9u8; 32]);
+
+    let receipt =
+        Receipt::from_execution(&env, seal.clone(), image_id.clone(), journal_digest.clone());
+
+    assert_eq!(receipt.seal, seal);
+    assert_eq!(
+        receipt.claim_digest,
+        ReceiptClaim::new(&env, image_id, journal_digest).digest(&env)
+    );
+}
+
+#[test]
+fn test_receipt_envelope() {
+    let env = Env::default();
+    let image_id = BytesN::from_array(&env, &[7u8; 32]);
+    let journal = Bytes::from_array(&env, &[1, 2, 3]);
+    let journal_digest: BytesN<32> = env.crypto().sha256(&journal).into();
+    let receipt = Receipt::from_execution(
+        &env,
+        Bytes::from_array(&env, &[1, 2, 3, 4]),
+        image_id.clone(),
+        journal_digest,
+    );
+
+    let envelope = ReceiptEnvelope::new(receipt.clone(), Some(journal));
+    assert_eq!(envelope.receipt(), Ok(receipt.clone()));
+    let halted = BytesN::from_array(&env, &ReceiptClaim::POST_STATE_DIGEST_HALTED);
+    let other = BytesN::from_array(&env, &[8u8; 32]);
+    assert!(envelope.commits_journal(&env, &image_id, &halted));
+    assert!(!envelope.commits_journal(&env, &other, &halted));
+    assert!(!envelope.commits_journal(&env, &image_id, &other));
+    assert!(!ReceiptEnvelope::new(receipt, None).commits_journal(&env, &image_id, &halted));
+
+    let mut future = envelope.clone();
+    future.version = ReceiptEnvelope::VERSION + 1;
+    assert_eq!(
+        future.receipt(),
+        Err(VerifierError::UnsupportedEnvelopeVersion)
+    );
+
+    let mut other = envelope;
+    other.proof_system = ReceiptEnvelope::PROOF_SYSTEM_RISC_ZERO + 1;
+    assert_eq!(other.receipt(), Err(VerifierError::UnsupportedProofSystem));
+}
+
+#[test]
+fn test_claim_preimages_hash_to_digests() {
+    let env = Env::default();
+    let image_id = BytesN::from_array(&env, &[7u8; 32]);
+    let journal_digest = BytesN::from_array(&env, &[9u8; 32]);
+    let output = Output::new(journal_digest.clone(), BytesN::from_array(&env, &[0u8; 32]));
+    let claim = ReceiptClaim::with_user_exit_code(&env, image_id, journal_digest, 3);
+
+    let preimage = output.preimage(&env);
+    assert_eq!(preimage.len(), 98);
+    assert_eq!(
+        preimage.slice(..32),
+        Bytes::from_array(&env, &risc0_constants::OUTPUT_TAG_DIGEST)
+    );
+    assert_eq!(
+        env.crypto().sha256(&preimage).to_bytes(),
+        output.digest(&env)
+    );
+
+    let preimage = claim.preimage(&env);
+    assert_eq!(preimage.len(), 170);
+    assert_eq!(
+        preimage.slice(..32),
+        Bytes::from_array(&env, &risc0_constants::RECEIPT_CLAIM_TAG_DIGEST)
+    );
+    assert_eq!(preimage.slice(128..160), Bytes::from(output.digest(&env)));
+    assert_eq!(
+        preimage.slice(160..),
+        Bytes::from_array(&env, &[0, 0, 0, 0, 3, 0, 0, 0, 4, 0])
+    );
+    assert_eq!(
+        env.crypto().sha256(&preimage).to_bytes(),
+        claim.digest(&env)
+    );
+}
+
+#[test]
+fn test_journal_encode_words() {
+    let env = Env::default();
+    let value = (
+        true,
+        -2i32,
+        0x0102_0304_0506_0708u64,
+        -1i128,
+        BytesN::from_array(&env, &[0xAB, 0xCD]),
+        Bytes::from_slice(&env, &[0xEF]),
+    );
+
+    let mut expected = Bytes::from_array(&env, &[1, 0, 0, 0]);
+    expected.extend_from_array(&[0xFE, 0xFF, 0xFF, 0xFF]);
+    expected.extend_from_array(&[8, 7, 6, 5, 4, 3, 2, 1]);
+    expected.extend_from_array(&[0xFF; 16]);
+    expected.extend_from_array(&[0xAB, 0, 0, 0, 0xCD, 0, 0, 0]);
+    expected.extend_from_array(&[1, 0, 0, 0, 0xEF, 0, 0, 0]);
+    assert_eq!(
+        journal::encode(
+            &env,
+            &((value.0, value.1, value.2), value.3, value.4, value.5)
+        ),
+        expected
+    );
+}
+
+#[test]
+fn test_journal_encode_address_as_xdr_digest() {
+    let env = Env::default();
+    let address = Address::generate(&env);
+    let amount = 1_000i128;
+
+    let address_digest: BytesN<32> = env.crypto().sha256(&address.clone().to_xdr(&env)).into();
+    assert_eq!(
+        journal::encode(&env, &(address.clone(), amount)),
+        journal::encode(&env, &(address_digest, amount))
+    );
+    let journal_digest: BytesN<32> = env
+        .crypto()
+        .sha256(&journal::encode(&env, &(address.clone(), amount)))
+        .into();
+    assert_eq!(journal::digest(&env, &(address, amount)), journal_digest);
+}
+
+#[test]
+fn test_journal_decode_inverts_encode() {
+    let env = Env::default();
+    let value = (
+        (true, u32::MAX, -2i32, 0x0102_0304_0506_0708u64),
+        (-1i64, u128::MAX, i128::MIN),
+        BytesN::from_array(&env, &[0xAB, 0xCD]),
+        Bytes::from_slice(&env, &[0xEF, 0x01]),
+    );
+
+    let journal = journal::encode(&env, &value);
+    assert_eq!(journal::decode(&journal), Some(value));
+}
+
+#[test]
+fn test_journal_decode_rejects_non_canonical_encodings() {
+    let env = Env::default();
+    let journal = Bytes::from_array(&env, &[2, 0, 0, 0]);
+    assert_eq!(journal::decode::<bool>(&journal), None);
+    let journal = Bytes::from_array(&env, &[0, 1, 0, 0]);
+    assert_eq!(journal::decode::<BytesN<1>>(&journal), None);
+    // Truncated, and with trailing words.
+    let journal = journal::encode(&env, &Bytes::from_slice(&env, &[1, 2]));
+    assert_eq!(journal::decode::<Bytes>(&journal.slice(..8)), None);
+    assert_eq!(journal::decode::<u32>(&journal), None);
+}
+
+#[cfg(feature = "addresses")]
+#[test]
+fn test_address_book_is_well_formed() {
+    use crate::addresses::Network;
+
+    for network in Network::ALL {
+        let deployment = network.deployment();
+        let mut ids = deployment.router.into_iter().chain(
+            deployment
+                .verifiers
+                .iter()
+                .flat_map(|v| [v.contract_id, v.estop_contract_id]),
+        );
+        assert!(
+            ids.all(|id| id.len() == 56 && id.starts_with('C')),
+            "{network:?}"
+        );
+
+        for (i, verifier) in deployment.verifiers.iter().enumerate() {
+            assert_eq!(deployment.verifier(verifier.selector), Some(verifier));
+            assert!(
+                deployment.verifiers[..i]
+                    .iter()
+                    .all(|v| v.selector != verifier.selector),
+                "{network:?}: duplicate selector {:02x?}",
+                verifier.selector
+            );
+        }
+    }
+}
+
+#[test]
+fn test_ensure_nonzero() {
+    let env = Env::default();
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let mut bytes = [0u8; 32];
+    bytes[31] = 1;
+    let nonzero = BytesN::from_array(&env, &bytes);
+
+    assert_eq!(ensure_nonzero_image_id(&nonzero), Ok(()));
+    assert_eq!(
+        ensure_nonzero_image_id(&zero),
+        Err(VerifierError::ZeroImageId)
+    );
+    assert_eq!(ensure_nonzero_claim_digest(&nonzero), Ok(()));
+    assert_eq!(
+        ensure_nonzero_claim_digest(&zero),
+        Err(VerifierError::ZeroClaimDigest)
+    );
+}
+
+/// Returns the leaves and root of a four-leaf tree hashed with `hash`.
+fn merkle_tree(
+    env: &Env,
+    hash: fn(&Env, &BytesN<32>, &BytesN<32>) -> BytesN<32>,
+) -> ([BytesN<32>; 4], BytesN<32>) {
+    let leaves = [1u8, 2, 3, 4].map(|byte| BytesN::from_array(env, &[byte; 32]));
+    let left = hash(env, &leaves[0], &leaves[1]);
+    let right = hash(env, &leaves[2], &leaves[3]);
+    let root = hash(env, &left, &right);
+    (leaves, root)
+}
+
+#[test]
+fn test_verify_merkle_path() {
+    let env = Env::default();
+    let (leaves, root) = merkle_tree(&env, merkle::hash_pair);
+    let paths = [
+        vec![
+            &env,
+            leaves[1].clone(),
+            merkle::hash_pair(&env, &leaves[2], &leaves[3]),
+        ],
+        vec![
+            &env,
+            leaves[0].clone(),
+            merkle::hash_pair(&env, &leaves[2], &leaves[3]),
+        ],
+        vec![
+            &env,
+            leaves[3].clone(),
+            merkle::hash_pair(&env, &leaves[0], &leaves[1]),
+        ],
+        vec![
+            &env,
+            leaves[2].clone(),
+            merkle::hash_pair(&env, &leaves[0], &leaves[1]),
+        ],
+    ];
+
+    for (index, (leaf, path)) in leaves.iter().zip(&paths).enumerate() {
+        let index = index as u32;
+        assert!(merkle::verify_merkle_path(&env, &root, leaf, path, index));
+        assert!(!merkle::verify_merkle_path(
+            &env,
+            &root,
+            leaf,
+            path,
+            index ^ 1
+        ));
+        // The same position with a higher bit set must not verify.
+        assert!(!merkle::verify_merkle_path(
+            &env,
+            &root,
+            leaf,
+            path,
+            index | 4
+        ));
+    }
+    assert!(!merkle::verify_merkle_path(
+        &env, &root, &leaves[1], &paths[0], 0
+    ));
+
+    // A single leaf is its own root, at index 0 only.
+    assert!(merkle::verify_merkle_path(
+        &env,
+        &leaves[0],
+        &leaves[0],
+        &Vec::new(&env),
+        0
+    ));
+    assert!(!merkle::verify_merkle_path(
+        &env,
+        &leaves[0],
+        &leaves[0],
+        &Vec::new(&env),
+        1
+    ));
+
+    // Paths deeper than the index can address are rejected.
+    let mut deep = Vec::new(&env);
+    for _ in 0..=u32::BITS {
+        deep.push_back(leaves[0].clone());
+    }
+    assert!(!merkle::verify_merkle_path(
+        &env, &root, &leaves[0], &deep, 0
+    ));
+}
+
+#[test]
+fn test_verify_sorted_merkle_path() {
+    let env = Env::default();
+    let (leaves, root) = merkle_tree(&env, merkle::hash_sorted_pair);
+    let upper_right = merkle::hash_sorted_pair(&env, &leaves[2], &leaves[3]);
+
+    assert!(merkle::verify_sorted_merkle_path(
+        &env,
+        &root,
+        &leaves[0],
+        &vec![&env, leaves[1].clone(), upper_right.clone()],
+    ));
+    // Pairs are ordered before hashing, so siblings verify in either position.
+    assert!(merkle::verify_sorted_merkle_path(
+        &env,
+        &root,
+        &leaves[1],
+        &vec![&env, leaves[0].clone(), upper_right.clone()],
+    ));
+    assert!(!merkle::verify_sorted_merkle_path(
+        &env,
+        &root,
+        &leaves[2],
+        &vec![&env, leaves[1].clone(), upper_right],
+    ));
+    assert_eq!(
+        merkle::hash_sorted_pair(&env, &leaves[3], &leaves[2]),
+        merkle::hash_pair(&env, &leaves[2], &leaves[3])
+    );
+}
+
+#[test]
+fn test_journal_chunks_depth() {
+    let depths = [0, 0, 1, 2, 2, 3, 3, 3, 3, 4].map(|depth| depth as u32);
+    for (chunks, depth) in depths.iter().enumerate() {
+        assert_eq!(journal::chunks::depth(chunks as u32), *depth);
+    }
+}
+
+#[test]
+fn test_journal_chunks_commit() {
+    let env = Env::default();
+    let leaf =
+        |data: &[u8]| -> BytesN<32> { env.crypto().sha256(&Bytes::from_slice(&env, data)).into() };
+
+    let commitment = journal::chunks::commit(&env, &Bytes::from_slice(&env, b"abcdefgh"), 4);
+    assert_eq!(commitment.chunks, 2);
+    assert_eq!(
+        commitment.root,
+        merkle::hash_pair(&env, &leaf(b"abcd"), &leaf(b"efgh"))
+    );
+
+    // Padded with zero digests up to a power of two.
+    let commitment = journal::chunks::commit(&env, &Bytes::from_slice(&env, b"abcdefghi"), 4);
+    let zero = BytesN::from_array(&env, &ZERO_DIGEST);
+    assert_eq!(commitment.chunks, 3);
+    assert_eq!(
+        commitment.root,
+        merkle::hash_pair(
+            &env,
+            &merkle::hash_pair(&env, &leaf(b"abcd"), &leaf(b"efgh")),
+            &merkle::hash_pair(&env, &leaf(b"i"), &zero),
+        )
+    );
+
+    // An empty journal is a single empty chunk.
+    let commitment = journal::chunks::commit(&env, &Bytes::new(&env), 4);
+    assert_eq!(commitment.chunks, 1);
+    assert_eq!(commitment.root, leaf(b""));
+    assert!(journal::chunks::verify_chunk(
+        &env,
+        &commitment,
+        0,
+        &Bytes::new(&env),
+        &Vec::new(&env),
+    ));
+
+    // The guest commits the commitment as `(root, chunk_count)`.
+    assert_eq!(
+        journal::encode(&env, &commitment),
+        journal::encode(&env, &(commitment.root.clone(), 1u32))
+    );
+    assert_eq!(
+        journal::decode(&journal::encode(&env, &commitment)),
+        Some(commitment)
+    );
+}
+
+#[test]
+fn test_journal_chunks_verify_chunk() {
+    let env = Env::default();
+    let data = Bytes::from_slice(&env, b"a journal of 21 bytes");
+    let commitment = journal::chunks::commit(&env, &data, 4);
+    assert_eq!(commitment.chunks, 6);
+
+    for index in 0..commitment.chunks {
+        let (chunk, path) = journal::chunks::open(&env, &data, 4, index).unwrap();
+        assert_eq!(chunk, data.slice(index * 4..data.len().min(index * 4 + 4)));
+        assert!(journal::chunks::verify_chunk(
+            &env,
+            &commitment,
+            index,
+            &chunk,
+            &path
+        ));
+
+        let mut other = chunk.clone();
+        other.set(0, other.get_unchecked(0) ^ 1);
+        assert!(!journal::chunks::verify_chunk(
+            &env,
+            &commitment,
+            index,
+            &other,
+            &path
+        ));
+        assert!(!journal::chunks::verify_chunk(
+            &env,
+            &commitment,
+            index ^ 1,
+            &chunk,
+            &path
+        ));
+    }
+    assert_eq!(journal::chunks::open(&env, &data, 4, 6), None);
+
+    // Inner nodes cannot be passed off as chunks with a shorter path, nor padding leaves
+    // opened past the last chunk.
+    let (chunk, mut path) = journal::chunks::open(&env, &data, 4, 0).unwrap();
+    let mut node = Bytes::from(env.crypto().sha256(&chunk).to_bytes());
+    node.append(&Bytes::from(path.pop_front_unchecked()));
+    let node_digest: BytesN<32> = env.crypto().sha256(&node).into();
+    assert!(merkle::verify_merkle_path(
+        &env,
+        &commitment.root,
+        &node_digest,
+        &path,
+        0
+    ));
+    assert!(!journal::chunks::verify_chunk(
+        &env,
+        &commitment,
+        0,
+        &node,
+        &path
+    ));
+    let (_, path) = journal::chunks::open(&env, &data, 4, 5).unwrap();
+    assert!(!journal::chunks::verify_chunk(
+        &env,
+        &commitment,
+        7,
+        &Bytes::new(&env),
+        &path
+    ));
+}
+
+#[test]
+fn test_is_alert() {
+    let env = Env::default();
+    let topic = |name: &str| Symbol::new(&env, name).to_val();
+    let pause_alert = vec![
+        &env,
+        topic(alerts::ALERT_TOPIC),
+        topic(alerts::PAUSE_ALERT),
+        BytesN::from_array(&env, &[0xEE; 4]).to_val(),
+    ];
+
+    assert!(alerts::is_alert(&env, &pause_alert, alerts::PAUSE_ALERT));
+    assert!(!alerts::is_alert(&env, &pause_alert, alerts::ESTOP_ALERT));
+    assert!(!alerts::is_alert(
+        &env,
+        &vec![&env, topic(alerts::ALERT_TOPIC)],
+        alerts::PAUSE_ALERT
+    ));
+    assert!(!alerts::is_alert(
+        &env,
+        &vec![&env, topic("selector_tripped"), topic(alerts::PAUSE_ALERT)],
+        alerts::PAUSE_ALERT
+    ));
+    assert!(!alerts::is_alert(
+        &env,
+        &vec![&env, 1u32.into_val(&env), topic(alerts::PAUSE_ALERT)],
+        alerts::PAUSE_ALERT
+    ));
+}
@@ -8,6 +8,7 @@
 //!
 //! - [`Receipt`]: Complete proof package with seal and claim
 //! - [`ReceiptClaim`]: Detailed execution claim including state and exit codes
+//! - [`ReceiptEnvelope`]: Versioned container of a receipt and its journal, for transport
 //!
 //! ## Verification Flow
 //!
@@ -16,9 +17,20 @@
 //! 3. The receipt is submitted to a Soroban verifier contract for validation
 //! 4. The verifier cryptographically validates that the seal proves the claim
 
-use soroban_sdk::{Address, Bytes, BytesN, Env, contracterror, contracttype};
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, String, Vec, contracterror, contracttype,
+    xdr::{FromXdr, ToXdr},
+};
 
-/// Errors that can occur during Groth16 proof verification.
+use crate::{HostSha256, hash::RecordingSha256};
+
+/// Errors returned by verifier and router contracts.
+///
+/// Codes follow the workspace error registry (see the crate documentation): generic verifier
+/// errors use `0..=99`, Groth16-specific errors `100..=199` and router errors `200..=299`.
+/// Codes `0..=9` predate the registry and keep their original variants, including the Groth16
+/// and router errors among them. Numeric values are part of the public ABI and must never
+/// change.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -39,6 +51,75 @@ pub enum VerifierError {
     SelectorInUse = 6,
     /// The selector is not registered.
     SelectorUnknown = 7,
+    /// The claim (or the image id it was produced for) was revoked by governance.
+    ClaimRevoked = 8,
+    /// The proof system cannot prove this number of claims with a single seal.
+    UnsupportedAggregation = 9,
+    /// The stored storage schema is newer than this build can migrate.
+    UnsupportedSchemaVersion = 10,
+    /// The receipt envelope has a version this build does not understand.
+    UnsupportedEnvelopeVersion = 11,
+    /// The receipt envelope is for a proof system this verifier does not support.
+    UnsupportedProofSystem = 12,
+    /// The proven journal is not the encoding of the type the consumer decodes it as.
+    MalformedJournal = 13,
+    /// An upgrade did not increase the contract version.
+    VersionNotIncreasing = 14,
+    /// The claim digest is all zeros, which indicates a caller-side encoding bug.
+    ZeroClaimDigest = 15,
+    /// The image id is all zeros, which indicates a caller-side encoding bug.
+    ZeroImageId = 16,
+    /// The verifier was deprecated and its grace period has ended.
+    Deprecated = 17,
+    /// The proof is valid but not in canonical form (strict mode only).
+    NonCanonicalProof = 101,
+    /// The verifier registered for the selector could not be reached.
+    VerifierUnavailable = 204,
+    /// The consumer has not registered a configuration with the router.
+    ConsumerNotConfigured = 205,
+    /// The journal does not start with the prefix configured by the consumer.
+    JournalPrefixMismatch = 206,
+    /// The receipt's claim digest does not match the claim derived from its image id and
+    /// journal digest.
+    ClaimDigestMismatch = 207,
+    /// The caller exhausted its verifications for the current rate limit window.
+    RateLimitExceeded = 208,
+    /// The verifier is not the active verifier of any selector.
+    VerifierNotRegistered = 209,
+    /// The selector was paused by the router's circuit breaker.
+    SelectorPaused = 210,
+    /// The routing table was finalized and can no longer change.
+    RegistryFinalized = 211,
+    /// The image id requires proofs from several verifiers, which a single seal cannot provide.
+    QuorumRequired = 212,
+    /// Fewer distinct verifiers accepted the claim than the image id's quorum requires.
+    QuorumNotMet = 213,
+    /// The selector reached the sunset ledger set for it by governance.
+    SelectorExpired = 214,
+}
+
+/// Fails with [`VerifierError::ZeroImageId`] if `image_id` is all zeros.
+///
+/// No guest program has the zero image id, so it almost always means the caller passed an
+/// uninitialized value. Checking it first avoids paying for a proof verification that cannot
+/// succeed.
+pub fn ensure_nonzero_image_id(image_id: &BytesN<32>) -> Result<(), VerifierError> {
+    if image_id.to_array() == [0u8; 32] {
+        return Err(VerifierError::ZeroImageId);
+    }
+    Ok(())
+}
+
+/// Fails with [`VerifierError::ZeroClaimDigest`] if `claim_digest` is all zeros.
+///
+/// The zero digest is never the digest of a claim; a valid proof for it is the emergency
+/// stop's proof of exploit. Application-facing entrypoints reject it before any expensive
+/// work, while verifiers must keep accepting it so the emergency stop can check such proofs.
+pub fn ensure_nonzero_claim_digest(claim_digest: &BytesN<32>) -> Result<(), VerifierError> {
+    if claim_digest.to_array() == [0u8; 32] {
+        return Err(VerifierError::ZeroClaimDigest);
+    }
+    Ok(())
 }
 
 /// A receipt attesting to a claim using the RISC Zero proof system.
@@ -58,24 +139,27 @@ pub enum VerifierError {
 /// have meaningful security guarantees. This is similar to verifying an ECDSA signature where
 /// the message hash must be computed correctly.
 ///
-/// For standard successful executions, use:
+/// For standard successful executions, [`Receipt::from_execution`] computes it:
+///
 /// ```ignore
-/// let claim = ReceiptClaim::new(&env, image_id, journal_digest);
-/// let claim_digest = claim.digest(&env);
+/// use risc0_verifier_interface::Receipt;
+///
+/// let receipt = Receipt::from_execution(&env, seal, image_id, journal_digest);
 /// ```
 ///
-/// # Example
+/// Other claims (e.g. with a non-zero exit code) are built with [`ReceiptClaim`]:
 ///
 /// ```ignore
-/// use risc0_verifier_interface::{Receipt, ReceiptClaim, Seal};
+/// use risc0_verifier_interface::{Receipt, ReceiptClaim};
 ///
-/// let claim = ReceiptClaim::new(&env, image_id, journal_digest);
+/// let claim = ReceiptClaim::with_user_exit_code(&env, image_id, journal_digest, 1);
 /// let receipt = Receipt {
 ///     seal: seal,
 ///     claim_digest: claim.digest(&env),
 /// };
 /// ```
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Receipt {
     /// The zero-knowledge proof (SNARK) as raw bytes.
     pub seal: Bytes,
@@ -94,7 +178,8 @@ pub struct Receipt {
 /// The claim follows RISC Zero's standard structure for zkVM execution:
 ///
 /// - **pre_state_digest**: The image id of the guest program
-/// - **post_state_digest**: Final state after execution (fixed constant for successful runs)
+/// - **post_state_digest**: Final state after execution (a per-release constant for successful
+///   runs)
 /// - **exit_code**: How the program terminated (system and user codes)
 /// - **input**: Committed input digest (currently unused, set to zero)
 /// - **output**: Digest of the [`Output`] containing journal and assumptions
@@ -102,8 +187,10 @@ pub struct Receipt {
 /// # Usage
 ///
 /// Most users should construct claims using [`ReceiptClaim::new()`] for standard
-/// successful executions, which automatically sets appropriate defaults.
+/// successful executions, which automatically sets appropriate defaults, or
+/// [`ReceiptClaim::with_post_state_digest()`] for a verifier of another zkVM release.
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ReceiptClaim {
     /// Digest of the system state before execution (the program [`ImageId`]).
     ///
@@ -113,9 +200,10 @@ pub struct ReceiptClaim {
 
     /// Digest of the system state after execution has completed.
     ///
-    /// This is a fixed constant value
-    /// (`0xa3acc27117418996340b84e5a90f3ef4c49d22c79e44aad822ec9c313e1eb8e2`)
-    /// representing the halted state.
+    /// For successful runs this is the halted state of the zkVM release that ran the guest,
+    /// which may differ across releases; for the current release it is
+    /// [`POST_STATE_DIGEST_HALTED`](Self::POST_STATE_DIGEST_HALTED). Verifiers report the
+    /// digest of the release they verify.
     post_state_digest: BytesN<32>,
 
     /// The exit code indicating how the execution terminated.
@@ -141,16 +229,35 @@ pub struct ReceiptClaim {
 ///
 /// The exit code consists of two parts:
 /// - **System code**: Indicates the execution mode (halted, paused, or split)
-/// - **User code**: Application-specific exit code (8 bytes)
+/// - **User code**: Application-specific exit code (`u32`)
 ///
-/// For standard successful executions, the system code is [`SystemExitCode::Halted`]
-/// and the user code is zero.
+/// Both parts mirror the `(system, user)` pair of the RISC Zero `ExitCode`. For standard
+/// successful executions, the system code is [`SystemExitCode::Halted`] and the user code is
+/// zero.
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ExitCode {
     /// System-level exit code indicating the execution termination mode.
     system: SystemExitCode,
-    /// User-defined exit code (8 bytes) set by the guest program.
-    user: BytesN<8>,
+    /// User-defined exit code set by the guest program.
+    user: u32,
+}
+
+impl ExitCode {
+    /// Constructs an [`ExitCode`] from its system and user parts.
+    pub fn new(system: SystemExitCode, user: u32) -> Self {
+        Self { system, user }
+    }
+
+    /// Returns the system-level exit code.
+    pub fn system(&self) -> SystemExitCode {
+        self.system
+    }
+
+    /// Returns the user-defined exit code.
+    pub fn user(&self) -> u32 {
+        self.user
+    }
 }
 
 /// System-level exit codes for RISC Zero execution.
@@ -165,8 +272,8 @@ pub struct ExitCode {
 ///
 /// # Encoding
 ///
-/// These values are encoded as `u32` in the receipt claim digest computation,
-/// shifted left by 24 bits.
+/// These values are encoded as little-endian `u32` words in the receipt claim digest
+/// computation.
 #[contracttype]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
@@ -190,6 +297,7 @@ pub enum SystemExitCode {
 /// - **journal_digest**: SHA-256 hash of the journal (public outputs)
 /// - **assumptions_digest**: SHA-256 hash of assumptions (zero for unconditional proofs)
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Output {
     /// SHA-256 digest of the journal bytes (public outputs from the guest program).
     journal_digest: BytesN<32>,
@@ -200,13 +308,25 @@ pub struct Output {
 }
 
 impl Output {
-    /// Pre-computed SHA-256("risc0.Output") tag digest.
-    /// This constant avoids computing the tag hash on every call.
-    const TAG_DIGEST: [u8; 32] = [
-        0x77, 0xea, 0xfe, 0xb3, 0x66, 0xa7, 0x8b, 0x47, 0x74, 0x7d, 0xe0, 0xd7, 0xbb, 0x17, 0x62,
-        0x84, 0x08, 0x5f, 0xf5, 0x56, 0x48, 0x87, 0x00, 0x9a, 0x5b, 0xe6, 0x3d, 0xa3, 0x2d, 0x35,
-        0x59, 0xd4,
-    ];
+    /// Constructs an [`Output`] from its journal and assumptions digests.
+    ///
+    /// Use the zero digest as `assumptions_digest` for unconditional receipts.
+    pub fn new(journal_digest: BytesN<32>, assumptions_digest: BytesN<32>) -> Self {
+        Self {
+            journal_digest,
+            assumptions_digest,
+        }
+    }
+
+    /// Returns the SHA-256 digest of the journal.
+    pub fn journal_digest(&self) -> &BytesN<32> {
+        &self.journal_digest
+    }
+
+    /// Returns the digest of the assumptions.
+    pub fn assumptions_digest(&self) -> &BytesN<32> {
+        &self.assumptions_digest
+    }
 
     /// Computes the SHA-256 digest of this [`Output`] struct.
     ///
@@ -228,33 +348,265 @@ impl Output {
     ///
     /// A 32-byte SHA-256 digest of the output structure.
     pub fn digest(&self, env: &Env) -> BytesN<32> {
-        let mut data = Bytes::new(env);
-        data.append(&Bytes::from_array(env, &Self::TAG_DIGEST));
-        data.append(&self.journal_digest.clone().into());
-        data.append(&self.assumptions_digest.clone().into());
-        data.append(&Bytes::from_array(env, &[0x02, 0x00]));
+        let digest = risc0_digest::output_digest(
+            HostSha256::new(env),
+            &self.journal_digest.to_array(),
+            &self.assumptions_digest.to_array(),
+        );
+        BytesN::from_array(env, &digest)
+    }
+
+    /// Returns the message hashed by [`digest`](Self::digest), 98 bytes laid out as described
+    /// there.
+    ///
+    /// Lets implementations in other languages compare their encoding byte by byte.
+    pub fn preimage(&self, env: &Env) -> Bytes {
+        let mut preimage = Bytes::new(env);
+        risc0_digest::output_digest(
+            RecordingSha256::new(&mut preimage),
+            &self.journal_digest.to_array(),
+            &self.assumptions_digest.to_array(),
+        );
+        preimage
+    }
+}
+
+/// An assumption of a conditional receipt: a claim that must be proven by another receipt.
+///
+/// Mirrors RISC Zero's `Assumption`. Its digest is committed to, through [`Assumptions`], by
+/// the assumptions digest of the conditional receipt's [`Output`].
+///
+/// # Fields
+///
+/// - **claim**: Digest of the [`ReceiptClaim`] the assumption requires a proof of
+/// - **control_root**: Control root of the recursion circuit the proof must verify under, or
+///   the zero digest for the control root of the conditional receipt itself
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Assumption {
+    /// Digest of the assumed [`ReceiptClaim`].
+    claim: BytesN<32>,
+    /// Control root the assumption must be resolved under, zero for the same as the receipt.
+    control_root: BytesN<32>,
+}
+
+impl Assumption {
+    /// Constructs an [`Assumption`] from its claim digest and control root.
+    ///
+    /// Use the zero digest as `control_root` for an assumption resolved by a receipt of the
+    /// same verifier.
+    pub fn new(claim: BytesN<32>, control_root: BytesN<32>) -> Self {
+        Self {
+            claim,
+            control_root,
+        }
+    }
+
+    /// Returns the digest of the assumed claim.
+    pub fn claim(&self) -> &BytesN<32> {
+        &self.claim
+    }
+
+    /// Returns the control root the assumption must be resolved under.
+    pub fn control_root(&self) -> &BytesN<32> {
+        &self.control_root
+    }
+
+    /// Computes the SHA-256 digest of this [`Assumption`] struct.
+    ///
+    /// ```text
+    /// SHA-256(SHA-256("risc0.Assumption") || claim || control_root || length)
+    /// ```
+    ///
+    /// Where `length` = 0x02 0x00 (2 fields in little-endian u16).
+    pub fn digest(&self, env: &Env) -> BytesN<32> {
+        let digest = risc0_digest::assumption_digest(
+            HostSha256::new(env),
+            &self.claim.to_array(),
+            &self.control_root.to_array(),
+        );
+        BytesN::from_array(env, &digest)
+    }
+}
+
+/// Builder of the assumptions digest committed by a conditional receipt's [`Output`].
+///
+/// RISC Zero hashes the list of assumptions as a cons list tagged `risc0.Assumptions`: the
+/// empty list is the zero digest, and [`add`](Self::add) prepends an assumption to the head,
+/// like upstream's `Assumptions::add`. Only the running digest is kept, so building the list
+/// takes constant memory regardless of its length.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Assumptions {
+    /// Digest of the list built so far.
+    digest: BytesN<32>,
+}
+
+impl Assumptions {
+    /// Returns the empty list, the assumptions of an unconditional receipt.
+    pub fn new(env: &Env) -> Self {
+        Self {
+            digest: BytesN::from_array(env, &[0u8; 32]),
+        }
+    }
+
+    /// Returns the list of `assumptions`, in order: the first one is the head.
+    pub fn from_assumptions(env: &Env, assumptions: &Vec<Assumption>) -> Self {
+        let digests = assumptions
+            .iter()
+            .map(|assumption| assumption.digest(env).to_array());
+        let digest = risc0_digest::assumptions_digest(|| HostSha256::new(env), digests);
+        Self {
+            digest: BytesN::from_array(env, &digest),
+        }
+    }
+
+    /// Prepends `assumption` to the head of the list.
+    pub fn add(&mut self, env: &Env, assumption: &Assumption) {
+        let digest = risc0_digest::tagged_list_cons(
+            HostSha256::new(env),
+            &risc0_digest::ASSUMPTIONS_TAG_DIGEST,
+            &assumption.digest(env).to_array(),
+            &self.digest.to_array(),
+        );
+        self.digest = BytesN::from_array(env, &digest);
+    }
+
+    /// Returns the assumptions digest of the list, to commit to with [`Output::new`].
+    pub fn digest(&self) -> &BytesN<32> {
+        &self.digest
+    }
+}
 
-        env.crypto().sha256(&data).into()
+impl Receipt {
+    /// Constructs the receipt of a successful execution of `image_id` that committed a
+    /// journal with digest `journal_digest`.
+    ///
+    /// The claim digest is that of [`ReceiptClaim::new()`], for the current zkVM release. For a
+    /// verifier whose post-state digest differs, build the claim with
+    /// [`ReceiptClaim::with_post_state_digest()`] instead.
+    pub fn from_execution(
+        env: &Env,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal_digest: BytesN<32>,
+    ) -> Self {
+        Self {
+            seal,
+            claim_digest: ReceiptClaim::new(env, image_id, journal_digest).digest(env),
+        }
+    }
+}
+
+/// A versioned container of a receipt, for passing proofs between contracts and off-chain
+/// tooling.
+///
+/// The envelope carries a [`Receipt`] together with the identifiers needed to interpret it,
+/// and optionally the journal the claim commits to. Callers that exchange envelopes keep a
+/// single XDR type when fields are added: a new layout gets a new
+/// [`version`](Self::version), which verifiers reject with
+/// [`VerifierError::UnsupportedEnvelopeVersion`] until they support it.
+///
+/// # Fields
+///
+/// - **version**: Layout version of the envelope, [`ReceiptEnvelope::VERSION`]
+/// - **proof_system**: Proof system of the seal, [`ReceiptEnvelope::PROOF_SYSTEM_RISC_ZERO`]
+///   for RISC Zero seals, which start with their verifier selector
+/// - **seal**, **claim_digest**: The [`Receipt`]
+/// - **journal**: The raw journal, if the sender includes it
+///
+/// Both identifiers are `u32` rather than enums, so an envelope from a newer sender decodes
+/// and fails with an error instead of a conversion trap.
+///
+/// The journal is not bound by the claim digest alone; use
+/// [`commits_journal`](Self::commits_journal) to check it against the image id.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiptEnvelope {
+    /// Layout version of the envelope.
+    pub version: u32,
+    /// Identifier of the proof system of the seal.
+    pub proof_system: u32,
+    /// The zero-knowledge proof as raw bytes.
+    pub seal: Bytes,
+    /// SHA-256 digest of the [`ReceiptClaim`] the seal proves.
+    pub claim_digest: BytesN<32>,
+    /// The raw journal committed by the guest, if included.
+    pub journal: Option<Bytes>,
+}
+
+impl ReceiptEnvelope {
+    /// Layout version of envelopes built by this crate.
+    pub const VERSION: u32 = 1;
+
+    /// Proof system identifier of RISC Zero seals.
+    pub const PROOF_SYSTEM_RISC_ZERO: u32 = 1;
+
+    /// Wraps `receipt` in an envelope of the current version for a RISC Zero seal.
+    pub fn new(receipt: Receipt, journal: Option<Bytes>) -> Self {
+        Self {
+            version: Self::VERSION,
+            proof_system: Self::PROOF_SYSTEM_RISC_ZERO,
+            seal: receipt.seal,
+            claim_digest: receipt.claim_digest,
+            journal,
+        }
+    }
+
+    /// Returns the receipt of the envelope, after checking that this build understands it.
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::UnsupportedEnvelopeVersion`] - `version` is not [`Self::VERSION`]
+    /// - [`VerifierError::UnsupportedProofSystem`] - `proof_system` is not
+    ///   [`Self::PROOF_SYSTEM_RISC_ZERO`]
+    pub fn receipt(&self) -> Result<Receipt, VerifierError> {
+        if self.version != Self::VERSION {
+            return Err(VerifierError::UnsupportedEnvelopeVersion);
+        }
+        if self.proof_system != Self::PROOF_SYSTEM_RISC_ZERO {
+            return Err(VerifierError::UnsupportedProofSystem);
+        }
+        Ok(Receipt {
+            seal: self.seal.clone(),
+            claim_digest: self.claim_digest.clone(),
+        })
+    }
+
+    /// Returns whether the envelope includes a journal and the claim digest is that of a
+    /// successful execution of `image_id` committing it, as built by
+    /// [`ReceiptClaim::with_post_state_digest()`].
+    ///
+    /// `post_state_digest` is that of the verifier the seal is for, see
+    /// [`RiscZeroVerifierInfo::post_state_digest`](crate::RiscZeroVerifierInfo::post_state_digest).
+    pub fn commits_journal(
+        &self,
+        env: &Env,
+        image_id: &BytesN<32>,
+        post_state_digest: &BytesN<32>,
+    ) -> bool {
+        let Some(journal) = &self.journal else {
+            return false;
+        };
+        let journal_digest: BytesN<32> = env.crypto().sha256(journal).into();
+        let claim = ReceiptClaim::with_post_state_digest(
+            env,
+            image_id.clone(),
+            journal_digest,
+            post_state_digest.clone(),
+            0,
+        );
+        claim.digest(env) == self.claim_digest
     }
 }
 
 impl ReceiptClaim {
-    /// Pre-computed SHA-256("risc0.ReceiptClaim") tag digest.
-    /// This constant avoids computing the tag hash on every call.
-    const TAG_DIGEST: [u8; 32] = [
-        0xcb, 0x1f, 0xef, 0xcd, 0x1f, 0x2d, 0x9a, 0x64, 0x97, 0x5c, 0xbb, 0xbf, 0x6e, 0x16, 0x1e,
-        0x29, 0x14, 0x43, 0x4b, 0x0c, 0xbb, 0x99, 0x60, 0xb8, 0x4d, 0xf5, 0xd7, 0x17, 0xe8, 0x6b,
-        0x48, 0xaf,
-    ];
-
-    /// Fixed post-state digest for a halted execution.
-    ///
-    /// This is a protocol constant used in standard successful receipt claims.
-    const POST_STATE_DIGEST_HALTED: [u8; 32] = [
-        0xa3, 0xac, 0xc2, 0x71, 0x17, 0x41, 0x89, 0x96, 0x34, 0x0b, 0x84, 0xe5, 0xa9, 0x0f, 0x3e,
-        0xf4, 0xc4, 0x9d, 0x22, 0xc7, 0x9e, 0x44, 0xaa, 0xd8, 0x22, 0xec, 0x9c, 0x31, 0x3e, 0x1e,
-        0xb8, 0xe2,
-    ];
+    /// Post-state digest for a halted execution.
+    ///
+    /// This is the protocol constant of the current zkVM release, used in standard successful
+    /// receipt claims. It may change across releases: verifiers build their claims with the
+    /// digest of the release they verify, see
+    /// [`with_post_state_digest`](Self::with_post_state_digest).
+    pub const POST_STATE_DIGEST_HALTED: [u8; 32] = risc0_digest::POST_STATE_DIGEST_HALTED;
 
     /// Constructs a standard [`ReceiptClaim`] for a successful guest program execution.
     ///
@@ -264,7 +616,8 @@ impl ReceiptClaim {
     /// - **Input**: Zero digest (no committed input)
     /// - **Exit code**: (Halted, 0) indicating successful completion
     /// - **Assumptions**: Zero digest (unconditional proof)
-    /// - **Post-state**: Fixed constant for halted state
+    /// - **Post-state**: [`POST_STATE_DIGEST_HALTED`](Self::POST_STATE_DIGEST_HALTED), the
+    ///   halted state of the current zkVM release
     ///
     /// # Parameters
     ///
@@ -276,24 +629,118 @@ impl ReceiptClaim {
     ///
     /// A [`ReceiptClaim`] configured for standard successful execution.
     pub fn new(env: &Env, image_id: BytesN<32>, journal_digest: BytesN<32>) -> Self {
-        let output = Output {
+        Self::with_user_exit_code(env, image_id, journal_digest, 0)
+    }
+
+    /// Constructs a [`ReceiptClaim`] for a guest program that halted with a user exit code.
+    ///
+    /// Identical to [`ReceiptClaim::new()`] except for the user part of the exit code, for
+    /// guests that intentionally terminate with a non-zero code (e.g. to encode a result
+    /// status). The system code is still [`SystemExitCode::Halted`], and the post-state digest
+    /// that of the current zkVM release; see
+    /// [`with_post_state_digest`](Self::with_post_state_digest) for other releases.
+    pub fn with_user_exit_code(
+        env: &Env,
+        image_id: BytesN<32>,
+        journal_digest: BytesN<32>,
+        user_exit_code: u32,
+    ) -> Self {
+        let post_state_digest = BytesN::from_array(env, &Self::POST_STATE_DIGEST_HALTED);
+        Self::with_post_state_digest(
+            env,
+            image_id,
             journal_digest,
-            assumptions_digest: BytesN::from_array(env, &[0u8; 32]),
-        };
-        let post_state: BytesN<32> = BytesN::from_array(env, &Self::POST_STATE_DIGEST_HALTED);
+            post_state_digest,
+            user_exit_code,
+        )
+    }
+
+    /// Constructs a [`ReceiptClaim`] like [`ReceiptClaim::with_user_exit_code()`], for a zkVM
+    /// release whose halted post-state digest is `post_state_digest`.
+    ///
+    /// [`POST_STATE_DIGEST_HALTED`](Self::POST_STATE_DIGEST_HALTED) is the digest of the
+    /// current release; verifiers of other releases pass their own.
+    pub fn with_post_state_digest(
+        env: &Env,
+        image_id: BytesN<32>,
+        journal_digest: BytesN<32>,
+        post_state_digest: BytesN<32>,
+        user_exit_code: u32,
+    ) -> Self {
+        let output = Output::new(journal_digest, BytesN::from_array(env, &[0u8; 32]));
 
         Self {
             pre_state_digest: image_id,
-            post_state_digest: post_state,
+            post_state_digest,
             exit_code: ExitCode {
                 system: SystemExitCode::Halted,
-                user: BytesN::from_array(env, &[0u8; 8]),
+                user: user_exit_code,
             },
             input: BytesN::from_array(env, &[0u8; 32]),
             output: output.digest(env),
         }
     }
 
+    /// Constructs a [`ReceiptClaim`] from its individual parts.
+    ///
+    /// Unlike [`ReceiptClaim::new()`], this constructor does not assume a successful,
+    /// unconditional execution. All fields are stored as given, which allows advanced users
+    /// (continuations, custom inputs, conditional outputs) to describe any claim. The
+    /// `output_digest` must be the digest of the [`Output`] struct, see [`Output::digest()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `pre_state_digest`: The image id of the guest program
+    /// - `post_state_digest`: Digest of the system state after execution
+    /// - `exit_code`: How the execution terminated
+    /// - `input`: Digest of the committed input
+    /// - `output_digest`: Digest of the execution [`Output`]
+    ///
+    /// # Returns
+    ///
+    /// A [`ReceiptClaim`] whose [`digest()`](ReceiptClaim::digest) commits to exactly the
+    /// given parts.
+    pub fn from_parts(
+        pre_state_digest: BytesN<32>,
+        post_state_digest: BytesN<32>,
+        exit_code: ExitCode,
+        input: BytesN<32>,
+        output_digest: BytesN<32>,
+    ) -> Self {
+        Self {
+            pre_state_digest,
+            post_state_digest,
+            exit_code,
+            input,
+            output: output_digest,
+        }
+    }
+
+    /// Returns the image id of the guest program (the pre-state digest).
+    pub fn pre_state_digest(&self) -> &BytesN<32> {
+        &self.pre_state_digest
+    }
+
+    /// Returns the digest of the system state after execution.
+    pub fn post_state_digest(&self) -> &BytesN<32> {
+        &self.post_state_digest
+    }
+
+    /// Returns the exit code of the execution.
+    pub fn exit_code(&self) -> &ExitCode {
+        &self.exit_code
+    }
+
+    /// Returns the digest of the committed input.
+    pub fn input(&self) -> &BytesN<32> {
+        &self.input
+    }
+
+    /// Returns the digest of the execution [`Output`].
+    pub fn output(&self) -> &BytesN<32> {
+        &self.output
+    }
+
     /// Computes the SHA-256 digest of this [`ReceiptClaim`].
     ///
     /// This digest becomes the `claim_digest` field in a [`Receipt`] and is what the
@@ -318,7 +765,8 @@ impl ReceiptClaim {
     ///
     /// Where:
     /// - `tag_digest` = SHA-256("risc0.ReceiptClaim")
-    /// - Exit codes are encoded as big-endian u32, shifted left by 24 bits
+    /// - Exit codes are encoded as full little-endian u32 words, as in the RISC Zero reference
+    ///   implementation
     /// - `length` = 0x04 0x00 (4 state fields in little-endian u16)
     ///
     /// # Parameters
@@ -334,38 +782,89 @@ impl ReceiptClaim {
     /// This digest must be computed correctly for verification to be secure. Always use
     /// this method rather than implementing custom hashing.
     pub fn digest(&self, env: &Env) -> BytesN<32> {
-        let mut data = Bytes::new(env);
-        data.append(&Bytes::from_array(env, &Self::TAG_DIGEST));
-        data.append(&self.input.clone().into());
-        data.append(&self.pre_state_digest.clone().into());
-        data.append(&self.post_state_digest.clone().into());
-        data.append(&self.output.clone().into());
-
-        // System exit code encoding: (value as u32) << 24, then to_be_bytes()
-        //
-        // | Value           | as u32 | << 24        | to_be_bytes()             |
-        // |-----------------|--------|--------------|---------------------------|
-        // | Halted = 0      | 0      | 0x00000000   | [0x00, 0x00, 0x00, 0x00]  |
-        // | Paused = 1      | 1      | 0x01000000   | [0x01, 0x00, 0x00, 0x00]  |
-        // | SystemSplit = 2 | 2      | 0x02000000   | [0x02, 0x00, 0x00, 0x00]  |
-        //
-        // Shifting left by 24 bits moves the value into the MSB of the u32.
-        // to_be_bytes() outputs the MSB first, so the result is [value, 0, 0, 0].
-        // Since all variants fit in one byte, we write this directly.
-        data.append(&Bytes::from_array(
-            env,
-            &[self.exit_code.system as u8, 0, 0, 0],
-        ));
+        let digest = risc0_digest::receipt_claim_digest(
+            HostSha256::new(env),
+            &self.input.to_array(),
+            &self.pre_state_digest.to_array(),
+            &self.post_state_digest.to_array(),
+            &self.output.to_array(),
+            self.exit_code.system as u32,
+            self.exit_code.user,
+        );
+        BytesN::from_array(env, &digest)
+    }
+
+    /// Returns the message hashed by [`digest`](Self::digest), 170 bytes laid out as described
+    /// there.
+    ///
+    /// Lets implementations in other languages compare their encoding byte by byte.
+    pub fn preimage(&self, env: &Env) -> Bytes {
+        let mut preimage = Bytes::new(env);
+        risc0_digest::receipt_claim_digest(
+            RecordingSha256::new(&mut preimage),
+            &self.input.to_array(),
+            &self.pre_state_digest.to_array(),
+            &self.post_state_digest.to_array(),
+            &self.output.to_array(),
+            self.exit_code.system as u32,
+            self.exit_code.user,
+        );
+        preimage
+    }
+}
+
+impl From<&ReceiptClaim> for BytesN<32> {
+    /// Computes the claim digest, see [`ReceiptClaim::digest()`].
+    fn from(claim: &ReceiptClaim) -> Self {
+        claim.digest(claim.input.env())
+    }
+}
+
+impl From<ReceiptClaim> for BytesN<32> {
+    /// Computes the claim digest, see [`ReceiptClaim::digest()`].
+    fn from(claim: ReceiptClaim) -> Self {
+        Self::from(&claim)
+    }
+}
+
+impl From<&Receipt> for Bytes {
+    /// Serializes the receipt to its canonical XDR byte form.
+    fn from(receipt: &Receipt) -> Self {
+        receipt.clone().to_xdr(receipt.seal.env())
+    }
+}
+
+impl TryFrom<&Bytes> for Receipt {
+    type Error = soroban_sdk::ConversionError;
+
+    /// Deserializes a receipt from its canonical XDR byte form.
+    ///
+    /// Bytes that are not a valid encoding of this type may abort execution in the host
+    /// instead of returning an error.
+    fn try_from(bytes: &Bytes) -> Result<Self, Self::Error> {
+        Self::from_xdr(bytes.env(), bytes)
+    }
+}
 
-        // User exit code: first 4 bytes interpreted as BE u32, then << 24
-        // This effectively keeps only the 4th byte (index 3) at position 0
-        let user_bytes = self.exit_code.user.to_array();
-        data.append(&Bytes::from_array(env, &[user_bytes[3], 0, 0, 0]));
+impl From<&ReceiptClaim> for Bytes {
+    /// Serializes the claim to its canonical XDR byte form.
+    ///
+    /// This is the storage/wire encoding of the struct, not the preimage hashed by
+    /// [`ReceiptClaim::digest()`].
+    fn from(claim: &ReceiptClaim) -> Self {
+        claim.clone().to_xdr(claim.input.env())
+    }
+}
 
-        // Length: uint16(4) << 8 encoded as 2 bytes
-        data.append(&Bytes::from_array(env, &[0x04, 0x00]));
+impl TryFrom<&Bytes> for ReceiptClaim {
+    type Error = soroban_sdk::ConversionError;
 
-        env.crypto().sha256(&data).into()
+    /// Deserializes a claim from its canonical XDR byte form.
+    ///
+    /// Bytes that are not a valid encoding of this type may abort execution in the host
+    /// instead of returning an error.
+    fn try_from(bytes: &Bytes) -> Result<Self, Self::Error> {
+        Self::from_xdr(bytes.env(), bytes)
     }
 }
 
@@ -385,3 +884,73 @@ pub enum VerifierEntry {
     /// Selector is permanently removed.
     Tombstone,
 }
+
+/// Verification settings a consumer contract registers with the router.
+///
+/// Each consumer sets its own configuration, which the router then enforces on the consumer's
+/// behalf, e.g. in `verify_with_journal_prefix`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsumerConfig {
+    /// Bytes every journal verified for the consumer must start with, such as a schema
+    /// version tag.
+    pub journal_prefix: Bytes,
+}
+
+/// Static resource estimate for one verification through a verifier.
+///
+/// Estimates are registered per selector by the router operator, measured off-chain for the
+/// verifier's proof system. They are hints for pre-computing transaction footprints; the
+/// resources actually charged are always those of the simulated transaction.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CostEstimate {
+    /// CPU instructions consumed by the verification.
+    pub cpu_instructions: u64,
+    /// Resource fee of the verification, in stroops.
+    pub fee: i128,
+}
+
+/// Human-readable metadata of a guest program, registered for its image id in an image
+/// registry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageMetadata {
+    /// Name of the program.
+    pub name: String,
+    /// Version of the program, e.g. a semantic version or a git tag.
+    pub version: String,
+    /// SHA-256 digest of the URL of the program's source code.
+    pub source_url_digest: BytesN<32>,
+}
+
+/// Per-caller limit on verifications through the router's
+/// [`verify_as`](crate::RiscZeroVerifierRouterInterface::verify_as) entrypoint.
+///
+/// A caller's window opens at its first verification and lasts `window_ledgers` ledgers;
+/// within it, at most `max_verifications` verifications succeed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimit {
+    /// Verifications allowed per caller within a window.
+    pub max_verifications: u32,
+    /// Length of a window, in ledgers.
+    pub window_ledgers: u32,
+}
+
+/// Failure rate at which the router's circuit breaker pauses a selector.
+///
+/// The router counts the verifications dispatched to each selector in windows that open at the
+/// first verification and last `window_ledgers` ledgers. When a failure brings the failures of
+/// a window above `max_failure_bps` basis points of its verifications, once the window holds at
+/// least `min_verifications`, the selector is paused until a guardian resumes it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreaker {
+    /// Highest tolerated share of failed verifications, in basis points.
+    pub max_failure_bps: u32,
+    /// Verifications a window needs before it can trip the breaker.
+    pub min_verifications: u32,
+    /// Length of a window, in ledgers.
+    pub window_ledgers: u32,
+}
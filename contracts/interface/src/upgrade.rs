@@ -0,0 +1,141 @@
+//! # Versioned Upgrades
+//!
+//! Shared upgrade logic for the router and emergency-stop contracts. Each contract stores a
+//! contract version in instance storage, set by its constructor and bumped on every upgrade.
+//! Upgrades must strictly increase the version, so an older (possibly vulnerable) build cannot
+//! be redeployed by accident, and each upgrade publishes an [`Upgraded`] event.
+//!
+//! The version is supplied by the caller alongside the new Wasm hash, since the running code
+//! cannot inspect the build it is being replaced with. It must match the `CONTRACT_VERSION`
+//! constant of the new build.
+//!
+//! ## Storage Migrations
+//!
+//! The layout of the contract's storage is versioned separately, as a schema version set by
+//! the constructor. A build that changes the layout bumps its `STORAGE_SCHEMA_VERSION` and
+//! adds a migration step from the previous schema, which its `migrate` entrypoint runs through
+//! [`migrate`]. Schedule the `upgrade` and `migrate` calls in the same timelock batch, so no
+//! other call observes old entries under the new code.
+
+use soroban_sdk::{BytesN, Env, contractevent, contracttype};
+
+use crate::VerifierError;
+
+/// Storage keys used by the upgrade helpers.
+#[contracttype]
+enum UpgradeKey {
+    /// Version of the currently deployed contract code.
+    ContractVersion,
+    /// Version of the storage layout.
+    SchemaVersion,
+}
+
+/// Event emitted when the contract code is upgraded.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Upgraded {
+    /// Contract version before the upgrade.
+    pub from: u32,
+    /// Contract version after the upgrade.
+    pub to: u32,
+}
+
+/// Event emitted when the storage is migrated to a new schema.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Migrated {
+    /// Schema version before the migration.
+    pub from: u32,
+    /// Schema version after the migration.
+    pub to: u32,
+}
+
+/// Returns the stored contract version, or `0` if none was set.
+pub fn contract_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&UpgradeKey::ContractVersion)
+        .unwrap_or(0)
+}
+
+/// Stores the contract version. Intended for constructors.
+pub fn set_contract_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&UpgradeKey::ContractVersion, &version);
+}
+
+/// Replaces the contract code with `new_wasm_hash`, recording `new_version`.
+///
+/// Authorization is the caller's responsibility.
+///
+/// # Errors
+///
+/// - [`VerifierError::VersionNotIncreasing`] - `new_version` is not greater than the stored
+///   contract version
+pub fn upgrade(
+    env: &Env,
+    new_wasm_hash: BytesN<32>,
+    new_version: u32,
+) -> Result<(), VerifierError> {
+    let from = contract_version(env);
+    if new_version <= from {
+        return Err(VerifierError::VersionNotIncreasing);
+    }
+
+    set_contract_version(env, new_version);
+    Upgraded {
+        from,
+        to: new_version,
+    }
+    .publish(env);
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
+    Ok(())
+}
+
+/// Returns the stored schema version, or `0` for deployments that predate schema versioning.
+pub fn schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&UpgradeKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+/// Stores the schema version. Intended for constructors.
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&UpgradeKey::SchemaVersion, &version);
+}
+
+/// Migrates the storage to schema `target`, calling `step(env, version)` to migrate from each
+/// `version` to `version + 1` in turn.
+///
+/// Storage already at `target` is left untouched, so migrating twice is harmless. Otherwise the
+/// new schema version is recorded and [`Migrated`] is published. Authorization is the caller's
+/// responsibility.
+///
+/// # Errors
+///
+/// - [`VerifierError::UnsupportedSchemaVersion`] - The stored schema is newer than `target`
+/// - Any error returned by `step`
+pub fn migrate(
+    env: &Env,
+    target: u32,
+    step: impl Fn(&Env, u32) -> Result<(), VerifierError>,
+) -> Result<(), VerifierError> {
+    let from = schema_version(env);
+    if from > target {
+        return Err(VerifierError::UnsupportedSchemaVersion);
+    }
+    if from == target {
+        return Ok(());
+    }
+
+    for version in from..target {
+        step(env, version)?;
+    }
+    set_schema_version(env, target);
+    Migrated { from, to: target }.publish(env);
+    Ok(())
+}
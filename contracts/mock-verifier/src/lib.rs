@@ -1,11 +1,15 @@
 #![no_std]
 
-use soroban_sdk::{Bytes, BytesN, Env, contract, contractimpl, contracttype};
+use soroban_sdk::{Bytes, BytesN, Env, Vec, contract, contractimpl, contracttype};
 
+#[cfg(any(test, feature = "testutils"))]
+use risc0_interface::Output;
 use risc0_interface::{Receipt, ReceiptClaim, RiscZeroVerifierInterface, VerifierError};
 
 #[cfg(test)]
 mod test;
+#[cfg(feature = "testutils")]
+pub mod testutils;
 
 const DAY_IN_LEDGERS: u32 = 17_280;
 const VERIFIER_EXTEND_AMOUNT: u32 = 90 * DAY_IN_LEDGERS;
@@ -61,6 +65,12 @@ impl RiscZeroMockVerifier {
         BytesN::try_from(&selector).map_err(|_| VerifierError::InvalidSelector)
     }
 
+    /// Returns the post-state digest of the claims built by [`mock_prove`](Self::mock_prove),
+    /// [`ReceiptClaim::POST_STATE_DIGEST_HALTED`].
+    pub fn post_state_digest(env: Env) -> BytesN<32> {
+        BytesN::from_array(&env, &ReceiptClaim::POST_STATE_DIGEST_HALTED)
+    }
+
     /// Build a mock receipt for the given image ID and journal digest.
     ///
     /// The seal format matches the Ethereum mock verifier: `selector || claim_digest`.
@@ -87,26 +97,46 @@ impl RiscZeroMockVerifier {
     }
 }
 
-#[contractimpl]
-impl RiscZeroVerifierInterface for RiscZeroMockVerifier {
-    type Proof = ();
+/// Preimages hashed into a claim digest, returned by
+/// [`echo_claim`](RiscZeroMockVerifier::echo_claim).
+#[cfg(any(test, feature = "testutils"))]
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimPreimage {
+    /// Preimage of the `Output` digest, see [`Output::digest`](risc0_interface::Output::digest).
+    pub output: Bytes,
+    /// Preimage of the claim digest, see
+    /// [`ReceiptClaim::digest`](risc0_interface::ReceiptClaim::digest).
+    pub claim: Bytes,
+    /// The claim digest.
+    pub claim_digest: BytesN<32>,
+}
 
-    /// Verify a mock seal by reconstructing the claim digest from inputs.
+/// Debug entrypoints, only built with the `testutils` feature.
+#[cfg(any(test, feature = "testutils"))]
+#[contractimpl]
+impl RiscZeroMockVerifier {
+    /// Returns the exact bytes hashed for the claim digest of a successful execution of
+    /// `image_id` with `journal_digest`, as computed by [`mock_prove`](Self::mock_prove) and
+    /// the default `verify`.
     ///
-    /// Returns a structured [`VerifierError`] on selector mismatch or invalid proof.
-    fn verify(
-        env: Env,
-        seal: Bytes,
-        image_id: BytesN<32>,
-        journal: BytesN<32>,
-    ) -> Result<(), VerifierError> {
-        let claim = ReceiptClaim::new(&env, image_id, journal);
-        let receipt = Receipt {
-            seal,
+    /// Lets SDK authors in other languages byte-compare their claim encoding with the chain's
+    /// on a local or test network. The `output` field of the claim preimage is the digest of
+    /// the output preimage.
+    pub fn echo_claim(env: Env, image_id: BytesN<32>, journal_digest: BytesN<32>) -> ClaimPreimage {
+        let output = Output::new(journal_digest.clone(), BytesN::from_array(&env, &[0u8; 32]));
+        let claim = ReceiptClaim::new(&env, image_id, journal_digest);
+        ClaimPreimage {
+            output: output.preimage(&env),
+            claim: claim.preimage(&env),
             claim_digest: claim.digest(&env),
-        };
-        Self::verify_integrity(env, receipt)
+        }
     }
+}
+
+#[contractimpl(contracttrait)]
+impl RiscZeroVerifierInterface for RiscZeroMockVerifier {
+    type Proof = ();
 
     fn verify_integrity(env: Env, receipt: risc0_interface::Receipt) -> Result<(), VerifierError> {
         if receipt.seal.len() < 4 {
@@ -132,4 +162,16 @@ impl RiscZeroVerifierInterface for RiscZeroMockVerifier {
 
         Ok(())
     }
+
+    /// Mock seals commit to a single claim digest, so only single-claim requests are accepted.
+    fn verify_many(env: Env, seal: Bytes, claims: Vec<BytesN<32>>) -> Result<(), VerifierError> {
+        if claims.len() != 1 {
+            return Err(VerifierError::UnsupportedAggregation);
+        }
+        let receipt = Receipt {
+            seal,
+            claim_digest: claims.get_unchecked(0),
+        };
+        Self::verify_integrity(env, receipt)
+    }
 }
@@ -3,7 +3,7 @@ extern crate std;
 use soroban_sdk::{Bytes, BytesN, Env};
 
 use crate::{RiscZeroMockVerifier, RiscZeroMockVerifierClient};
-use risc0_interface::{Receipt, ReceiptClaim, VerifierError};
+use risc0_interface::{ExitCode, Receipt, ReceiptClaim, SystemExitCode, VerifierError};
 
 fn bytes_from<const N: usize>(env: &Env, value: &BytesN<N>) -> Bytes {
     Bytes::from_array(env, &value.to_array())
@@ -43,6 +43,24 @@ fn test_verify_integrity_ok() {
     assert_eq!(client.verify_integrity(&receipt), ());
 }
 
+#[test]
+fn test_default_verify_derives_claim() {
+    let (env, client, _selector) = setup();
+
+    let image_id = BytesN::from_array(&env, &[0x01; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x02; 32]);
+    let receipt = client.mock_prove(&image_id, &journal_digest);
+    assert_eq!(client.verify(&receipt.seal, &image_id, &journal_digest), ());
+
+    let other_journal = BytesN::from_array(&env, &[0x03; 32]);
+    let result = client.try_verify(&receipt.seal, &image_id, &other_journal);
+    assert_eq!(result, Err(Ok(VerifierError::InvalidProof)));
+
+    let zero_image_id = BytesN::from_array(&env, &[0u8; 32]);
+    let result = client.try_verify(&receipt.seal, &zero_image_id, &journal_digest);
+    assert_eq!(result, Err(Ok(VerifierError::ZeroImageId)));
+}
+
 #[test]
 fn test_verify_integrity_invalid_selector() {
     let (env, client, selector) = setup();
@@ -77,3 +95,90 @@ fn test_verify_integrity_invalid_proof() {
         panic!("expected InvalidProof");
     };
 }
+
+#[test]
+fn test_verify_integrity_claim_from_parts() {
+    let (env, client, _selector) = setup();
+
+    let image_id = BytesN::from_array(&env, &[0x01; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x02; 32]);
+    let standard = ReceiptClaim::new(&env, image_id.clone(), journal_digest.clone());
+
+    // A claim built from the standard parts must hash to the same digest.
+    // Output digest: SHA-256(SHA-256("risc0.Output") || journal || assumptions || 2u16).
+    let mut output = Bytes::new(&env);
+    output.append(
+        &env.crypto()
+            .sha256(&Bytes::from_slice(&env, b"risc0.Output"))
+            .into(),
+    );
+    output.append(&bytes_from(&env, &journal_digest));
+    output.append(&Bytes::from_array(&env, &[0u8; 32]));
+    output.append(&Bytes::from_array(&env, &[0x02, 0x00]));
+    let output_digest: BytesN<32> = env.crypto().sha256(&output).into();
+    let exit_code = ExitCode::new(SystemExitCode::Halted, 0);
+    let claim = ReceiptClaim::from_parts(
+        image_id.clone(),
+        BytesN::from_array(&env, &ReceiptClaim::POST_STATE_DIGEST_HALTED),
+        exit_code,
+        BytesN::from_array(&env, &[0u8; 32]),
+        output_digest.clone(),
+    );
+    assert_eq!(claim.digest(&env), standard.digest(&env));
+
+    // Custom parts are committed as given.
+    let paused = ReceiptClaim::from_parts(
+        image_id,
+        BytesN::from_array(&env, &[0x03; 32]),
+        ExitCode::new(SystemExitCode::Paused, 0),
+        BytesN::from_array(&env, &[0u8; 32]),
+        output_digest,
+    );
+    assert_ne!(paused.digest(&env), standard.digest(&env));
+
+    let receipt = client.mock_prove_claim(&paused.digest(&env));
+    assert_eq!(client.verify_integrity(&receipt), ());
+}
+
+#[test]
+fn test_receipt_conversions_round_trip() {
+    let (env, client, _selector) = setup();
+
+    let image_id = BytesN::from_array(&env, &[0x01; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x02; 32]);
+    let claim = ReceiptClaim::new(&env, image_id, journal_digest);
+
+    let claim_digest: BytesN<32> = (&claim).into();
+    assert_eq!(claim_digest, claim.digest(&env));
+
+    let claim_bytes = Bytes::from(&claim);
+    assert_eq!(ReceiptClaim::try_from(&claim_bytes), Ok(claim.clone()));
+
+    let receipt = client.mock_prove_claim(&claim.into());
+    let receipt_bytes = Bytes::from(&receipt);
+    let decoded = Receipt::try_from(&receipt_bytes).unwrap();
+    assert_eq!(decoded, receipt);
+    assert_eq!(client.verify_integrity(&decoded), ());
+}
+
+#[test]
+fn test_echo_claim_returns_hashed_preimages() {
+    let (env, client, _selector) = setup();
+    let image_id = BytesN::from_array(&env, &[0x01; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x02; 32]);
+
+    let echo = client.echo_claim(&image_id, &journal_digest);
+
+    let claim = ReceiptClaim::new(&env, image_id.clone(), journal_digest.clone());
+    assert_eq!(echo.claim_digest, claim.digest(&env));
+    assert_eq!(
+        env.crypto().sha256(&echo.claim).to_bytes(),
+        echo.claim_digest
+    );
+    let output_digest = env.crypto().sha256(&echo.output).to_bytes();
+    assert_eq!(echo.claim.slice(128..160), bytes_from(&env, &output_digest));
+    assert_eq!(
+        echo.claim_digest,
+        client.mock_prove(&image_id, &journal_digest).claim_digest
+    );
+}
@@ -0,0 +1,56 @@
+//! Fixtures for testing contracts that verify receipts through the mock verifier.
+//!
+//! Contracts under test are configured with the address of a [`MockProver`] as their verifier,
+//! and receive the seals it proves for [`IMAGE_ID`]. The mock accepts any receipt it proved,
+//! so tests can drive the verification paths of a contract without a real proof.
+
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+use crate::{RiscZeroMockVerifier, RiscZeroMockVerifierClient};
+
+/// Selector the mock verifier is registered with.
+pub const SELECTOR: [u8; 4] = [0xFF, 0x00, 0x00, 0x01];
+
+/// Image id of the guest program the fixtures prove receipts of.
+pub const IMAGE_ID: [u8; 32] = [0xA1; 32];
+
+/// A mock verifier registered in a test environment.
+pub struct MockProver {
+    env: Env,
+    /// Client of the registered verifier.
+    pub client: RiscZeroMockVerifierClient<'static>,
+}
+
+impl MockProver {
+    /// Registers a mock verifier with [`SELECTOR`].
+    pub fn register(env: &Env) -> Self {
+        let selector = BytesN::from_array(env, &SELECTOR);
+        let verifier_id = env.register(RiscZeroMockVerifier, (selector,));
+        Self {
+            env: env.clone(),
+            client: RiscZeroMockVerifierClient::new(env, &verifier_id),
+        }
+    }
+
+    /// Returns the address of the verifier.
+    pub fn address(&self) -> &Address {
+        &self.client.address
+    }
+
+    /// Returns [`IMAGE_ID`] as bytes.
+    pub fn image_id(&self) -> BytesN<32> {
+        BytesN::from_array(&self.env, &IMAGE_ID)
+    }
+
+    /// Returns a seal proving `journal_digest` for [`IMAGE_ID`].
+    pub fn prove(&self, journal_digest: &BytesN<32>) -> Bytes {
+        self.client
+            .mock_prove(&self.image_id(), journal_digest)
+            .seal
+    }
+
+    /// Returns a seal proving the SHA-256 digest of `journal` for [`IMAGE_ID`].
+    pub fn prove_journal(&self, journal: &Bytes) -> Bytes {
+        self.prove(&self.env.crypto().sha256(journal).into())
+    }
+}
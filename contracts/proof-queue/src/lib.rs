@@ -0,0 +1,214 @@
+#![no_std]
+
+use risc0_interface::{RiscZeroVerifierClient, VerifierError};
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, InvokeError, TryFromVal, Val, contract, contractevent,
+    contractimpl, contracttype,
+};
+
+#[cfg(test)]
+mod test;
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+/// Lifetime of a queued receipt: receipts not processed within it expire.
+const RECEIPT_TTL: u32 = DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    /// Address of the verifier (or router) queued receipts are verified with.
+    Verifier,
+    /// Id of the oldest receipt not yet processed.
+    Head,
+    /// Id of the next receipt to be enqueued.
+    Tail,
+    /// Receipt waiting to be processed (temporary).
+    Receipt(u64),
+}
+
+/// Receipt waiting in the queue.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedReceipt {
+    /// Account that enqueued the receipt.
+    pub relayer: Address,
+    /// The encoded proof.
+    pub seal: Bytes,
+    /// Image id of the guest program.
+    pub image_id: BytesN<32>,
+    /// SHA-256 digest of the journal.
+    pub journal: BytesN<32>,
+}
+
+/// Event emitted when a receipt is enqueued.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiptQueued {
+    /// Queue id of the receipt.
+    #[topic]
+    pub id: u64,
+    /// Account that enqueued the receipt.
+    pub relayer: Address,
+}
+
+/// Event emitted when a queued receipt verifies.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiptVerified {
+    /// Queue id of the receipt.
+    #[topic]
+    pub id: u64,
+    /// Image id of the guest program.
+    pub image_id: BytesN<32>,
+    /// SHA-256 digest of the journal.
+    pub journal: BytesN<32>,
+}
+
+/// Event emitted when a queued receipt fails verification.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiptRejected {
+    /// Queue id of the receipt.
+    #[topic]
+    pub id: u64,
+    /// Image id of the guest program.
+    pub image_id: BytesN<32>,
+    /// SHA-256 digest of the journal.
+    pub journal: BytesN<32>,
+    /// Contract error code the verifier failed with, `None` if it aborted without one.
+    pub error: Option<u32>,
+}
+
+/// Event emitted when a queued receipt expired before being processed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiptExpired {
+    /// Queue id of the receipt.
+    #[topic]
+    pub id: u64,
+}
+
+/// Queue decoupling the submission of receipts from their verification.
+///
+/// Relayers [`enqueue`](Self::enqueue) receipts, which only stores them; anyone then calls
+/// [`process`](Self::process) to verify the oldest ones, as many per transaction as its budget
+/// allows. Outcomes are published as events: [`ReceiptVerified`], [`ReceiptRejected`], or
+/// [`ReceiptExpired`] for receipts that were not processed within a day of being enqueued.
+///
+/// Queued receipts live in temporary storage, so the queue never accumulates state. It has no
+/// admin and is not upgradeable.
+#[contract]
+pub struct ProofQueue;
+
+#[contractimpl]
+impl ProofQueue {
+    /// Initializes the queue with the verifier (or router) receipts are verified with.
+    pub fn __constructor(env: Env, verifier: Address) {
+        env.storage().instance().set(&DataKey::Verifier, &verifier);
+        env.storage().instance().set(&DataKey::Head, &0u64);
+        env.storage().instance().set(&DataKey::Tail, &0u64);
+    }
+
+    /// Returns the verifier address queued receipts are verified with.
+    pub fn verifier(env: Env) -> Address {
+        read_instance(&env, &DataKey::Verifier)
+    }
+
+    /// Enqueues a receipt and returns its queue id. Requires the relayer's authorization.
+    pub fn enqueue(
+        env: Env,
+        relayer: Address,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> u64 {
+        relayer.require_auth();
+
+        let id: u64 = read_instance(&env, &DataKey::Tail);
+        let key = DataKey::Receipt(id);
+        let receipt = QueuedReceipt {
+            relayer: relayer.clone(),
+            seal,
+            image_id,
+            journal,
+        };
+        env.storage().temporary().set(&key, &receipt);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, RECEIPT_TTL, RECEIPT_TTL);
+        env.storage().instance().set(&DataKey::Tail, &(id + 1));
+
+        ReceiptQueued { id, relayer }.publish(&env);
+        id
+    }
+
+    /// Verifies up to `n` of the oldest queued receipts and returns how many were processed,
+    /// including expired ones.
+    ///
+    /// Each receipt is verified in a sub-invocation whose failure is caught, so a rejected
+    /// receipt does not fail the call. Running out of budget does, so `n` must be sized to
+    /// the cost of the receipts' proof systems.
+    pub fn process(env: Env, n: u32) -> u32 {
+        let verifier = RiscZeroVerifierClient::new(&env, &Self::verifier(env.clone()));
+        let head: u64 = read_instance(&env, &DataKey::Head);
+        let tail: u64 = read_instance(&env, &DataKey::Tail);
+        let end = tail.min(head + u64::from(n));
+
+        for id in head..end {
+            let key = DataKey::Receipt(id);
+            let Some(receipt) = env.storage().temporary().get::<_, QueuedReceipt>(&key) else {
+                ReceiptExpired { id }.publish(&env);
+                continue;
+            };
+            env.storage().temporary().remove(&key);
+
+            match verifier.try_verify(&receipt.seal, &receipt.image_id, &receipt.journal) {
+                Ok(_) => ReceiptVerified {
+                    id,
+                    image_id: receipt.image_id,
+                    journal: receipt.journal,
+                }
+                .publish(&env),
+                Err(error) => ReceiptRejected {
+                    id,
+                    image_id: receipt.image_id,
+                    journal: receipt.journal,
+                    error: error_code(error),
+                }
+                .publish(&env),
+            }
+        }
+        env.storage().instance().set(&DataKey::Head, &end);
+
+        // `end - head <= n`
+        (end - head) as u32
+    }
+
+    /// Returns the number of receipts waiting to be processed, including expired ones.
+    pub fn pending(env: Env) -> u64 {
+        let head: u64 = read_instance(&env, &DataKey::Head);
+        let tail: u64 = read_instance(&env, &DataKey::Tail);
+        tail - head
+    }
+
+    /// Returns the queued receipt with id `id`, if it is still waiting and has not expired.
+    pub fn receipt(env: Env, id: u64) -> Option<QueuedReceipt> {
+        env.storage().temporary().get(&DataKey::Receipt(id))
+    }
+}
+
+fn read_instance<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> V {
+    env.storage()
+        .instance()
+        .get(key)
+        .expect("contract is initialized in the constructor")
+}
+
+/// Returns the contract error code of a failed verification.
+fn error_code(error: Result<VerifierError, InvokeError>) -> Option<u32> {
+    match error {
+        Ok(error) => Some(error as u32),
+        Err(InvokeError::Contract(code)) => Some(code),
+        Err(InvokeError::Abort) => None,
+    }
+}
@@ -0,0 +1,166 @@
+extern crate std;
+
+use mock_verifier::testutils::MockProver;
+use risc0_interface::VerifierError;
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, Event,
+    testutils::{Address as _, Events as _, Ledger as _},
+};
+
+use crate::{
+    DAY_IN_LEDGERS, ProofQueue, ProofQueueClient, QueuedReceipt, ReceiptExpired, ReceiptQueued,
+    ReceiptRejected, ReceiptVerified,
+};
+
+struct Setup {
+    env: Env,
+    queue: ProofQueueClient<'static>,
+    verifier: MockProver,
+    relayer: Address,
+}
+
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let verifier = MockProver::register(&env);
+    let queue_id = env.register(ProofQueue, (verifier.address().clone(),));
+    let queue = ProofQueueClient::new(&env, &queue_id);
+
+    Setup {
+        relayer: Address::generate(&env),
+        env,
+        queue,
+        verifier,
+    }
+}
+
+/// Returns a receipt for the test image and `journal`, with a seal for `proven` instead if
+/// given.
+fn receipt(s: &Setup, journal: u8, proven: Option<u8>) -> (Bytes, BytesN<32>, BytesN<32>) {
+    let journal_digest = BytesN::from_array(&s.env, &[journal; 32]);
+    let proven = BytesN::from_array(&s.env, &[proven.unwrap_or(journal); 32]);
+    (
+        s.verifier.prove(&proven),
+        s.verifier.image_id(),
+        journal_digest,
+    )
+}
+
+fn enqueue(s: &Setup, journal: u8, proven: Option<u8>) -> u64 {
+    let (seal, image_id, journal) = receipt(s, journal, proven);
+    s.queue.enqueue(&s.relayer, &seal, &image_id, &journal)
+}
+
+#[test]
+fn enqueue_stores_receipt() {
+    let s = setup();
+    let (seal, image_id, journal) = receipt(&s, 1, None);
+
+    assert_eq!(s.queue.enqueue(&s.relayer, &seal, &image_id, &journal), 0);
+    assert_eq!(
+        s.env.events().all(),
+        [ReceiptQueued {
+            id: 0,
+            relayer: s.relayer.clone(),
+        }
+        .to_xdr(&s.env, &s.queue.address)]
+    );
+    assert_eq!(enqueue(&s, 2, None), 1);
+
+    assert_eq!(s.queue.pending(), 2);
+    assert_eq!(
+        s.queue.receipt(&0),
+        Some(QueuedReceipt {
+            relayer: s.relayer.clone(),
+            seal,
+            image_id,
+            journal,
+        })
+    );
+}
+
+#[test]
+fn enqueue_requires_relayer_auth() {
+    let s = setup();
+    enqueue(&s, 1, None);
+
+    assert_eq!(s.env.auths()[0].0, s.relayer);
+}
+
+#[test]
+fn process_publishes_outcomes_in_order() {
+    let s = setup();
+    enqueue(&s, 1, None);
+    enqueue(&s, 2, Some(3));
+
+    assert_eq!(s.queue.process(&10), 2);
+
+    let image_id = s.verifier.image_id();
+    assert_eq!(
+        s.env.events().all().filter_by_contract(&s.queue.address),
+        [
+            ReceiptVerified {
+                id: 0,
+                image_id: image_id.clone(),
+                journal: BytesN::from_array(&s.env, &[1; 32]),
+            }
+            .to_xdr(&s.env, &s.queue.address),
+            ReceiptRejected {
+                id: 1,
+                image_id,
+                journal: BytesN::from_array(&s.env, &[2; 32]),
+                error: Some(VerifierError::InvalidProof as u32),
+            }
+            .to_xdr(&s.env, &s.queue.address),
+        ]
+    );
+    assert_eq!(s.queue.pending(), 0);
+    assert_eq!(s.queue.receipt(&0), None);
+    assert_eq!(s.queue.receipt(&1), None);
+}
+
+#[test]
+fn process_stops_after_n_receipts() {
+    let s = setup();
+    for journal in 1..=3 {
+        enqueue(&s, journal, None);
+    }
+
+    assert_eq!(s.queue.process(&2), 2);
+    assert_eq!(s.queue.pending(), 1);
+    assert!(s.queue.receipt(&2).is_some());
+
+    assert_eq!(s.queue.process(&2), 1);
+    assert_eq!(s.queue.process(&2), 0);
+    assert_eq!(s.queue.pending(), 0);
+}
+
+#[test]
+fn process_skips_expired_receipts() {
+    let s = setup();
+    enqueue(&s, 1, None);
+    s.env
+        .ledger()
+        .with_mut(|ledger| ledger.sequence_number += DAY_IN_LEDGERS + 1);
+    let id = enqueue(&s, 2, None);
+
+    assert_eq!(s.queue.receipt(&0), None);
+    assert_eq!(s.queue.pending(), 2);
+    assert_eq!(s.queue.process(&2), 2);
+
+    let events = s.env.events().all().filter_by_contract(&s.queue.address);
+    assert_eq!(
+        events.events()[0],
+        ReceiptExpired { id: 0 }.to_xdr(&s.env, &s.queue.address)
+    );
+    assert_eq!(
+        events.events()[1],
+        ReceiptVerified {
+            id,
+            image_id: s.verifier.image_id(),
+            journal: BytesN::from_array(&s.env, &[2; 32]),
+        }
+        .to_xdr(&s.env, &s.queue.address)
+    );
+}
@@ -1,15 +1,34 @@
 #![no_std]
 
+use risc0_digest::ZERO_DIGEST;
 use risc0_interface::{
-    Receipt, RiscZeroVerifierClient, RiscZeroVerifierRouterInterface, VerifierEntry, VerifierError,
+    Assumption, Assumptions, CircuitBreaker, ConsumerConfig, CostEstimate, ExitCode, Output,
+    RateLimit, Receipt, ReceiptClaim, ReceiptEnvelope, RiscZeroImageRegistryClient,
+    RiscZeroVerifierClient, RiscZeroVerifierInfoClient, RiscZeroVerifierRouterInterface,
+    SystemExitCode, VerifierEntry, VerifierError, ensure_nonzero_claim_digest,
+    ensure_nonzero_image_id, extract_selector,
+    roles::{GUARDIAN_ROLE, OPERATOR_ROLE},
+    upgrade,
 };
-use soroban_sdk::{Address, Bytes, BytesN, Env, contract, contractimpl, contracttype};
-use stellar_access::ownable::{Ownable, set_owner};
-use stellar_macros::only_owner;
+use soroban_sdk::{
+    Address, Bytes, BytesN, ConversionError, Env, InvokeError, String, Symbol, Vec, contract,
+    contractevent, contractimpl, contracttype, panic_with_error,
+};
+use stellar_access::access_control::{AccessControl, grant_role_no_auth, set_admin};
+use stellar_macros::{only_admin, only_role};
+
+#[cfg(any(test, feature = "testutils"))]
+use risc0_interface::debug::VerificationFailure;
 
 #[cfg(test)]
 mod test;
 
+/// Version of this build, recorded on deployment and required to increase on every upgrade.
+const CONTRACT_VERSION: u32 = 1;
+
+/// Version of the storage layout of this build, see [`risc0_interface::upgrade`].
+const STORAGE_SCHEMA_VERSION: u32 = 1;
+
 const DAY_IN_LEDGERS: u32 = 17_280;
 const VERIFIER_EXTEND_AMOUNT: u32 = 90 * DAY_IN_LEDGERS;
 const VERIFIER_TTL_THRESHOLD: u32 = VERIFIER_EXTEND_AMOUNT - DAY_IN_LEDGERS;
@@ -19,10 +38,233 @@ const VERIFIER_TTL_THRESHOLD: u32 = VERIFIER_EXTEND_AMOUNT - DAY_IN_LEDGERS;
 enum DataKey {
     /// Selector-specific verifier entry.
     Verifier(BytesN<4>),
+    /// Marker for a revoked claim digest.
+    RevokedClaim(BytesN<32>),
+    /// Marker for a revoked image id.
+    RevokedImageId(BytesN<32>),
+    /// Configuration registered by a consumer contract.
+    ConsumerConfig(Address),
+    /// Selector-specific verification cost estimate.
+    CostEstimate(BytesN<4>),
+    /// Every selector ever assigned, in registration order.
+    Selectors,
+    /// Per-caller rate limit of `verify_as`, absent when disabled.
+    RateLimit,
+    /// Start ledger and count of a caller's current rate limit window.
+    RateLimitUsage(Address),
+    /// Image registry annotating verifications, absent when disabled.
+    ImageRegistry,
+    /// Circuit breaker pausing failing selectors, absent when disabled.
+    CircuitBreaker,
+    /// Start ledger, verification count and failure count of a selector's current circuit
+    /// breaker window.
+    BreakerWindow(BytesN<4>),
+    /// Marker for a selector paused by the circuit breaker.
+    Paused(BytesN<4>),
+    /// Marker for a routing table that can no longer change.
+    RegistryFinalized,
+    /// Number of distinct verifiers that must accept claims of an image id, absent below 2.
+    Quorum(BytesN<32>),
+    /// Ledger sequence from which a selector is refused, absent when it has no sunset.
+    SelectorSunset(BytesN<4>),
+}
+
+/// Event emitted when a verifier is registered for a selector.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifierAdded {
+    /// Selector the verifier is registered under.
+    #[topic]
+    pub selector: BytesN<4>,
+    /// Address of the registered verifier.
+    pub verifier: Address,
+}
+
+/// Event emitted when a verifier is removed, tombstoning its selector.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifierRemoved {
+    /// Selector that was removed.
+    #[topic]
+    pub selector: BytesN<4>,
+    /// Address of the verifier that was registered for the selector, if it was active.
+    pub verifier: Option<Address>,
+}
+
+/// Event emitted when the routing table is finalized.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistryFinalized {
+    /// Every selector ever assigned, in registration order, as frozen.
+    pub selectors: Vec<BytesN<4>>,
+}
+
+/// Event emitted when a claim digest is revoked.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimRevoked {
+    /// The revoked claim digest.
+    #[topic]
+    pub claim_digest: BytesN<32>,
+}
+
+/// Event emitted when an image id is revoked.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageIdRevoked {
+    /// The revoked image id.
+    #[topic]
+    pub image_id: BytesN<32>,
+}
+
+/// Event emitted when a consumer sets its configuration.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsumerConfigured {
+    /// Consumer whose configuration changed.
+    #[topic]
+    pub consumer: Address,
+    /// The new configuration.
+    pub config: ConsumerConfig,
+}
+
+/// Event emitted when the cost estimate of a selector is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CostEstimateSet {
+    /// Selector the estimate applies to.
+    #[topic]
+    pub selector: BytesN<4>,
+    /// The new estimate.
+    pub estimate: CostEstimate,
+}
+
+/// Event emitted when the rate limit is set or disabled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitSet {
+    /// The new rate limit, or `None` if rate limiting was disabled.
+    pub limit: Option<RateLimit>,
+}
+
+/// Event emitted when the image registry is set or disabled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageRegistrySet {
+    /// The new image registry, or `None` if annotations were disabled.
+    pub registry: Option<Address>,
+}
+
+/// Event emitted when the circuit breaker is set or disabled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreakerSet {
+    /// The new circuit breaker, or `None` if it was disabled.
+    pub breaker: Option<CircuitBreaker>,
+}
+
+/// Event emitted when the quorum of an image id is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuorumSet {
+    /// Image id the quorum applies to.
+    #[topic]
+    pub image_id: BytesN<32>,
+    /// The new quorum, 1 if it was disabled.
+    pub quorum: u32,
+}
+
+/// Event emitted when the sunset ledger of a selector is set or cleared.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SelectorSunsetSet {
+    /// Selector the sunset applies to.
+    #[topic]
+    pub selector: BytesN<4>,
+    /// Ledger sequence from which the selector is refused, or `None` if the sunset was cleared.
+    pub sunset_ledger: Option<u32>,
+}
+
+/// Event emitted when the circuit breaker pauses a selector.
+///
+/// Monitoring should alert on it: verifications for the selector fail with
+/// [`VerifierError::SelectorPaused`] until a guardian resumes it. Published as a
+/// [`PAUSE_ALERT`](risc0_interface::alerts::PAUSE_ALERT), see [`risc0_interface::alerts`].
+#[contractevent(topics = ["risc0_alert", "pause"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SelectorTripped {
+    /// The paused selector.
+    #[topic]
+    pub selector: BytesN<4>,
+    /// Failed verifications in the window that tripped the breaker.
+    pub failures: u32,
+    /// Verifications in the window that tripped the breaker.
+    pub verifications: u32,
+}
+
+/// Event emitted when a guardian resumes a paused selector.
+///
+/// Published as a [`RESUME_ALERT`](risc0_interface::alerts::RESUME_ALERT).
+#[contractevent(topics = ["risc0_alert", "resume"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SelectorResumed {
+    /// The resumed selector.
+    #[topic]
+    pub selector: BytesN<4>,
+}
+
+/// Event emitted when a receipt of an image id verifies, while an image registry is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageVerified {
+    /// The verified image id.
+    #[topic]
+    pub image_id: BytesN<32>,
+    /// Name registered for the image id, or `None` if it is not registered.
+    pub name: Option<String>,
+}
+
+/// Event emitted when a call to a verifier fails, carrying the verifier's original error.
+///
+/// The router still fails, so the event is only visible in the diagnostic events of the failed
+/// invocation (e.g. when simulating). `code` is the contract error code returned by the
+/// verifier, or `None` if it trapped without one.
+///
+/// Published as a [`VERIFICATION_FAILED_ALERT`](risc0_interface::alerts::VERIFICATION_FAILED_ALERT).
+/// Besides simulations, it is visible on-chain for the failures counted by
+/// [`report_verification`](RiscZeroVerifierRouter::report_verification).
+#[contractevent(topics = ["risc0_alert", "verify_failed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifierCallFailed {
+    /// The verifier that failed.
+    #[topic]
+    pub verifier: Address,
+    /// The verifier's contract error code, if any.
+    pub code: Option<u32>,
 }
 
-#[contract]
 /// Routes verification requests to selector-specific verifier contracts.
+///
+/// Every change to the routing table and to revocations publishes an event
+/// ([`VerifierAdded`], [`VerifierRemoved`], [`RegistryFinalized`], [`ClaimRevoked`],
+/// [`ImageIdRevoked`]), so indexers can reconstruct its history. Consumer configuration changes
+/// publish [`ConsumerConfigured`], cost estimate changes [`CostEstimateSet`], rate limit changes
+/// [`RateLimitSet`], image registry changes [`ImageRegistrySet`], circuit breaker changes
+/// [`CircuitBreakerSet`], quorum changes [`QuorumSet`], and selector sunsets
+/// [`SelectorSunsetSet`].
+///
+/// Image ids can require a quorum of verifiers, see [`verify_quorum`](Self::verify_quorum).
+///
+/// When a [`CircuitBreaker`] is set, the router pauses selectors whose verifiers fail too often,
+/// publishing [`SelectorTripped`]; see [`report_verification`](Self::report_verification).
+///
+/// When an image registry is set, every successful verification of an image id publishes
+/// [`ImageVerified`] with the program name registered there, so explorers can label proofs.
+/// Annotations are best effort: a failing registry call leaves the name empty and never fails
+/// the verification.
+/// Role and admin changes are published by `access_control`, and upgrades by
+/// [`risc0_interface::upgrade`].
+#[contract]
 pub struct RiscZeroVerifierRouter;
 
 #[contractimpl]
@@ -39,19 +281,152 @@ impl RiscZeroVerifierRouter {
         })
     }
 
-    /// Initializes the router with the admin that can manage verifiers.
-    pub fn __constructor(env: Env, owner: Address) {
-        set_owner(&env, &owner);
+    /// Initializes the router with its admin, the initial operator and guardian, and the
+    /// initial verifiers.
+    ///
+    /// The admin manages role membership, the operator registers verifiers, and the guardian
+    /// removes them. See [`risc0_interface::roles`].
+    ///
+    /// `verifiers` maps selectors to verifier addresses and is registered atomically with the
+    /// deployment, so there is no window where only some selectors are routable. Panics with
+    /// [`VerifierError::SelectorInUse`] if a selector appears twice.
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        operator: Address,
+        guardian: Address,
+        verifiers: Vec<(BytesN<4>, Address)>,
+    ) {
+        set_admin(&env, &admin);
+        grant_role_no_auth(&env, &operator, &OPERATOR_ROLE, &admin);
+        grant_role_no_auth(&env, &guardian, &GUARDIAN_ROLE, &admin);
+        upgrade::set_contract_version(&env, CONTRACT_VERSION);
+        upgrade::set_schema_version(&env, STORAGE_SCHEMA_VERSION);
+
+        for (selector, verifier) in verifiers.iter() {
+            if let Err(error) = Self::register_verifier(&env, selector, verifier) {
+                panic_with_error!(&env, error);
+            }
+        }
+    }
+
+    /// Returns the version of the deployed contract code.
+    pub fn contract_version(env: Env) -> u32 {
+        upgrade::contract_version(&env)
+    }
+
+    /// Upgrades the contract code to `new_wasm_hash`. Only the admin can call this.
+    ///
+    /// `new_version` must be the `CONTRACT_VERSION` of the new build and greater than the
+    /// current version; see [`risc0_interface::upgrade`].
+    #[only_admin]
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+        new_version: u32,
+    ) -> Result<(), VerifierError> {
+        upgrade::upgrade(&env, new_wasm_hash, new_version)
+    }
+
+    /// Returns the version of the storage layout.
+    pub fn schema_version(env: Env) -> u32 {
+        upgrade::schema_version(&env)
+    }
+
+    /// Migrates storage written by an earlier build to the layout of this build. Only the admin
+    /// can call this.
+    ///
+    /// A no-op if the storage is already up to date; see [`risc0_interface::upgrade`].
+    #[only_admin]
+    pub fn migrate(env: Env) -> Result<(), VerifierError> {
+        upgrade::migrate(&env, STORAGE_SCHEMA_VERSION, Self::migrate_from)
     }
 
-    /// Adds a verifier for the selector.
-    #[only_owner]
+    /// Migrates storage from schema `version` to `version + 1`.
+    fn migrate_from(_env: &Env, version: u32) -> Result<(), VerifierError> {
+        match version {
+            // Schema 1 only records the version; unversioned deployments share its layout.
+            0 => Ok(()),
+            _ => Err(VerifierError::UnsupportedSchemaVersion),
+        }
+    }
+
+    /// Adds a verifier for the selector. Only an operator can call this.
+    ///
+    /// Fails with [`VerifierError::RegistryFinalized`] once the routing table is finalized.
+    #[only_role(operator, "operator")]
     pub fn add_verifier(
         env: Env,
         selector: BytesN<4>,
         verifier: Address,
+        operator: Address,
     ) -> Result<(), VerifierError> {
-        let key = DataKey::Verifier(selector);
+        Self::ensure_registry_not_finalized(&env)?;
+        Self::register_verifier(&env, selector, verifier)
+    }
+
+    /// Permanently freezes the routing table. Only the admin can call this.
+    ///
+    /// Afterwards [`add_verifier`](Self::add_verifier),
+    /// [`remove_verifier`](Self::remove_verifier), [`import_registry`](Self::import_registry),
+    /// [`set_selector_sunset`](Self::set_selector_sunset) and [`set_quorum`](Self::set_quorum)
+    /// fail with [`VerifierError::RegistryFinalized`], so every selector keeps its current entry
+    /// and sunset, and every image id its quorum, for the lifetime of the router. Deployments finalize once their initial governance
+    /// phase is over, to give integrators an immutable routing table.
+    ///
+    /// Revocations, circuit breaker pauses and the emergency stops in front of the verifiers
+    /// keep working, and remain the way to halt a broken verifier. Finalization does not
+    /// restrict [`upgrade`](Self::upgrade): a fully immutable stack also needs an admin that
+    /// can no longer upgrade, e.g. a timelock without proposers.
+    ///
+    /// Fails with [`VerifierError::RegistryFinalized`] if already finalized.
+    #[only_admin]
+    pub fn finalize_registry(env: Env) -> Result<(), VerifierError> {
+        Self::ensure_registry_not_finalized(&env)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RegistryFinalized, &true);
+        RegistryFinalized {
+            selectors: Self::read_selectors(&env),
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Returns whether the routing table was finalized.
+    pub fn is_registry_finalized(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::RegistryFinalized)
+    }
+
+    /// Fails with [`VerifierError::RegistryFinalized`] if the routing table was finalized.
+    fn ensure_registry_not_finalized(env: &Env) -> Result<(), VerifierError> {
+        if env.storage().instance().has(&DataKey::RegistryFinalized) {
+            return Err(VerifierError::RegistryFinalized);
+        }
+        Ok(())
+    }
+
+    /// Stores an active verifier entry for a selector that was never assigned.
+    fn register_verifier(
+        env: &Env,
+        selector: BytesN<4>,
+        verifier: Address,
+    ) -> Result<(), VerifierError> {
+        Self::assign_selector(env, &selector, &VerifierEntry::Active(verifier.clone()))?;
+        VerifierAdded { selector, verifier }.publish(env);
+
+        Ok(())
+    }
+
+    /// Stores the entry for a selector that was never assigned and indexes the selector.
+    fn assign_selector(
+        env: &Env,
+        selector: &BytesN<4>,
+        entry: &VerifierEntry,
+    ) -> Result<(), VerifierError> {
+        let key = DataKey::Verifier(selector.clone());
         let verifier_address: Option<VerifierEntry> = env.storage().persistent().get(&key);
 
         if let Some(entry) = verifier_address {
@@ -61,30 +436,779 @@ impl RiscZeroVerifierRouter {
             }
         }
 
+        env.storage().persistent().set(&key, entry);
+
+        let mut selectors = Self::read_selectors(env);
+        selectors.push_back(selector.clone());
         env.storage()
             .persistent()
-            .set(&key, &VerifierEntry::Active(verifier));
+            .set(&DataKey::Selectors, &selectors);
+        env.storage().persistent().extend_ttl(
+            &DataKey::Selectors,
+            VERIFIER_TTL_THRESHOLD,
+            VERIFIER_EXTEND_AMOUNT,
+        );
 
         Ok(())
     }
 
-    /// Removes a verifier for the selector, marking it as permanently removed.
-    #[only_owner]
-    pub fn remove_verifier(env: Env, selector: BytesN<4>) -> Result<(), VerifierError> {
-        let key = DataKey::Verifier(selector);
-        let verifier_address: Option<VerifierEntry> = env.storage().persistent().get(&key);
+    /// Reads the index of assigned selectors.
+    fn read_selectors(env: &Env) -> Vec<BytesN<4>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Selectors)
+            .unwrap_or_else(|| Vec::new(env))
+    }
 
-        if verifier_address.is_none() {
-            return Err(VerifierError::SelectorUnknown);
+    /// Returns the complete routing table: every selector ever assigned with its entry, in
+    /// registration order.
+    ///
+    /// Removed selectors are included as [`VerifierEntry::Tombstone`], so that
+    /// [`import_registry`](Self::import_registry) on a replacement router keeps them
+    /// unassignable. Revocations and consumer configurations are not part of the export.
+    pub fn export_registry(env: Env) -> Vec<(BytesN<4>, VerifierEntry)> {
+        let mut entries = Vec::new(&env);
+        for selector in Self::read_selectors(&env).iter() {
+            let key = DataKey::Verifier(selector.clone());
+            if let Some(entry) = Self::read_verifier_entry(&env, &key) {
+                entries.push_back((selector, entry));
+            }
+        }
+        entries
+    }
+
+    /// Seeds the routing table from the [`export_registry`](Self::export_registry) of another
+    /// router, for disaster recovery. Only the admin can call this.
+    ///
+    /// The admin is expected to be a timelock, so an import is scheduled and publicly visible
+    /// for the timelock delay before it takes effect. Active entries publish [`VerifierAdded`]
+    /// and tombstones publish [`VerifierRemoved`]. Fails with [`VerifierError::SelectorInUse`]
+    /// or [`VerifierError::SelectorRemoved`] if a selector is already assigned, in which case
+    /// nothing is imported, and with [`VerifierError::RegistryFinalized`] once the routing
+    /// table is finalized.
+    #[only_admin]
+    pub fn import_registry(
+        env: Env,
+        entries: Vec<(BytesN<4>, VerifierEntry)>,
+    ) -> Result<(), VerifierError> {
+        Self::ensure_registry_not_finalized(&env)?;
+        for (selector, entry) in entries.iter() {
+            match entry {
+                VerifierEntry::Active(verifier) => {
+                    Self::register_verifier(&env, selector, verifier)?;
+                }
+                VerifierEntry::Tombstone => {
+                    Self::assign_selector(&env, &selector, &VerifierEntry::Tombstone)?;
+                    VerifierRemoved {
+                        selector,
+                        verifier: None,
+                    }
+                    .publish(&env);
+                }
+            }
         }
+        Ok(())
+    }
+
+    /// Removes a verifier for the selector, marking it as permanently removed. Only a guardian
+    /// can call this.
+    ///
+    /// Fails with [`VerifierError::RegistryFinalized`] once the routing table is finalized.
+    #[only_role(guardian, "guardian")]
+    pub fn remove_verifier(
+        env: Env,
+        selector: BytesN<4>,
+        guardian: Address,
+    ) -> Result<(), VerifierError> {
+        Self::ensure_registry_not_finalized(&env)?;
+        let key = DataKey::Verifier(selector.clone());
+        let verifier = match env.storage().persistent().get(&key) {
+            None => return Err(VerifierError::SelectorUnknown),
+            Some(VerifierEntry::Active(verifier)) => Some(verifier),
+            Some(VerifierEntry::Tombstone) => None,
+        };
 
         env.storage()
             .persistent()
             .set(&key, &VerifierEntry::Tombstone);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CostEstimate(selector.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::SelectorSunset(selector.clone()));
+        VerifierRemoved { selector, verifier }.publish(&env);
 
         Ok(())
     }
 
+    /// Sets the cost estimate of the verifier registered for the selector, replacing any
+    /// previous one. Only an operator can call this.
+    ///
+    /// Fails with [`VerifierError::SelectorUnknown`] or [`VerifierError::SelectorRemoved`] if
+    /// no active verifier is registered for the selector. Removing the verifier also drops its
+    /// estimate.
+    #[only_role(operator, "operator")]
+    pub fn set_cost_estimate(
+        env: Env,
+        selector: BytesN<4>,
+        estimate: CostEstimate,
+        operator: Address,
+    ) -> Result<(), VerifierError> {
+        Self::get_verifier(&env, &selector)?;
+
+        let key = DataKey::CostEstimate(selector.clone());
+        env.storage().persistent().set(&key, &estimate);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, VERIFIER_TTL_THRESHOLD, VERIFIER_EXTEND_AMOUNT);
+        CostEstimateSet { selector, estimate }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Schedules the retirement of the verifier registered for the selector, or cancels it with
+    /// `None`. Only the admin can call this.
+    ///
+    /// From `sunset_ledger` on, receipts with the selector are refused with
+    /// [`VerifierError::SelectorExpired`], without a [`remove_verifier`](Self::remove_verifier)
+    /// transaction at that exact ledger. Governance sets it when a RISC Zero release is
+    /// deprecated, giving integrators until the sunset to move to the next release's selector.
+    /// Unlike a removal, the selector keeps its entry: the sunset can be moved or cancelled, also
+    /// after it passed.
+    ///
+    /// Fails with [`VerifierError::SelectorUnknown`] or [`VerifierError::SelectorRemoved`] if
+    /// no active verifier is registered for the selector. Removing the verifier also drops its
+    /// sunset.
+    ///
+    /// Fails with [`VerifierError::RegistryFinalized`] once the routing table is finalized:
+    /// sunsets scheduled before then still apply, but can no longer be moved or cancelled.
+    #[only_admin]
+    pub fn set_selector_sunset(
+        env: Env,
+        selector: BytesN<4>,
+        sunset_ledger: Option<u32>,
+    ) -> Result<(), VerifierError> {
+        Self::ensure_registry_not_finalized(&env)?;
+        Self::get_verifier(&env, &selector)?;
+
+        let key = DataKey::SelectorSunset(selector.clone());
+        match sunset_ledger {
+            Some(sunset_ledger) => {
+                env.storage().persistent().set(&key, &sunset_ledger);
+                env.storage().persistent().extend_ttl(
+                    &key,
+                    VERIFIER_TTL_THRESHOLD,
+                    VERIFIER_EXTEND_AMOUNT,
+                );
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+        SelectorSunsetSet {
+            selector,
+            sunset_ledger,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Returns the ledger sequence from which the selector is refused, if it has a sunset.
+    pub fn selector_sunset(env: Env, selector: BytesN<4>) -> Option<u32> {
+        let key = DataKey::SelectorSunset(selector);
+        env.storage().persistent().get(&key).inspect(|_| {
+            env.storage().persistent().extend_ttl(
+                &key,
+                VERIFIER_TTL_THRESHOLD,
+                VERIFIER_EXTEND_AMOUNT,
+            );
+        })
+    }
+
+    /// Fails with [`VerifierError::SelectorExpired`] if the selector reached its sunset ledger.
+    fn ensure_selector_not_expired(env: &Env, selector: &BytesN<4>) -> Result<(), VerifierError> {
+        let sunset_ledger = Self::selector_sunset(env.clone(), selector.clone());
+        if sunset_ledger.is_some_and(|sunset| env.ledger().sequence() >= sunset) {
+            return Err(VerifierError::SelectorExpired);
+        }
+        Ok(())
+    }
+
+    /// Sets the per-caller rate limit of `verify_as`, or disables it with `None`. Only an
+    /// operator can call this.
+    ///
+    /// Rate limiting is disabled by default. Changing the limit keeps the callers' current
+    /// windows, which are then measured against the new limit.
+    #[only_role(operator, "operator")]
+    pub fn set_rate_limit(env: Env, limit: Option<RateLimit>, operator: Address) {
+        match &limit {
+            Some(limit) => env.storage().instance().set(&DataKey::RateLimit, limit),
+            None => env.storage().instance().remove(&DataKey::RateLimit),
+        }
+        RateLimitSet { limit }.publish(&env);
+    }
+
+    /// Sets the image registry annotating verifications, or disables annotations with `None`.
+    /// Only the admin can call this.
+    ///
+    /// Annotations are disabled by default, as each one costs a call to the registry.
+    #[only_admin]
+    pub fn set_image_registry(env: Env, registry: Option<Address>) {
+        match &registry {
+            Some(registry) => env
+                .storage()
+                .instance()
+                .set(&DataKey::ImageRegistry, registry),
+            None => env.storage().instance().remove(&DataKey::ImageRegistry),
+        }
+        ImageRegistrySet { registry }.publish(&env);
+    }
+
+    /// Returns the image registry annotating verifications, if any.
+    pub fn image_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::ImageRegistry)
+    }
+
+    /// Sets the circuit breaker, or disables it with `None`. Only an operator can call this.
+    ///
+    /// The breaker is disabled by default, as it costs every verification a write to temporary
+    /// storage. Changing it keeps the selectors' current windows, which are then measured
+    /// against the new breaker; disabling it keeps paused selectors paused.
+    #[only_role(operator, "operator")]
+    pub fn set_circuit_breaker(env: Env, breaker: Option<CircuitBreaker>, operator: Address) {
+        match &breaker {
+            Some(breaker) => env
+                .storage()
+                .instance()
+                .set(&DataKey::CircuitBreaker, breaker),
+            None => env.storage().instance().remove(&DataKey::CircuitBreaker),
+        }
+        CircuitBreakerSet { breaker }.publish(&env);
+    }
+
+    /// Returns the circuit breaker, if enabled.
+    pub fn circuit_breaker(env: Env) -> Option<CircuitBreaker> {
+        env.storage().instance().get(&DataKey::CircuitBreaker)
+    }
+
+    /// Returns whether the circuit breaker paused the selector.
+    pub fn is_selector_paused(env: Env, selector: BytesN<4>) -> bool {
+        Self::has_marker(&env, &DataKey::Paused(selector))
+    }
+
+    /// Resumes a selector paused by the circuit breaker, with a fresh window. Only a guardian
+    /// can call this.
+    ///
+    /// Fails with [`VerifierError::SelectorUnknown`] or [`VerifierError::SelectorRemoved`] if
+    /// no active verifier is registered for the selector.
+    #[only_role(guardian, "guardian")]
+    pub fn resume_selector(
+        env: Env,
+        selector: BytesN<4>,
+        guardian: Address,
+    ) -> Result<(), VerifierError> {
+        Self::get_verifier(&env, &selector)?;
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Paused(selector.clone()));
+        env.storage()
+            .temporary()
+            .remove(&DataKey::BreakerWindow(selector.clone()));
+        SelectorResumed { selector }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Verifies a receipt from its components like `verify`, recording the outcome with the
+    /// circuit breaker instead of failing.
+    ///
+    /// A failed verification rolls back, so it cannot count itself against its selector's
+    /// window. Monitors that observe a failure when simulating `verify` submit the receipt
+    /// here instead: the router verifies it again and counts the verifier's outcome, which may
+    /// trip the breaker. Only an operator can call this, as any malformed seal carrying a
+    /// registered selector counts as a failure: open reporting would let anyone pause a
+    /// selector until a guardian resumes it.
+    ///
+    /// Returns the verifier's error code, or `None` if the receipt verified.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the checks `verify` runs before dispatching, e.g.
+    /// [`VerifierError::ZeroImageId`], [`VerifierError::ClaimRevoked`],
+    /// [`VerifierError::SelectorUnknown`] or [`VerifierError::SelectorPaused`]. These, and a
+    /// verifier failing to report its post-state digest, are not counted.
+    #[only_role(operator, "operator")]
+    pub fn report_verification(
+        env: Env,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+        operator: Address,
+    ) -> Result<Option<u32>, VerifierError> {
+        Self::ensure_routable_image_id(&env, &image_id)?;
+        let (selector, verifier) = Self::dispatch_target(&env, None, &seal)?;
+        Self::ensure_execution_not_revoked(&env, &verifier, &image_id, &journal)?;
+
+        let client = RiscZeroVerifierClient::new(&env, &verifier);
+        let result = client.try_verify(&seal, &image_id, &journal);
+        let error = Self::forward_verifier_result(&env, &verifier, result).err();
+        Self::record_outcome(&env, &selector, error.is_some());
+        Ok(error.map(|error| error as u32))
+    }
+
+    /// Sets the number of distinct verifiers that must accept claims of an image id, see
+    /// [`verify_quorum`](Self::verify_quorum). Only the admin can call this.
+    ///
+    /// A quorum of 0 or 1 disables it, which is the default. While a quorum of 2 or more is
+    /// set, the entrypoints verifying a single seal for the image id (e.g. `verify`,
+    /// `verify_with` or `verify_with_exit_code`) fail with [`VerifierError::QuorumRequired`].
+    /// Like [`revoke_image_id`](Self::revoke_image_id), the quorum cannot be enforced on
+    /// entrypoints that only receive a claim digest, i.e. `verify_integrity`, `verify_many` and
+    /// `verify_envelope`.
+    ///
+    /// Fails with [`VerifierError::RegistryFinalized`] once the routing table is finalized:
+    /// quorums set before then still apply, but can no longer be changed.
+    #[only_admin]
+    pub fn set_quorum(env: Env, image_id: BytesN<32>, quorum: u32) -> Result<(), VerifierError> {
+        Self::ensure_registry_not_finalized(&env)?;
+
+        let quorum = quorum.max(1);
+        let key = DataKey::Quorum(image_id.clone());
+        if quorum == 1 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &quorum);
+            env.storage().persistent().extend_ttl(
+                &key,
+                VERIFIER_TTL_THRESHOLD,
+                VERIFIER_EXTEND_AMOUNT,
+            );
+        }
+        QuorumSet { image_id, quorum }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Returns the number of distinct verifiers that must accept claims of an image id, 1 if
+    /// it has no quorum.
+    pub fn quorum(env: Env, image_id: BytesN<32>) -> u32 {
+        let key = DataKey::Quorum(image_id);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .inspect(|_| {
+                env.storage().persistent().extend_ttl(
+                    &key,
+                    VERIFIER_TTL_THRESHOLD,
+                    VERIFIER_EXTEND_AMOUNT,
+                );
+            })
+            .unwrap_or(1)
+    }
+
+    /// Verifies a receipt from its components with several seals, requiring the image id's
+    /// quorum of distinct verifiers to accept it.
+    ///
+    /// Each seal is dispatched to the verifier of its selector like `verify`, and every seal
+    /// must verify. Seals dispatched to the same verifier count once. Requiring proofs from
+    /// verifiers of different proof systems (e.g. Groth16 and fflonk) is a defense in depth:
+    /// forging a claim then takes a soundness bug in each of them.
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifierError::QuorumNotMet`] - Fewer distinct verifiers than the quorum accepted
+    ///   the claim, checked against the number of seals before any is verified
+    /// - Any error `verify` fails with for one of the seals
+    pub fn verify_quorum(
+        env: Env,
+        seals: Vec<Bytes>,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> Result<(), VerifierError> {
+        Self::ensure_routable_image_id(&env, &image_id)?;
+        let quorum = Self::quorum(env.clone(), image_id.clone());
+        if seals.len() < quorum {
+            return Err(VerifierError::QuorumNotMet);
+        }
+
+        let mut verifiers: Vec<Address> = Vec::new(&env);
+        for seal in seals.iter() {
+            let (selector, verifier) = Self::dispatch_target(&env, None, &seal)?;
+            Self::ensure_execution_not_revoked(&env, &verifier, &image_id, &journal)?;
+            let client = RiscZeroVerifierClient::new(&env, &verifier);
+            let result = client.try_verify(&seal, &image_id, &journal);
+            Self::forward_verifier_result(&env, &verifier, result)?;
+            Self::record_outcome(&env, &selector, false);
+            if !verifiers.contains(&verifier) {
+                verifiers.push_back(verifier);
+            }
+        }
+        if verifiers.len() < quorum {
+            return Err(VerifierError::QuorumNotMet);
+        }
+        Self::annotate_verification(&env, image_id);
+        Ok(())
+    }
+
+    /// Revokes a claim digest. Only a guardian can call this.
+    ///
+    /// Subsequent verifications of the claim fail with [`VerifierError::ClaimRevoked`], even
+    /// if the seal is cryptographically valid. Revocation is permanent.
+    #[only_role(guardian, "guardian")]
+    pub fn revoke_claim(env: Env, claim_digest: BytesN<32>, guardian: Address) {
+        Self::set_marker(&env, &DataKey::RevokedClaim(claim_digest.clone()));
+        ClaimRevoked { claim_digest }.publish(&env);
+    }
+
+    /// Revokes every claim produced for an image id. Only a guardian can call this.
+    ///
+    /// Only entrypoints that receive the image id can enforce this revocation, i.e. `verify`
+    /// and `verify_conditional`. Receipts submitted to `verify_integrity` carry just the claim
+    /// digest; revoke those individually with [`revoke_claim`](Self::revoke_claim).
+    #[only_role(guardian, "guardian")]
+    pub fn revoke_image_id(env: Env, image_id: BytesN<32>, guardian: Address) {
+        Self::set_marker(&env, &DataKey::RevokedImageId(image_id.clone()));
+        ImageIdRevoked { image_id }.publish(&env);
+    }
+
+    /// Returns whether the claim digest was revoked.
+    pub fn is_claim_revoked(env: Env, claim_digest: BytesN<32>) -> bool {
+        Self::has_marker(&env, &DataKey::RevokedClaim(claim_digest))
+    }
+
+    /// Returns whether the image id was revoked.
+    pub fn is_image_id_revoked(env: Env, image_id: BytesN<32>) -> bool {
+        Self::has_marker(&env, &DataKey::RevokedImageId(image_id))
+    }
+
+    /// Computes the digest of an [`Output`] from its journal and assumptions digests.
+    ///
+    /// This is the `output` part of a [`ReceiptClaim`] built with
+    /// [`ReceiptClaim::from_parts`], see [`Output::digest`].
+    pub fn compute_output_digest(
+        env: Env,
+        journal_digest: BytesN<32>,
+        assumptions_digest: BytesN<32>,
+    ) -> BytesN<32> {
+        Output::new(journal_digest, assumptions_digest).digest(&env)
+    }
+
+    /// Stores a revocation or pause marker.
+    fn set_marker(env: &Env, key: &DataKey) {
+        env.storage().persistent().set(key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, VERIFIER_TTL_THRESHOLD, VERIFIER_EXTEND_AMOUNT);
+    }
+
+    /// Reads a revocation or pause marker and refreshes its TTL when present.
+    fn has_marker(env: &Env, key: &DataKey) -> bool {
+        let present = env.storage().persistent().has(key);
+        if present {
+            env.storage().persistent().extend_ttl(
+                key,
+                VERIFIER_TTL_THRESHOLD,
+                VERIFIER_EXTEND_AMOUNT,
+            );
+        }
+        present
+    }
+
+    /// Fails with [`VerifierError::ClaimRevoked`] if the image id was revoked.
+    fn ensure_image_id_not_revoked(env: &Env, image_id: &BytesN<32>) -> Result<(), VerifierError> {
+        if Self::has_marker(env, &DataKey::RevokedImageId(image_id.clone())) {
+            return Err(VerifierError::ClaimRevoked);
+        }
+        Ok(())
+    }
+
+    /// Fails with [`VerifierError::QuorumRequired`] if the image id has a quorum, which a single
+    /// seal cannot meet.
+    fn ensure_quorum_not_required(env: &Env, image_id: &BytesN<32>) -> Result<(), VerifierError> {
+        if Self::quorum(env.clone(), image_id.clone()) > 1 {
+            return Err(VerifierError::QuorumRequired);
+        }
+        Ok(())
+    }
+
+    /// Fails with [`VerifierError::ClaimRevoked`] if the claim digest was revoked.
+    fn ensure_claim_not_revoked(env: &Env, claim_digest: &BytesN<32>) -> Result<(), VerifierError> {
+        if Self::has_marker(env, &DataKey::RevokedClaim(claim_digest.clone())) {
+            return Err(VerifierError::ClaimRevoked);
+        }
+        Ok(())
+    }
+
+    /// Counts a verification against the caller's rate limit window, if rate limiting is
+    /// enabled.
+    ///
+    /// Windows are kept in temporary storage and expire with their window, so the limiter
+    /// does not accumulate state for past callers.
+    fn consume_rate_limit(env: &Env, caller: &Address) -> Result<(), VerifierError> {
+        let Some(limit) = env
+            .storage()
+            .instance()
+            .get::<_, RateLimit>(&DataKey::RateLimit)
+        else {
+            return Ok(());
+        };
+
+        let key = DataKey::RateLimitUsage(caller.clone());
+        let ledger = env.ledger().sequence();
+        let (window_start, count) = match env.storage().temporary().get::<_, (u32, u32)>(&key) {
+            Some((start, count)) if ledger < start.saturating_add(limit.window_ledgers) => {
+                (start, count)
+            }
+            _ => (ledger, 0),
+        };
+        if count >= limit.max_verifications {
+            return Err(VerifierError::RateLimitExceeded);
+        }
+
+        env.storage()
+            .temporary()
+            .set(&key, &(window_start, count + 1));
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, limit.window_ledgers, limit.window_ledgers);
+        Ok(())
+    }
+
+    /// Publishes [`ImageVerified`] for a verified image id if an image registry is set.
+    fn annotate_verification(env: &Env, image_id: BytesN<32>) {
+        let Some(registry) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::ImageRegistry)
+        else {
+            return;
+        };
+
+        let name = match RiscZeroImageRegistryClient::new(env, &registry).try_image(&image_id) {
+            Ok(Ok(metadata)) => metadata.map(|metadata| metadata.name),
+            _ => None,
+        };
+        ImageVerified { image_id, name }.publish(env);
+    }
+
+    /// Reads a consumer configuration and refreshes its TTL when present.
+    fn read_consumer_config(env: &Env, consumer: &Address) -> Option<ConsumerConfig> {
+        let key = DataKey::ConsumerConfig(consumer.clone());
+        env.storage().persistent().get(&key).inspect(|_| {
+            env.storage().persistent().extend_ttl(
+                &key,
+                VERIFIER_TTL_THRESHOLD,
+                VERIFIER_EXTEND_AMOUNT,
+            );
+        })
+    }
+
+    /// Maps the result of a call to a verifier into the router's errors.
+    ///
+    /// A [`VerifierError`] returned by the verifier is forwarded unchanged, as every verifier
+    /// shares that namespace. Any other failure, i.e. an error code outside [`VerifierError`] or
+    /// a trap, becomes [`VerifierError::VerifierUnavailable`]. Every failure publishes
+    /// [`VerifierCallFailed`] with the verifier's original code.
+    fn forward_verifier_result(
+        env: &Env,
+        verifier: &Address,
+        result: Result<Result<(), ConversionError>, Result<VerifierError, InvokeError>>,
+    ) -> Result<(), VerifierError> {
+        let (code, error) = match result {
+            Ok(Ok(())) => return Ok(()),
+            Err(Ok(error)) => (Some(error as u32), error),
+            Err(Err(InvokeError::Contract(code))) => {
+                (Some(code), VerifierError::VerifierUnavailable)
+            }
+            Err(Err(InvokeError::Abort)) | Ok(Err(_)) => (None, VerifierError::VerifierUnavailable),
+        };
+
+        VerifierCallFailed {
+            verifier: verifier.clone(),
+            code,
+        }
+        .publish(env);
+        Err(error)
+    }
+
+    /// Dispatches a receipt given by its components to `verifier`, which must be registered,
+    /// or to the verifier of its selector if `None`.
+    fn route_verify(
+        env: &Env,
+        verifier: Option<Address>,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> Result<(), VerifierError> {
+        Self::ensure_routable_image_id(env, &image_id)?;
+        Self::ensure_quorum_not_required(env, &image_id)?;
+        let (selector, verifier) = Self::dispatch_target(env, verifier, &seal)?;
+        Self::ensure_execution_not_revoked(env, &verifier, &image_id, &journal)?;
+
+        let client = RiscZeroVerifierClient::new(env, &verifier);
+        let result = client.try_verify(&seal, &image_id, &journal);
+        Self::forward_verifier_result(env, &verifier, result)?;
+        Self::record_outcome(env, &selector, false);
+        Self::annotate_verification(env, image_id);
+        Ok(())
+    }
+
+    /// Dispatches a receipt to the verifier of its selector.
+    ///
+    /// Like the verifiers, this accepts the zero claim digest, so an emergency stop in front of
+    /// the router can check proofs of exploit through it.
+    fn route_verify_integrity(env: &Env, receipt: &Receipt) -> Result<(), VerifierError> {
+        Self::ensure_claim_not_revoked(env, &receipt.claim_digest)?;
+
+        let (selector, verifier) = Self::dispatch_target(env, None, &receipt.seal)?;
+        let client = RiscZeroVerifierClient::new(env, &verifier);
+        let result = client.try_verify_integrity(receipt);
+        Self::forward_verifier_result(env, &verifier, result)?;
+        Self::record_outcome(env, &selector, false);
+        Ok(())
+    }
+
+    /// Fails unless executions of `image_id` may be dispatched: the image id must be nonzero
+    /// and not revoked.
+    fn ensure_routable_image_id(env: &Env, image_id: &BytesN<32>) -> Result<(), VerifierError> {
+        ensure_nonzero_image_id(image_id)?;
+        Self::ensure_image_id_not_revoked(env, image_id)
+    }
+
+    /// Fails with [`VerifierError::ClaimRevoked`] if the claim `verifier` proves for a
+    /// successful execution of `image_id` committing `journal` was revoked.
+    fn ensure_execution_not_revoked(
+        env: &Env,
+        verifier: &Address,
+        image_id: &BytesN<32>,
+        journal: &BytesN<32>,
+    ) -> Result<(), VerifierError> {
+        let post_state_digest = Self::verifier_post_state_digest(env, verifier)?;
+        let claim = ReceiptClaim::with_post_state_digest(
+            env,
+            image_id.clone(),
+            journal.clone(),
+            post_state_digest,
+            0,
+        );
+        Self::ensure_claim_not_revoked(env, &claim.digest(env))
+    }
+
+    /// Returns the post-state digest `verifier` builds its claims with.
+    ///
+    /// Verifiers of different zkVM releases may report different digests, so claims built from
+    /// an image id and journal are only those the verifier proves with its own.
+    ///
+    /// Fails with [`VerifierError::VerifierUnavailable`] if the verifier does not report one.
+    fn verifier_post_state_digest(
+        env: &Env,
+        verifier: &Address,
+    ) -> Result<BytesN<32>, VerifierError> {
+        match RiscZeroVerifierInfoClient::new(env, verifier).try_post_state_digest() {
+            Ok(Ok(digest)) => Ok(digest),
+            _ => Err(VerifierError::VerifierUnavailable),
+        }
+    }
+
+    /// Returns the post-state digest of the verifier of `seal`'s selector.
+    fn seal_post_state_digest(env: &Env, seal: &Bytes) -> Result<BytesN<32>, VerifierError> {
+        let verifier = Self::get_verifier(env, &extract_selector(seal)?)?;
+        Self::verifier_post_state_digest(env, &verifier)
+    }
+
+    /// Returns the selector and verifier to dispatch `seal` to: `verifier` with its selector,
+    /// or the verifier of the seal's selector if `None`.
+    ///
+    /// Fails with [`VerifierError::SelectorExpired`] past the selector's sunset, and with
+    /// [`VerifierError::SelectorPaused`] if the circuit breaker paused the selector.
+    fn dispatch_target(
+        env: &Env,
+        verifier: Option<Address>,
+        seal: &Bytes,
+    ) -> Result<(BytesN<4>, Address), VerifierError> {
+        let (selector, verifier) = match verifier {
+            Some(verifier) => (Self::registered_selector(env, &verifier)?, verifier),
+            None => {
+                let selector = extract_selector(seal)?;
+                let verifier = Self::get_verifier(env, &selector)?;
+                (selector, verifier)
+            }
+        };
+        Self::ensure_selector_not_expired(env, &selector)?;
+        if Self::has_marker(env, &DataKey::Paused(selector.clone())) {
+            return Err(VerifierError::SelectorPaused);
+        }
+        Ok((selector, verifier))
+    }
+
+    /// Returns the selector `verifier` is the active verifier of.
+    ///
+    /// Fails with [`VerifierError::VerifierNotRegistered`] if there is none.
+    fn registered_selector(env: &Env, verifier: &Address) -> Result<BytesN<4>, VerifierError> {
+        Self::read_selectors(env)
+            .iter()
+            .find(|selector| {
+                matches!(
+                    Self::read_verifier_entry(env, &DataKey::Verifier(selector.clone())),
+                    Some(VerifierEntry::Active(active)) if active == *verifier
+                )
+            })
+            .ok_or(VerifierError::VerifierNotRegistered)
+    }
+
+    /// Counts a verification dispatched to the selector against its circuit breaker window,
+    /// if the breaker is enabled, and pauses the selector if a failure trips it.
+    ///
+    /// Windows are kept in temporary storage and expire with their window, like the rate
+    /// limit's.
+    fn record_outcome(env: &Env, selector: &BytesN<4>, failed: bool) {
+        let Some(breaker) = env
+            .storage()
+            .instance()
+            .get::<_, CircuitBreaker>(&DataKey::CircuitBreaker)
+        else {
+            return;
+        };
+
+        let key = DataKey::BreakerWindow(selector.clone());
+        let ledger = env.ledger().sequence();
+        let (window_start, verifications, failures) =
+            match env.storage().temporary().get::<_, (u32, u32, u32)>(&key) {
+                Some((start, verifications, failures))
+                    if ledger < start.saturating_add(breaker.window_ledgers) =>
+                {
+                    (start, verifications, failures)
+                }
+                _ => (ledger, 0, 0),
+            };
+        let verifications = verifications.saturating_add(1);
+        let failures = failures.saturating_add(u32::from(failed));
+
+        let tripped = failed
+            && verifications >= breaker.min_verifications
+            && u64::from(failures) * 10_000
+                > u64::from(breaker.max_failure_bps) * u64::from(verifications);
+        if tripped {
+            Self::set_marker(env, &DataKey::Paused(selector.clone()));
+            env.storage().temporary().remove(&key);
+            SelectorTripped {
+                selector: selector.clone(),
+                failures,
+                verifications,
+            }
+            .publish(env);
+            return;
+        }
+
+        env.storage()
+            .temporary()
+            .set(&key, &(window_start, verifications, failures));
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, breaker.window_ledgers, breaker.window_ledgers);
+    }
+
     /// Returns the verifier for a selector.
     fn get_verifier(env: &Env, selector: &BytesN<4>) -> Result<Address, VerifierError> {
         let key = DataKey::Verifier(selector.clone());
@@ -114,10 +1238,33 @@ impl RiscZeroVerifierRouterInterface for RiscZeroVerifierRouter {
 
     /// Returns the verifier for the selector stored in the seal prefix.
     fn get_verifier_from_seal(env: Env, seal: Bytes) -> Result<Address, VerifierError> {
-        let selector = selector_from_seal(&seal)?;
+        let selector = extract_selector(&seal)?;
         Self::get_verifier(&env, &selector)
     }
 
+    /// Checks the verifier for a selector with a dry run of its `selector` function.
+    fn healthcheck(env: Env, selector: BytesN<4>) -> Result<(), VerifierError> {
+        let verifier = Self::get_verifier(&env, &selector)?;
+        Self::ensure_selector_not_expired(&env, &selector)?;
+        if Self::has_marker(&env, &DataKey::Paused(selector.clone())) {
+            return Err(VerifierError::SelectorPaused);
+        }
+        let reported = match RiscZeroVerifierInfoClient::new(&env, &verifier).try_selector() {
+            Ok(Ok(reported)) => reported,
+            _ => return Err(VerifierError::VerifierUnavailable),
+        };
+
+        if reported != selector {
+            return Err(VerifierError::InvalidSelector);
+        }
+        Ok(())
+    }
+
+    /// Returns the selector stored in the seal prefix.
+    fn extract_selector(_env: Env, seal: Bytes) -> Result<BytesN<4>, VerifierError> {
+        extract_selector(&seal)
+    }
+
     /// Verifies a receipt from its components.
     fn verify(
         env: Env,
@@ -125,30 +1272,277 @@ impl RiscZeroVerifierRouterInterface for RiscZeroVerifierRouter {
         image_id: BytesN<32>,
         journal: BytesN<32>,
     ) -> Result<(), VerifierError> {
-        let selector = selector_from_seal(&seal)?;
-        let verifier = Self::get_verifier(&env, &selector)?;
-        let verifier = RiscZeroVerifierClient::new(&env, &verifier);
-        verifier.verify(&seal, &image_id, &journal);
+        #[cfg(any(test, feature = "testutils"))]
+        let failure_context =
+            Receipt::from_execution(&env, seal.clone(), image_id.clone(), journal.clone());
+        let result = Self::route_verify(&env, None, seal, image_id, journal);
+        #[cfg(any(test, feature = "testutils"))]
+        risc0_interface::debug::record_result(
+            &env,
+            &failure_context.seal,
+            &failure_context.claim_digest,
+            result,
+        );
+        result
+    }
+
+    /// Verifies a receipt from its components with a registered verifier.
+    fn verify_with(
+        env: Env,
+        verifier: Address,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> Result<(), VerifierError> {
+        #[cfg(any(test, feature = "testutils"))]
+        let failure_context =
+            Receipt::from_execution(&env, seal.clone(), image_id.clone(), journal.clone());
+        let result = Self::route_verify(&env, Some(verifier), seal, image_id, journal);
+        #[cfg(any(test, feature = "testutils"))]
+        risc0_interface::debug::record_result(
+            &env,
+            &failure_context.seal,
+            &failure_context.claim_digest,
+            result,
+        );
+        result
+    }
+
+    /// Verifies a receipt from its components, returning its claim digest.
+    fn verify_returning_digest(
+        env: Env,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> Result<BytesN<32>, VerifierError> {
+        ensure_nonzero_image_id(&image_id)?;
+        Self::ensure_image_id_not_revoked(&env, &image_id)?;
+        Self::ensure_quorum_not_required(&env, &image_id)?;
+        let post_state_digest = Self::seal_post_state_digest(&env, &seal)?;
+        let claim = ReceiptClaim::with_post_state_digest(
+            &env,
+            image_id.clone(),
+            journal,
+            post_state_digest,
+            0,
+        );
+        let claim_digest = claim.digest(&env);
+
+        let receipt = Receipt {
+            seal,
+            claim_digest: claim_digest.clone(),
+        };
+        Self::verify_integrity(env.clone(), receipt)?;
+        Self::annotate_verification(&env, image_id);
+        Ok(claim_digest)
+    }
+
+    /// Verifies a receipt from its components for a guest that halted with a user exit code.
+    fn verify_with_exit_code(
+        env: Env,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+        user_exit_code: u32,
+    ) -> Result<(), VerifierError> {
+        ensure_nonzero_image_id(&image_id)?;
+        Self::ensure_image_id_not_revoked(&env, &image_id)?;
+        Self::ensure_quorum_not_required(&env, &image_id)?;
+        let post_state_digest = Self::seal_post_state_digest(&env, &seal)?;
+        let claim = ReceiptClaim::with_post_state_digest(
+            &env,
+            image_id.clone(),
+            journal,
+            post_state_digest,
+            user_exit_code,
+        );
+
+        let receipt = Receipt {
+            seal,
+            claim_digest: claim.digest(&env),
+        };
+        Self::verify_integrity(env.clone(), receipt)?;
+        Self::annotate_verification(&env, image_id);
         Ok(())
     }
 
     /// Verifies receipt integrity using the selector's verifier.
     fn verify_integrity(env: Env, receipt: Receipt) -> Result<(), VerifierError> {
-        let selector = selector_from_seal(&receipt.seal)?;
-        let verifier = Self::get_verifier(&env, &selector)?;
-        let verifier = RiscZeroVerifierClient::new(&env, &verifier);
-        verifier.verify_integrity(&receipt);
+        let result = Self::route_verify_integrity(&env, &receipt);
+        #[cfg(any(test, feature = "testutils"))]
+        risc0_interface::debug::record_result(&env, &receipt.seal, &receipt.claim_digest, result);
+        result
+    }
+
+    /// Verifies the receipt of an envelope using the selector's verifier.
+    fn verify_envelope(env: Env, envelope: ReceiptEnvelope) -> Result<(), VerifierError> {
+        let receipt = envelope.receipt()?;
+        ensure_nonzero_claim_digest(&receipt.claim_digest)?;
+        Self::verify_integrity(env, receipt)
+    }
+
+    /// Checks the claim digest against the image id and journal, then verifies the receipt.
+    fn verify_integrity_with_claim(
+        env: Env,
+        receipt: Receipt,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> Result<(), VerifierError> {
+        ensure_nonzero_image_id(&image_id)?;
+        Self::ensure_image_id_not_revoked(&env, &image_id)?;
+        Self::ensure_quorum_not_required(&env, &image_id)?;
+
+        let post_state_digest = Self::seal_post_state_digest(&env, &receipt.seal)?;
+        let claim = ReceiptClaim::with_post_state_digest(
+            &env,
+            image_id.clone(),
+            journal,
+            post_state_digest,
+            0,
+        );
+        if claim.digest(&env) != receipt.claim_digest {
+            return Err(VerifierError::ClaimDigestMismatch);
+        }
+        Self::verify_integrity(env.clone(), receipt)?;
+        Self::annotate_verification(&env, image_id);
         Ok(())
     }
+
+    /// Verifies that the seal proves every claim using the selector's verifier.
+    fn verify_many(env: Env, seal: Bytes, claims: Vec<BytesN<32>>) -> Result<(), VerifierError> {
+        for claim_digest in claims.iter() {
+            ensure_nonzero_claim_digest(&claim_digest)?;
+            Self::ensure_claim_not_revoked(&env, &claim_digest)?;
+        }
+
+        let (selector, verifier) = Self::dispatch_target(&env, None, &seal)?;
+        let client = RiscZeroVerifierClient::new(&env, &verifier);
+        let result = client.try_verify_many(&seal, &claims);
+        Self::forward_verifier_result(&env, &verifier, result)?;
+        Self::record_outcome(&env, &selector, false);
+        Ok(())
+    }
+
+    /// Verifies a conditional receipt and the receipts discharging its assumptions.
+    fn verify_conditional(
+        env: Env,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+        assumption_receipts: Vec<Receipt>,
+    ) -> Result<(), VerifierError> {
+        ensure_nonzero_image_id(&image_id)?;
+        Self::ensure_image_id_not_revoked(&env, &image_id)?;
+        Self::ensure_quorum_not_required(&env, &image_id)?;
+
+        let zero = BytesN::from_array(&env, &ZERO_DIGEST);
+        let mut assumptions = Vec::new(&env);
+        for receipt in assumption_receipts.iter() {
+            assumptions.push_back(Assumption::new(receipt.claim_digest, zero.clone()));
+        }
+        let assumptions = Assumptions::from_assumptions(&env, &assumptions);
+        let output = Output::new(journal, assumptions.digest().clone());
+
+        let claim = ReceiptClaim::from_parts(
+            image_id.clone(),
+            Self::seal_post_state_digest(&env, &seal)?,
+            ExitCode::new(SystemExitCode::Halted, 0),
+            zero,
+            output.digest(&env),
+        );
+        let receipt = Receipt {
+            seal,
+            claim_digest: claim.digest(&env),
+        };
+        Self::verify_integrity(env.clone(), receipt)?;
+
+        for assumption in assumption_receipts.iter() {
+            Self::verify_integrity(env.clone(), assumption)?;
+        }
+        Self::annotate_verification(&env, image_id);
+        Ok(())
+    }
+
+    /// Verifies a receipt from its raw journal, enforcing the consumer's journal prefix.
+    fn verify_with_journal_prefix(
+        env: Env,
+        consumer: Address,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: Bytes,
+    ) -> Result<(), VerifierError> {
+        let config = Self::read_consumer_config(&env, &consumer)
+            .ok_or(VerifierError::ConsumerNotConfigured)?;
+
+        let prefix = config.journal_prefix;
+        if journal.len() < prefix.len() || journal.slice(..prefix.len()) != prefix {
+            return Err(VerifierError::JournalPrefixMismatch);
+        }
+
+        let journal_digest: BytesN<32> = env.crypto().sha256(&journal).into();
+        Self::verify(env, seal, image_id, journal_digest)
+    }
+
+    /// Verifies a receipt on behalf of an authorizing caller, counting it against the caller's
+    /// rate limit window.
+    fn verify_as(
+        env: Env,
+        caller: Address,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) -> Result<(), VerifierError> {
+        caller.require_auth();
+        Self::consume_rate_limit(&env, &caller)?;
+        Self::verify(env, seal, image_id, journal)
+    }
+
+    /// Returns the per-caller rate limit, if enabled.
+    fn rate_limit(env: Env) -> Option<RateLimit> {
+        env.storage().instance().get(&DataKey::RateLimit)
+    }
+
+    /// Stores the consumer's configuration after checking its authorization.
+    fn set_consumer_config(env: Env, consumer: Address, config: ConsumerConfig) {
+        consumer.require_auth();
+
+        let key = DataKey::ConsumerConfig(consumer.clone());
+        env.storage().persistent().set(&key, &config);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, VERIFIER_TTL_THRESHOLD, VERIFIER_EXTEND_AMOUNT);
+        ConsumerConfigured { consumer, config }.publish(&env);
+    }
+
+    /// Returns the consumer's configuration.
+    fn consumer_config(env: Env, consumer: Address) -> Option<ConsumerConfig> {
+        Self::read_consumer_config(&env, &consumer)
+    }
+
+    /// Returns the registered cost estimate for the selector.
+    fn estimate_cost(env: Env, selector: BytesN<4>) -> Option<CostEstimate> {
+        let key = DataKey::CostEstimate(selector);
+        env.storage().persistent().get(&key).inspect(|_| {
+            env.storage().persistent().extend_ttl(
+                &key,
+                VERIFIER_TTL_THRESHOLD,
+                VERIFIER_EXTEND_AMOUNT,
+            );
+        })
+    }
 }
 
-/// Extracts the 4-byte selector from the seal prefix.
-fn selector_from_seal(seal: &Bytes) -> Result<BytesN<4>, VerifierError> {
-    if seal.len() < 4 {
-        return Err(VerifierError::MalformedSeal);
+#[cfg(any(test, feature = "testutils"))]
+#[contractimpl]
+impl RiscZeroVerifierRouter {
+    /// Returns the context of the last failed verification, for debugging tests.
+    ///
+    /// Failures of the verifier are recorded with the router's error, see
+    /// [`risc0_interface::debug`].
+    pub fn last_error(env: Env) -> Option<VerificationFailure> {
+        risc0_interface::debug::last_failure(&env)
     }
-    Ok(seal.slice(0..4).try_into().unwrap())
 }
 
 #[contractimpl(contracttrait)]
-impl Ownable for RiscZeroVerifierRouter {}
+impl AccessControl for RiscZeroVerifierRouter {}
@@ -1,8 +1,12 @@
 use super::*;
-use risc0_interface::{Receipt, ReceiptClaim};
+use ::image_registry::{ImageRegistry, ImageRegistryClient};
+use ::mock_verifier::{RiscZeroMockVerifier, RiscZeroMockVerifierClient};
+use risc0_interface::{HostSha256, ImageMetadata, Receipt, ReceiptClaim, ReceiptEnvelope, alerts};
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, Symbol, contract, contractimpl, symbol_short,
-    testutils::Address as _,
+    Address, Bytes, BytesN, Env, Event, IntoVal, String, Symbol, Vec, contract, contractimpl,
+    symbol_short,
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec,
 };
 
 // =============================================================================
@@ -35,26 +39,27 @@ mod mock_verifier {
         pub fn get_verified_receipt(env: Env) -> Option<Receipt> {
             env.storage().temporary().get(&"receipt")
         }
+
+        /// Configures the post-state digest reported to the router.
+        pub fn set_post_state_digest(env: Env, digest: BytesN<32>) {
+            env.storage().temporary().set(&"post_state_digest", &digest);
+        }
+
+        /// Returns the configured post-state digest, by default that of the current release.
+        pub fn post_state_digest(env: Env) -> BytesN<32> {
+            env.storage()
+                .temporary()
+                .get(&"post_state_digest")
+                .unwrap_or_else(|| {
+                    BytesN::from_array(&env, &ReceiptClaim::POST_STATE_DIGEST_HALTED)
+                })
+        }
     }
 
-    #[contractimpl]
+    #[contractimpl(contracttrait)]
     impl RiscZeroVerifierInterface for MockVerifier {
         type Proof = ();
 
-        fn verify(
-            env: Env,
-            seal: Bytes,
-            image_id: BytesN<32>,
-            journal: BytesN<32>,
-        ) -> Result<(), VerifierError> {
-            let claim = ReceiptClaim::new(&env, image_id, journal);
-            let receipt = Receipt {
-                seal,
-                claim_digest: claim.digest(&env),
-            };
-            Self::verify_integrity(env, receipt)
-        }
-
         fn verify_integrity(env: Env, receipt: Receipt) -> Result<(), VerifierError> {
             env.storage().temporary().set(&"called", &true);
             env.storage().temporary().set(&"receipt", &receipt);
@@ -69,6 +74,18 @@ mod mock_verifier {
             }
             Ok(())
         }
+
+        fn verify_many(
+            env: Env,
+            seal: Bytes,
+            claims: Vec<BytesN<32>>,
+        ) -> Result<(), VerifierError> {
+            let receipt = Receipt {
+                seal,
+                claim_digest: claims.get_unchecked(0),
+            };
+            Self::verify_integrity(env, receipt)
+        }
     }
 }
 
@@ -76,15 +93,47 @@ mod mock_verifier {
 // Helper Functions
 // =============================================================================
 
-fn setup_env() -> (Env, Address, RiscZeroVerifierRouterClient<'static>) {
+/// Accounts holding the router roles.
+struct Roles {
+    admin: Address,
+    operator: Address,
+    guardian: Address,
+}
+
+fn register_router(env: &Env) -> (Roles, RiscZeroVerifierRouterClient<'static>) {
+    register_router_with_verifiers(env, Vec::new(env))
+}
+
+fn register_router_with_verifiers(
+    env: &Env,
+    verifiers: Vec<(BytesN<4>, Address)>,
+) -> (Roles, RiscZeroVerifierRouterClient<'static>) {
+    let roles = Roles {
+        admin: Address::generate(env),
+        operator: Address::generate(env),
+        guardian: Address::generate(env),
+    };
+    let contract_id = env.register(
+        RiscZeroVerifierRouter,
+        (
+            roles.admin.clone(),
+            roles.operator.clone(),
+            roles.guardian.clone(),
+            verifiers,
+        ),
+    );
+    let client = RiscZeroVerifierRouterClient::new(env, &contract_id);
+
+    (roles, client)
+}
+
+fn setup_env() -> (Env, Roles, RiscZeroVerifierRouterClient<'static>) {
     let env = Env::default();
     env.mock_all_auths();
 
-    let admin = Address::generate(&env);
-    let contract_id = env.register(RiscZeroVerifierRouter, (admin.clone(),));
-    let client = RiscZeroVerifierRouterClient::new(&env, &contract_id);
+    let (roles, client) = register_router(&env);
 
-    (env, admin, client)
+    (env, roles, client)
 }
 
 fn create_selector(env: &Env, bytes: [u8; 4]) -> BytesN<4> {
@@ -104,6 +153,7 @@ fn create_short_seal(env: &Env) -> Bytes {
 
 fn setup_two_verifiers(
     env: &Env,
+    roles: &Roles,
     client: &RiscZeroVerifierRouterClient<'static>,
 ) -> (BytesN<4>, BytesN<4>, Address, Address) {
     let verifier_a = env.register(mock_verifier::MockVerifier, ());
@@ -112,8 +162,8 @@ fn setup_two_verifiers(
     let selector_a = create_selector(env, [0x01, 0x02, 0x03, 0x04]);
     let selector_b = create_selector(env, [0x10, 0x20, 0x30, 0x40]);
 
-    client.add_verifier(&selector_a, &verifier_a);
-    client.add_verifier(&selector_b, &verifier_b);
+    client.add_verifier(&selector_a, &verifier_a, &roles.operator);
+    client.add_verifier(&selector_b, &verifier_b, &roles.operator);
 
     (selector_a, selector_b, verifier_a, verifier_b)
 }
@@ -136,13 +186,52 @@ fn unwrap_verifier_error<T: core::fmt::Debug>(
 // =============================================================================
 
 #[test]
-fn test_constructor_sets_owner() {
+fn test_constructor_sets_roles() {
+    let env = Env::default();
+    let (roles, client) = register_router(&env);
+
+    assert_eq!(client.get_admin(), Some(roles.admin.clone()));
+    assert!(client.has_role(&roles.operator, &OPERATOR_ROLE).is_some());
+    assert!(client.has_role(&roles.guardian, &GUARDIAN_ROLE).is_some());
+    assert!(client.has_role(&roles.admin, &OPERATOR_ROLE).is_none());
+    assert!(client.has_role(&roles.admin, &GUARDIAN_ROLE).is_none());
+}
+
+#[test]
+fn test_constructor_registers_verifiers() {
+    let env = Env::default();
+    let selector_a = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    let selector_b = create_selector(&env, [0x10, 0x20, 0x30, 0x40]);
+    let verifier_a = Address::generate(&env);
+    let verifier_b = Address::generate(&env);
+
+    let (_roles, client) = register_router_with_verifiers(
+        &env,
+        vec![
+            &env,
+            (selector_a.clone(), verifier_a.clone()),
+            (selector_b.clone(), verifier_b.clone()),
+        ],
+    );
+
+    assert_eq!(client.get_verifier_by_selector(&selector_a), verifier_a);
+    assert_eq!(client.get_verifier_by_selector(&selector_b), verifier_b);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_constructor_rejects_duplicate_selector() {
     let env = Env::default();
-    let admin = Address::generate(&env);
-    let contract_id = env.register(RiscZeroVerifierRouter, (admin.clone(),));
-    let client = RiscZeroVerifierRouterClient::new(&env, &contract_id);
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
 
-    assert_eq!(client.get_owner(), Some(admin));
+    register_router_with_verifiers(
+        &env,
+        vec![
+            &env,
+            (selector.clone(), Address::generate(&env)),
+            (selector, Address::generate(&env)),
+        ],
+    );
 }
 
 // =============================================================================
@@ -151,13 +240,13 @@ fn test_constructor_sets_owner() {
 
 #[test]
 fn test_add_verifier_success() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
     let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
     let verifier_address = Address::generate(&env);
 
     // Non-try version - will panic on error
-    client.add_verifier(&selector, &verifier_address);
+    client.add_verifier(&selector, &verifier_address, &roles.operator);
 
     // Verify it was added
     let result = client.get_verifier_by_selector(&selector);
@@ -166,23 +255,23 @@ fn test_add_verifier_success() {
 
 #[test]
 fn test_add_verifier_selector_in_use() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
     let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
     let verifier1 = Address::generate(&env);
     let verifier2 = Address::generate(&env);
 
     // First add should succeed
-    client.add_verifier(&selector, &verifier1);
+    client.add_verifier(&selector, &verifier1, &roles.operator);
 
     // Second add with same selector should fail - use try_ to capture error
-    let result = client.try_add_verifier(&selector, &verifier2);
+    let result = client.try_add_verifier(&selector, &verifier2, &roles.operator);
     assert_eq!(unwrap_verifier_error(result), VerifierError::SelectorInUse);
 }
 
 #[test]
 fn test_add_verifier_tombstone_selector() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
     let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
     let verifier = Address::generate(&env);
@@ -196,7 +285,7 @@ fn test_add_verifier_tombstone_selector() {
     });
 
     // Adding to tombstoned selector should fail - use try_ to capture error
-    let result = client.try_add_verifier(&selector, &verifier);
+    let result = client.try_add_verifier(&selector, &verifier, &roles.operator);
     assert_eq!(
         unwrap_verifier_error(result),
         VerifierError::SelectorRemoved
@@ -209,7 +298,7 @@ fn test_add_verifier_tombstone_selector() {
 
 #[test]
 fn test_get_verifier_by_selector_unknown() {
-    let (env, _admin, client) = setup_env();
+    let (env, _roles, client) = setup_env();
 
     let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
 
@@ -223,7 +312,7 @@ fn test_get_verifier_by_selector_unknown() {
 
 #[test]
 fn test_get_verifier_by_selector_tombstone() {
-    let (env, _admin, client) = setup_env();
+    let (env, _roles, client) = setup_env();
 
     let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
 
@@ -245,12 +334,12 @@ fn test_get_verifier_by_selector_tombstone() {
 
 #[test]
 fn test_get_verifier_from_seal() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
     let selector = create_selector(&env, [0xDE, 0xAD, 0xBE, 0xEF]);
     let verifier_address = Address::generate(&env);
 
-    client.add_verifier(&selector, &verifier_address);
+    client.add_verifier(&selector, &verifier_address, &roles.operator);
 
     let seal = create_seal_with_selector(&env, &selector);
     let result = client.get_verifier_from_seal(&seal);
@@ -259,7 +348,7 @@ fn test_get_verifier_from_seal() {
 
 #[test]
 fn test_get_verifier_from_seal_unknown() {
-    let (env, _admin, client) = setup_env();
+    let (env, _roles, client) = setup_env();
 
     let selector = create_selector(&env, [0xDE, 0xAD, 0xBE, 0xEF]);
     let seal = create_seal_with_selector(&env, &selector);
@@ -274,20 +363,94 @@ fn test_get_verifier_from_seal_unknown() {
 
 #[test]
 fn test_get_verifier_from_seal_malformed_seal() {
-    let (env, _admin, client) = setup_env();
+    let (env, _roles, client) = setup_env();
     let seal = create_short_seal(&env);
 
     let result = client.try_get_verifier_from_seal(&seal);
     assert_eq!(unwrap_verifier_error(result), VerifierError::MalformedSeal);
 }
 
+#[test]
+fn test_extract_selector() {
+    let (env, _roles, client) = setup_env();
+
+    // The selector is returned even when no verifier is registered for it.
+    let selector = create_selector(&env, [0xDE, 0xAD, 0xBE, 0xEF]);
+    let seal = create_seal_with_selector(&env, &selector);
+    assert_eq!(client.extract_selector(&seal), selector);
+}
+
+#[test]
+fn test_extract_selector_malformed_seal() {
+    let (env, _roles, client) = setup_env();
+    let seal = create_short_seal(&env);
+
+    let result = client.try_extract_selector(&seal);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::MalformedSeal);
+}
+
+// =============================================================================
+// Health Check Tests
+// =============================================================================
+
+#[test]
+fn test_healthcheck_success() {
+    let (env, roles, client) = setup_env();
+    let selector = create_selector(&env, [0xFF, 0, 0, 2]);
+    let verifier = env.register(RiscZeroMockVerifier, (selector.clone(),));
+
+    client.add_verifier(&selector, &verifier, &roles.operator);
+    assert_eq!(client.healthcheck(&selector), ());
+}
+
+#[test]
+fn test_healthcheck_selector_mismatch() {
+    let (env, roles, client) = setup_env();
+    let selector = create_selector(&env, [0xFF, 0, 0, 2]);
+    let other = create_selector(&env, [0xFF, 0, 0, 3]);
+    let verifier = env.register(RiscZeroMockVerifier, (other,));
+
+    client.add_verifier(&selector, &verifier, &roles.operator);
+    let result = client.try_healthcheck(&selector);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::InvalidSelector
+    );
+}
+
+#[test]
+fn test_healthcheck_verifier_unavailable() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, _selector_b, _verifier_a, _verifier_b) =
+        setup_two_verifiers(&env, &roles, &client);
+
+    // The test mock does not expose `selector`.
+    let result = client.try_healthcheck(&selector_a);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::VerifierUnavailable
+    );
+}
+
+#[test]
+fn test_healthcheck_unknown_selector() {
+    let (env, _roles, client) = setup_env();
+    let selector = create_selector(&env, [0xFF, 0, 0, 2]);
+
+    let result = client.try_healthcheck(&selector);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorUnknown
+    );
+}
+
 // =============================================================================
 // Raw Verifier Entry Tests
 // =============================================================================
 
 #[test]
 fn test_verifiers_getter_returns_raw_entry() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
     let selector = create_selector(&env, [0x10, 0x20, 0x30, 0x40]);
 
@@ -295,14 +458,14 @@ fn test_verifiers_getter_returns_raw_entry() {
     assert_eq!(client.verifiers(&selector), None);
 
     let verifier_address = Address::generate(&env);
-    client.add_verifier(&selector, &verifier_address);
+    client.add_verifier(&selector, &verifier_address, &roles.operator);
 
     assert_eq!(
         client.verifiers(&selector),
         Some(VerifierEntry::Active(verifier_address))
     );
 
-    client.remove_verifier(&selector);
+    client.remove_verifier(&selector, &roles.guardian);
 
     assert_eq!(client.verifiers(&selector), Some(VerifierEntry::Tombstone));
 }
@@ -313,13 +476,13 @@ fn test_verifiers_getter_returns_raw_entry() {
 
 #[test]
 fn test_remove_verifier_marks_tombstone() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
     let selector = create_selector(&env, [0xAA, 0xBB, 0xCC, 0xDD]);
     let verifier_address = Address::generate(&env);
 
-    client.add_verifier(&selector, &verifier_address);
-    client.remove_verifier(&selector);
+    client.add_verifier(&selector, &verifier_address, &roles.operator);
+    client.remove_verifier(&selector, &roles.guardian);
 
     let result = client.try_get_verifier_by_selector(&selector);
     assert_eq!(
@@ -328,7 +491,7 @@ fn test_remove_verifier_marks_tombstone() {
     );
 
     let new_verifier = Address::generate(&env);
-    let result = client.try_add_verifier(&selector, &new_verifier);
+    let result = client.try_add_verifier(&selector, &new_verifier, &roles.operator);
     assert_eq!(
         unwrap_verifier_error(result),
         VerifierError::SelectorRemoved
@@ -337,10 +500,10 @@ fn test_remove_verifier_marks_tombstone() {
 
 #[test]
 fn test_remove_verifier_unknown_selector() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
     let selector = create_selector(&env, [0xAA, 0xBB, 0xCC, 0xDD]);
-    let result = client.try_remove_verifier(&selector);
+    let result = client.try_remove_verifier(&selector, &roles.guardian);
     assert_eq!(
         unwrap_verifier_error(result),
         VerifierError::SelectorUnknown
@@ -349,16 +512,17 @@ fn test_remove_verifier_unknown_selector() {
 
 #[test]
 fn test_removed_selector_blocks_verify() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
-    let (selector_a, selector_b, verifier_a, verifier_b) = setup_two_verifiers(&env, &client);
+    let (selector_a, selector_b, verifier_a, verifier_b) =
+        setup_two_verifiers(&env, &roles, &client);
     let mock_a = mock_verifier::MockVerifierClient::new(&env, &verifier_a);
     let mock_b = mock_verifier::MockVerifierClient::new(&env, &verifier_b);
-    client.remove_verifier(&selector_b);
+    client.remove_verifier(&selector_b, &roles.guardian);
 
     let seal_a = create_seal_with_selector(&env, &selector_a);
     let seal_b = create_seal_with_selector(&env, &selector_b);
-    let image_id = BytesN::from_array(&env, &[0u8; 32]);
+    let image_id = BytesN::from_array(&env, &[0x11; 32]);
     let journal_digest = BytesN::from_array(&env, &[1u8; 32]);
 
     client.verify(&seal_a, &image_id, &journal_digest);
@@ -375,16 +539,17 @@ fn test_removed_selector_blocks_verify() {
 
 #[test]
 fn test_removed_selector_blocks_verify_integrity() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
-    let (selector_a, selector_b, verifier_a, verifier_b) = setup_two_verifiers(&env, &client);
+    let (selector_a, selector_b, verifier_a, verifier_b) =
+        setup_two_verifiers(&env, &roles, &client);
     let mock_a = mock_verifier::MockVerifierClient::new(&env, &verifier_a);
     let mock_b = mock_verifier::MockVerifierClient::new(&env, &verifier_b);
-    client.remove_verifier(&selector_b);
+    client.remove_verifier(&selector_b, &roles.guardian);
 
     let receipt_a = Receipt {
         seal: create_seal_with_selector(&env, &selector_a),
-        claim_digest: BytesN::from_array(&env, &[0u8; 32]),
+        claim_digest: BytesN::from_array(&env, &[0x11; 32]),
     };
     client.verify_integrity(&receipt_a);
     assert!(mock_a.was_called());
@@ -392,7 +557,7 @@ fn test_removed_selector_blocks_verify_integrity() {
 
     let receipt_b = Receipt {
         seal: create_seal_with_selector(&env, &selector_b),
-        claim_digest: BytesN::from_array(&env, &[0u8; 32]),
+        claim_digest: BytesN::from_array(&env, &[0x11; 32]),
     };
     let result = client.try_verify_integrity(&receipt_b);
     assert_eq!(
@@ -402,24 +567,69 @@ fn test_removed_selector_blocks_verify_integrity() {
     assert!(!mock_b.was_called());
 }
 
+// =============================================================================
+// Last Error Tests
+// =============================================================================
+
+#[test]
+fn test_last_error_records_failed_verify() {
+    let (env, _roles, client) = setup_env();
+    assert_eq!(client.last_error(), None);
+
+    let selector = create_selector(&env, [0xAA, 0xBB, 0xCC, 0xDD]);
+    let seal = create_seal_with_selector(&env, &selector);
+    let image_id = BytesN::from_array(&env, &[0x11; 32]);
+    let journal_digest = BytesN::from_array(&env, &[1u8; 32]);
+    let result = client.try_verify(&seal, &image_id, &journal_digest);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorUnknown
+    );
+
+    let failure = client.last_error().unwrap();
+    assert_eq!(failure.selector, Some(selector));
+    assert_eq!(
+        failure.claim_digest,
+        ReceiptClaim::new(&env, image_id, journal_digest).digest(&env)
+    );
+    assert_eq!(failure.error, VerifierError::SelectorUnknown as u32);
+}
+
+#[test]
+fn test_last_error_records_failed_verify_integrity() {
+    let (env, _roles, client) = setup_env();
+
+    let receipt = Receipt {
+        seal: create_short_seal(&env),
+        claim_digest: BytesN::from_array(&env, &[0x11; 32]),
+    };
+    let result = client.try_verify_integrity(&receipt);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::MalformedSeal);
+
+    let failure = client.last_error().unwrap();
+    assert_eq!(failure.selector, None);
+    assert_eq!(failure.claim_digest, receipt.claim_digest);
+    assert_eq!(failure.error, VerifierError::MalformedSeal as u32);
+}
+
 // =============================================================================
 // Verification Routing Tests
 // =============================================================================
 
 #[test]
 fn test_verify_routes_to_correct_verifier() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
     // Register a mock verifier
     let mock_verifier_id = env.register(mock_verifier::MockVerifier, ());
     let mock_client = mock_verifier::MockVerifierClient::new(&env, &mock_verifier_id);
 
     let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
-    client.add_verifier(&selector, &mock_verifier_id);
+    client.add_verifier(&selector, &mock_verifier_id, &roles.operator);
 
     // Create a seal with the correct selector
     let seal = create_seal_with_selector(&env, &selector);
-    let image_id = BytesN::from_array(&env, &[0u8; 32]);
+    let image_id = BytesN::from_array(&env, &[0x11; 32]);
     let journal_digest = BytesN::from_array(&env, &[1u8; 32]);
 
     // Verify through the router by invoking the contract function directly
@@ -435,13 +645,14 @@ fn test_verify_routes_to_correct_verifier() {
 
 #[test]
 fn test_verify_routes_to_multiple_verifiers() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
-    let (selector_a, selector_b, verifier_a, verifier_b) = setup_two_verifiers(&env, &client);
+    let (selector_a, selector_b, verifier_a, verifier_b) =
+        setup_two_verifiers(&env, &roles, &client);
     let mock_a = mock_verifier::MockVerifierClient::new(&env, &verifier_a);
     let mock_b = mock_verifier::MockVerifierClient::new(&env, &verifier_b);
 
-    let image_id = BytesN::from_array(&env, &[0u8; 32]);
+    let image_id = BytesN::from_array(&env, &[0x11; 32]);
     let journal_digest = BytesN::from_array(&env, &[1u8; 32]);
 
     let seal_a = create_seal_with_selector(&env, &selector_a);
@@ -458,19 +669,79 @@ fn test_verify_routes_to_multiple_verifiers() {
     assert_eq!(mock_b.get_verified_receipt().unwrap().seal, seal_b);
 }
 
+#[test]
+fn test_verify_with_bypasses_selector() {
+    let (env, roles, client) = setup_env();
+
+    let (_selector_a, selector_b, verifier_a, verifier_b) =
+        setup_two_verifiers(&env, &roles, &client);
+    let mock_a = mock_verifier::MockVerifierClient::new(&env, &verifier_a);
+    let mock_b = mock_verifier::MockVerifierClient::new(&env, &verifier_b);
+
+    let seal = create_seal_with_selector(&env, &selector_b);
+    let image_id = BytesN::from_array(&env, &[0x11; 32]);
+    let journal_digest = BytesN::from_array(&env, &[1u8; 32]);
+    client.verify_with(&verifier_a, &seal, &image_id, &journal_digest);
+
+    assert!(mock_a.was_called());
+    assert!(!mock_b.was_called());
+}
+
+#[test]
+fn test_verify_with_rejects_unregistered_verifier() {
+    let (env, roles, client) = setup_env();
+
+    let (selector_a, _selector_b, verifier_a, _verifier_b) =
+        setup_two_verifiers(&env, &roles, &client);
+    let unregistered = env.register(mock_verifier::MockVerifier, ());
+    let seal = create_seal_with_selector(&env, &selector_a);
+    let image_id = BytesN::from_array(&env, &[0x11; 32]);
+    let journal_digest = BytesN::from_array(&env, &[1u8; 32]);
+
+    let result = client.try_verify_with(&unregistered, &seal, &image_id, &journal_digest);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::VerifierNotRegistered
+    );
+    assert!(!mock_verifier::MockVerifierClient::new(&env, &unregistered).was_called());
+
+    client.remove_verifier(&selector_a, &roles.guardian);
+    let result = client.try_verify_with(&verifier_a, &seal, &image_id, &journal_digest);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::VerifierNotRegistered
+    );
+}
+
+#[test]
+fn test_verify_with_enforces_revocation() {
+    let (env, roles, client) = setup_env();
+
+    let (selector_a, _selector_b, verifier_a, _verifier_b) =
+        setup_two_verifiers(&env, &roles, &client);
+    let seal = create_seal_with_selector(&env, &selector_a);
+    let image_id = BytesN::from_array(&env, &[0x11; 32]);
+    let journal_digest = BytesN::from_array(&env, &[1u8; 32]);
+    client.revoke_image_id(&image_id, &roles.guardian);
+
+    let result = client.try_verify_with(&verifier_a, &seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ClaimRevoked);
+    assert!(!mock_verifier::MockVerifierClient::new(&env, &verifier_a).was_called());
+}
+
 #[test]
 fn test_verify_returns_verifier_error_on_failure() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
     let verifier_id = env.register(mock_verifier::MockVerifier, ());
     let mock_client = mock_verifier::MockVerifierClient::new(&env, &verifier_id);
     let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
-    client.add_verifier(&selector, &verifier_id);
+    client.add_verifier(&selector, &verifier_id, &roles.operator);
 
     mock_client.set_should_fail(&true);
 
     let seal = create_seal_with_selector(&env, &selector);
-    let image_id = BytesN::from_array(&env, &[0u8; 32]);
+    let image_id = BytesN::from_array(&env, &[0x11; 32]);
     let journal_digest = BytesN::from_array(&env, &[1u8; 32]);
 
     let result = client.try_verify(&seal, &image_id, &journal_digest);
@@ -480,20 +751,80 @@ fn test_verify_returns_verifier_error_on_failure() {
     assert!(mock_client.get_verified_receipt().is_none());
 }
 
+#[test]
+fn test_verify_maps_verifier_trap_to_unavailable() {
+    let (env, roles, client) = setup_env();
+
+    // An address without a verifier contract makes the call trap.
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    client.add_verifier(&selector, &Address::generate(&env), &roles.operator);
+
+    let seal = create_seal_with_selector(&env, &selector);
+    let image_id = BytesN::from_array(&env, &[0x11; 32]);
+    let journal_digest = BytesN::from_array(&env, &[1u8; 32]);
+
+    let result = client.try_verify(&seal, &image_id, &journal_digest);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::VerifierUnavailable
+    );
+}
+
+#[test]
+fn test_forward_verifier_result_publishes_original_code() {
+    let (env, _roles, client) = setup_env();
+    let verifier = Address::generate(&env);
+
+    let cases = [
+        (Ok(Ok(())), Ok(()), None),
+        (
+            Err(Ok(VerifierError::InvalidProof)),
+            Err(VerifierError::InvalidProof),
+            Some(Some(0)),
+        ),
+        (
+            Err(Err(InvokeError::Contract(1000))),
+            Err(VerifierError::VerifierUnavailable),
+            Some(Some(1000)),
+        ),
+        (
+            Err(Err(InvokeError::Abort)),
+            Err(VerifierError::VerifierUnavailable),
+            Some(None),
+        ),
+    ];
+    for (result, expected, code) in cases {
+        env.as_contract(&client.address, || {
+            let forwarded =
+                RiscZeroVerifierRouter::forward_verifier_result(&env, &verifier, result);
+            assert_eq!(forwarded, expected);
+        });
+
+        let events = code.map(|code| {
+            VerifierCallFailed {
+                verifier: verifier.clone(),
+                code,
+            }
+            .to_xdr(&env, &client.address)
+        });
+        assert_eq!(env.events().all(), events.as_slice());
+    }
+}
+
 #[test]
 fn test_verify_integrity_routes_to_correct_verifier() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
     // Register a mock verifier
     let mock_verifier_id = env.register(mock_verifier::MockVerifier, ());
     let mock_client = mock_verifier::MockVerifierClient::new(&env, &mock_verifier_id);
 
     let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
-    client.add_verifier(&selector, &mock_verifier_id);
+    client.add_verifier(&selector, &mock_verifier_id, &roles.operator);
 
     // Create a receipt with the correct selector in the seal
     let seal = create_seal_with_selector(&env, &selector);
-    let claim_digest = BytesN::from_array(&env, &[0u8; 32]);
+    let claim_digest = BytesN::from_array(&env, &[0x11; 32]);
     let receipt = Receipt {
         seal,
         claim_digest: claim_digest.clone(),
@@ -515,13 +846,14 @@ fn test_verify_integrity_routes_to_correct_verifier() {
 
 #[test]
 fn test_verify_integrity_routes_to_multiple_verifiers() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
-    let (selector_a, selector_b, verifier_a, verifier_b) = setup_two_verifiers(&env, &client);
+    let (selector_a, selector_b, verifier_a, verifier_b) =
+        setup_two_verifiers(&env, &roles, &client);
     let mock_a = mock_verifier::MockVerifierClient::new(&env, &verifier_a);
     let mock_b = mock_verifier::MockVerifierClient::new(&env, &verifier_b);
 
-    let claim_digest = BytesN::from_array(&env, &[0u8; 32]);
+    let claim_digest = BytesN::from_array(&env, &[0x11; 32]);
 
     let receipt_a = Receipt {
         seal: create_seal_with_selector(&env, &selector_a),
@@ -545,16 +877,16 @@ fn test_verify_integrity_routes_to_multiple_verifiers() {
 
 #[test]
 fn test_verify_integrity_returns_verifier_error_on_failure() {
-    let (env, _admin, client) = setup_env();
+    let (env, roles, client) = setup_env();
 
     let verifier_id = env.register(mock_verifier::MockVerifier, ());
     let mock_client = mock_verifier::MockVerifierClient::new(&env, &verifier_id);
     let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
-    client.add_verifier(&selector, &verifier_id);
+    client.add_verifier(&selector, &verifier_id, &roles.operator);
 
     mock_client.set_should_fail(&true);
 
-    let claim_digest = BytesN::from_array(&env, &[0u8; 32]);
+    let claim_digest = BytesN::from_array(&env, &[0x11; 32]);
     let receipt = Receipt {
         seal: create_seal_with_selector(&env, &selector),
         claim_digest: claim_digest.clone(),
@@ -567,14 +899,32 @@ fn test_verify_integrity_returns_verifier_error_on_failure() {
     assert!(mock_client.get_verified_receipt().is_none());
 }
 
+#[test]
+fn test_verify_many_forwards_verifier_error() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+
+    let receipt = verifier.mock_prove(
+        &BytesN::from_array(&env, &[0xAA; 32]),
+        &BytesN::from_array(&env, &[0x01; 32]),
+    );
+    let claims = vec![&env, receipt.claim_digest.clone(), receipt.claim_digest];
+
+    let result = client.try_verify_many(&receipt.seal, &claims);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::UnsupportedAggregation
+    );
+}
+
 #[test]
 #[should_panic]
 fn test_verify_panics_on_unknown_selector() {
-    let (env, _admin, client) = setup_env();
+    let (env, _roles, client) = setup_env();
 
     let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
     let seal = create_seal_with_selector(&env, &selector);
-    let image_id = BytesN::from_array(&env, &[0u8; 32]);
+    let image_id = BytesN::from_array(&env, &[0x11; 32]);
     let journal_digest = BytesN::from_array(&env, &[1u8; 32]);
 
     // This should panic because no verifier is registered for this selector
@@ -587,10 +937,10 @@ fn test_verify_panics_on_unknown_selector() {
 
 #[test]
 fn test_verify_malformed_seal() {
-    let (env, _admin, client) = setup_env();
+    let (env, _roles, client) = setup_env();
 
     let seal = create_short_seal(&env);
-    let image_id = BytesN::from_array(&env, &[0u8; 32]);
+    let image_id = BytesN::from_array(&env, &[0x11; 32]);
     let journal_digest = BytesN::from_array(&env, &[1u8; 32]);
 
     let result = client.try_verify(&seal, &image_id, &journal_digest);
@@ -599,61 +949,1811 @@ fn test_verify_malformed_seal() {
 
 #[test]
 fn test_verify_integrity_malformed_seal() {
-    let (env, _admin, client) = setup_env();
+    let (env, _roles, client) = setup_env();
 
     let seal = create_short_seal(&env);
     let receipt = Receipt {
         seal,
-        claim_digest: BytesN::from_array(&env, &[0u8; 32]),
+        claim_digest: BytesN::from_array(&env, &[0x11; 32]),
     };
 
     let result = client.try_verify_integrity(&receipt);
     assert_eq!(unwrap_verifier_error(result), VerifierError::MalformedSeal);
 }
 
-// =============================================================================
-// Admin Authorization Tests
-// =============================================================================
+/// Registers the recording mock verifier and returns it with a seal carrying its selector.
+fn setup_recording_verifier(
+    env: &Env,
+    roles: &Roles,
+    client: &RiscZeroVerifierRouterClient<'static>,
+) -> (mock_verifier::MockVerifierClient<'static>, Bytes) {
+    let mock_verifier_id = env.register(mock_verifier::MockVerifier, ());
+    let selector = create_selector(env, [0x01, 0x02, 0x03, 0x04]);
+    client.add_verifier(&selector, &mock_verifier_id, &roles.operator);
+    (
+        mock_verifier::MockVerifierClient::new(env, &mock_verifier_id),
+        create_seal_with_selector(env, &selector),
+    )
+}
 
 #[test]
-#[should_panic]
-fn test_add_verifier_requires_admin_auth() {
-    let env = Env::default();
-    env.mock_all_auths();
+fn test_verify_integrity_with_claim_forwards_matching_claim() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let claim_digest =
+        ReceiptClaim::new(&env, image_id.clone(), journal_digest.clone()).digest(&env);
+    let receipt = Receipt {
+        seal,
+        claim_digest: claim_digest.clone(),
+    };
 
-    let admin = Address::generate(&env);
-    let contract_id = env.register(RiscZeroVerifierRouter, (admin.clone(),));
-    let client = RiscZeroVerifierRouterClient::new(&env, &contract_id);
-    env.set_auths(&[]);
+    client.verify_integrity_with_claim(&receipt, &image_id, &journal_digest);
 
-    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
-    let verifier = Address::generate(&env);
+    assert!(mock_client.was_called());
+    let verified_receipt = mock_client.get_verified_receipt().unwrap();
+    assert_eq!(verified_receipt.claim_digest, claim_digest);
+}
 
-    // Should trap on admin.require_auth().
-    client.add_verifier(&selector, &verifier);
+#[test]
+fn test_verify_integrity_with_claim_rejects_mismatch_before_verifier() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let other_journal = BytesN::from_array(&env, &[0x02; 32]);
+    let receipt = Receipt {
+        seal,
+        claim_digest: ReceiptClaim::new(&env, image_id.clone(), other_journal).digest(&env),
+    };
+
+    let result = client.try_verify_integrity_with_claim(&receipt, &image_id, &journal_digest);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::ClaimDigestMismatch
+    );
+
+    let zero_image_id = BytesN::from_array(&env, &[0u8; 32]);
+    let result = client.try_verify_integrity_with_claim(&receipt, &zero_image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ZeroImageId);
+    assert!(!mock_client.was_called());
 }
 
 #[test]
-#[should_panic]
-fn test_remove_verifier_requires_admin_auth() {
-    let env = Env::default();
-    env.mock_all_auths();
+fn test_verify_envelope_forwards_receipt() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
 
-    let admin = Address::generate(&env);
-    let contract_id = env.register(RiscZeroVerifierRouter, (admin.clone(),));
-    let client = RiscZeroVerifierRouterClient::new(&env, &contract_id);
-    env.set_auths(&[]);
+    let receipt = Receipt {
+        seal,
+        claim_digest: BytesN::from_array(&env, &[0x11; 32]),
+    };
+    client.verify_envelope(&ReceiptEnvelope::new(receipt.clone(), None));
 
-    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
-    let verifier = Address::generate(&env);
+    assert_eq!(mock_client.get_verified_receipt(), Some(receipt));
+}
 
-    env.as_contract(&client.address, || {
-        env.storage().persistent().set(
-            &DataKey::Verifier(selector.clone()),
-            &VerifierEntry::Active(verifier),
-        );
-    });
+#[test]
+fn test_verify_envelope_rejects_unknown_layout_before_verifier() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
 
-    // Should trap on admin.require_auth().
-    client.remove_verifier(&selector);
+    let receipt = Receipt {
+        seal,
+        claim_digest: BytesN::from_array(&env, &[0x11; 32]),
+    };
+    let mut envelope = ReceiptEnvelope::new(receipt.clone(), None);
+    envelope.version = 0;
+    assert_eq!(
+        unwrap_verifier_error(client.try_verify_envelope(&envelope)),
+        VerifierError::UnsupportedEnvelopeVersion
+    );
+
+    let mut envelope = ReceiptEnvelope::new(receipt, None);
+    envelope.proof_system = 0;
+    assert_eq!(
+        unwrap_verifier_error(client.try_verify_envelope(&envelope)),
+        VerifierError::UnsupportedProofSystem
+    );
+    assert!(!mock_client.was_called());
+}
+
+#[test]
+fn test_verify_returning_digest() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let claim_digest =
+        ReceiptClaim::new(&env, image_id.clone(), journal_digest.clone()).digest(&env);
+
+    let digest = client.verify_returning_digest(&seal, &image_id, &journal_digest);
+
+    assert_eq!(digest, claim_digest);
+    let verified_receipt = mock_client.get_verified_receipt().unwrap();
+    assert_eq!(verified_receipt.claim_digest, claim_digest);
+}
+
+#[test]
+fn test_verify_returning_digest_checks_revocations() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let claim_digest =
+        ReceiptClaim::new(&env, image_id.clone(), journal_digest.clone()).digest(&env);
+
+    client.revoke_claim(&claim_digest, &roles.guardian);
+    let result = client.try_verify_returning_digest(&seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ClaimRevoked);
+
+    let zero_image_id = BytesN::from_array(&env, &[0u8; 32]);
+    let result = client.try_verify_returning_digest(&seal, &zero_image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ZeroImageId);
+    assert!(!mock_client.was_called());
+}
+
+#[test]
+fn test_verify_with_exit_code() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let user_exit_code = 2;
+
+    client.verify_with_exit_code(&seal, &image_id, &journal_digest, &user_exit_code);
+
+    let claim = ReceiptClaim::with_user_exit_code(&env, image_id, journal_digest, user_exit_code);
+    let verified_receipt = mock_client.get_verified_receipt().unwrap();
+    assert_eq!(verified_receipt.claim_digest, claim.digest(&env));
+}
+
+#[test]
+fn test_claims_use_verifier_post_state_digest() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
+    let post_state_digest = BytesN::from_array(&env, &[0x22; 32]);
+    mock_client.set_post_state_digest(&post_state_digest);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let claim = |user_exit_code| {
+        ReceiptClaim::with_post_state_digest(
+            &env,
+            image_id.clone(),
+            journal_digest.clone(),
+            post_state_digest.clone(),
+            user_exit_code,
+        )
+        .digest(&env)
+    };
+
+    let digest = client.verify_returning_digest(&seal, &image_id, &journal_digest);
+    assert_eq!(digest, claim(0));
+    assert_eq!(
+        mock_client.get_verified_receipt().unwrap().claim_digest,
+        claim(0)
+    );
+
+    client.verify_with_exit_code(&seal, &image_id, &journal_digest, &2);
+    assert_eq!(
+        mock_client.get_verified_receipt().unwrap().claim_digest,
+        claim(2)
+    );
+
+    let receipt = Receipt {
+        seal: seal.clone(),
+        claim_digest: claim(0),
+    };
+    client.verify_integrity_with_claim(&receipt, &image_id, &journal_digest);
+    let standard = Receipt {
+        seal: seal.clone(),
+        claim_digest: ReceiptClaim::new(&env, image_id.clone(), journal_digest.clone())
+            .digest(&env),
+    };
+    let result = client.try_verify_integrity_with_claim(&standard, &image_id, &journal_digest);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::ClaimDigestMismatch
+    );
+
+    // Revoking the claim the verifier proves blocks every path to it.
+    client.revoke_claim(&claim(0), &roles.guardian);
+    let result = client.try_verify(&seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ClaimRevoked);
+    let result = client.try_verify_returning_digest(&seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ClaimRevoked);
+}
+
+// =============================================================================
+// Conditional Verification Tests
+// =============================================================================
+
+/// Registers a claim-checking mock verifier behind the router.
+fn setup_claim_verifier(
+    env: &Env,
+    roles: &Roles,
+    client: &RiscZeroVerifierRouterClient<'static>,
+) -> RiscZeroMockVerifierClient<'static> {
+    let selector = create_selector(env, [0xFF, 0x00, 0x00, 0x02]);
+    let verifier_id = env.register(RiscZeroMockVerifier, (selector.clone(),));
+    client.add_verifier(&selector, &verifier_id, &roles.operator);
+    RiscZeroMockVerifierClient::new(env, &verifier_id)
+}
+
+/// Computes the claim digest of a successful execution conditional on `assumptions`.
+fn conditional_claim_digest(
+    env: &Env,
+    image_id: &BytesN<32>,
+    journal_digest: &BytesN<32>,
+    assumptions: &[&Receipt],
+) -> BytesN<32> {
+    let mut assumptions_digest = [0u8; 32];
+    for receipt in assumptions.iter().rev() {
+        let head = risc0_digest::assumption_digest(
+            HostSha256::new(env),
+            &receipt.claim_digest.to_array(),
+            &[0u8; 32],
+        );
+        assumptions_digest = risc0_digest::tagged_list_cons(
+            HostSha256::new(env),
+            &risc0_digest::ASSUMPTIONS_TAG_DIGEST,
+            &head,
+            &assumptions_digest,
+        );
+    }
+    let output = risc0_digest::output_digest(
+        HostSha256::new(env),
+        &journal_digest.to_array(),
+        &assumptions_digest,
+    );
+    ReceiptClaim::from_parts(
+        image_id.clone(),
+        BytesN::from_array(env, &ReceiptClaim::POST_STATE_DIGEST_HALTED),
+        ExitCode::new(SystemExitCode::Halted, 0),
+        BytesN::from_array(env, &[0u8; 32]),
+        BytesN::from_array(env, &output),
+    )
+    .digest(env)
+}
+
+#[test]
+fn test_verify_conditional_with_assumptions() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let first = verifier.mock_prove(&BytesN::from_array(&env, &[0xB1; 32]), &journal_digest);
+    let second = verifier.mock_prove(&BytesN::from_array(&env, &[0xB2; 32]), &journal_digest);
+
+    let claim_digest =
+        conditional_claim_digest(&env, &image_id, &journal_digest, &[&first, &second]);
+    let conditional = verifier.mock_prove_claim(&claim_digest);
+
+    client.verify_conditional(
+        &conditional.seal,
+        &image_id,
+        &journal_digest,
+        &vec![&env, first, second],
+    );
+}
+
+#[test]
+fn test_verify_conditional_rejects_mismatched_assumptions() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let first = verifier.mock_prove(&BytesN::from_array(&env, &[0xB1; 32]), &journal_digest);
+    let second = verifier.mock_prove(&BytesN::from_array(&env, &[0xB2; 32]), &journal_digest);
+
+    let claim_digest =
+        conditional_claim_digest(&env, &image_id, &journal_digest, &[&first, &second]);
+    let conditional = verifier.mock_prove_claim(&claim_digest);
+
+    // Missing assumption.
+    let result = client.try_verify_conditional(
+        &conditional.seal,
+        &image_id,
+        &journal_digest,
+        &vec![&env, first.clone()],
+    );
+    assert_eq!(unwrap_verifier_error(result), VerifierError::InvalidProof);
+
+    // Assumptions out of order.
+    let result = client.try_verify_conditional(
+        &conditional.seal,
+        &image_id,
+        &journal_digest,
+        &vec![&env, second, first],
+    );
+    assert_eq!(unwrap_verifier_error(result), VerifierError::InvalidProof);
+}
+
+#[test]
+fn test_verify_conditional_rejects_invalid_assumption_receipt() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let valid = verifier.mock_prove(&BytesN::from_array(&env, &[0xB1; 32]), &journal_digest);
+    let forged = Receipt {
+        seal: valid.seal.clone(),
+        claim_digest: BytesN::from_array(&env, &[0xCC; 32]),
+    };
+
+    let claim_digest = conditional_claim_digest(&env, &image_id, &journal_digest, &[&forged]);
+    let conditional = verifier.mock_prove_claim(&claim_digest);
+
+    let result = client.try_verify_conditional(
+        &conditional.seal,
+        &image_id,
+        &journal_digest,
+        &vec![&env, forged],
+    );
+    assert_eq!(unwrap_verifier_error(result), VerifierError::InvalidProof);
+}
+
+#[test]
+fn test_verify_conditional_without_assumptions_matches_verify() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let receipt = verifier.mock_prove(&image_id, &journal_digest);
+
+    client.verify(&receipt.seal, &image_id, &journal_digest);
+    client.verify_conditional(&receipt.seal, &image_id, &journal_digest, &vec![&env]);
+}
+
+#[test]
+fn test_compute_output_digest() {
+    let (env, _roles, client) = setup_env();
+    let journal_digest = BytesN::from_array(&env, &[0x11; 32]);
+    let assumptions_digest = BytesN::from_array(&env, &[0x22; 32]);
+
+    let expected = risc0_digest::output_digest(
+        HostSha256::new(&env),
+        &journal_digest.to_array(),
+        &assumptions_digest.to_array(),
+    );
+    assert_eq!(
+        client.compute_output_digest(&journal_digest, &assumptions_digest),
+        BytesN::from_array(&env, &expected)
+    );
+}
+
+// =============================================================================
+// Revocation Tests
+// =============================================================================
+
+#[test]
+fn test_revoked_claim_blocks_verify_and_verify_integrity() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let receipt = verifier.mock_prove(&image_id, &journal_digest);
+    client.verify_integrity(&receipt);
+
+    client.revoke_claim(&receipt.claim_digest, &roles.guardian);
+    assert!(client.is_claim_revoked(&receipt.claim_digest));
+
+    let result = client.try_verify(&receipt.seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ClaimRevoked);
+    let result = client.try_verify_integrity(&receipt);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ClaimRevoked);
+
+    // Other claims for the same image id are unaffected.
+    let other_journal = BytesN::from_array(&env, &[0x02; 32]);
+    let other = verifier.mock_prove(&image_id, &other_journal);
+    client.verify(&other.seal, &image_id, &other_journal);
+}
+
+#[test]
+fn test_revoked_image_id_blocks_verify() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let receipt = verifier.mock_prove(&image_id, &journal_digest);
+
+    client.revoke_image_id(&image_id, &roles.guardian);
+    assert!(client.is_image_id_revoked(&image_id));
+    assert!(!client.is_claim_revoked(&receipt.claim_digest));
+
+    let result = client.try_verify(&receipt.seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ClaimRevoked);
+    let result =
+        client.try_verify_conditional(&receipt.seal, &image_id, &journal_digest, &vec![&env]);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ClaimRevoked);
+}
+
+#[test]
+fn test_revoked_assumption_blocks_verify_conditional() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let assumption = verifier.mock_prove(&BytesN::from_array(&env, &[0xB1; 32]), &journal_digest);
+    let claim_digest = conditional_claim_digest(&env, &image_id, &journal_digest, &[&assumption]);
+    let conditional = verifier.mock_prove_claim(&claim_digest);
+
+    client.revoke_claim(&assumption.claim_digest, &roles.guardian);
+
+    let result = client.try_verify_conditional(
+        &conditional.seal,
+        &image_id,
+        &journal_digest,
+        &vec![&env, assumption],
+    );
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ClaimRevoked);
+}
+
+#[test]
+fn test_revoked_claim_blocks_verify_many() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let receipt = verifier.mock_prove(&image_id, &journal_digest);
+    let claims = vec![&env, receipt.claim_digest.clone()];
+    client.verify_many(&receipt.seal, &claims);
+
+    client.revoke_claim(&receipt.claim_digest, &roles.guardian);
+
+    let result = client.try_verify_many(&receipt.seal, &claims);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ClaimRevoked);
+}
+
+#[test]
+fn test_zero_image_id_rejected() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+
+    let image_id = BytesN::from_array(&env, &[0u8; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let receipt = verifier.mock_prove(&image_id, &journal_digest);
+
+    let result = client.try_verify(&receipt.seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ZeroImageId);
+    let result =
+        client.try_verify_conditional(&receipt.seal, &image_id, &journal_digest, &vec![&env]);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ZeroImageId);
+}
+
+/// `verify_integrity` passes the zero claim digest through to the verifier, so an emergency
+/// stop in front of the router can check proofs of exploit, while the other entrypoints
+/// reject it.
+#[test]
+fn test_zero_claim_digest_rejected_outside_verify_integrity() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+
+    let zero_digest = BytesN::from_array(&env, &[0u8; 32]);
+    let receipt = verifier.mock_prove_claim(&zero_digest);
+
+    client.verify_integrity(&receipt);
+
+    let result = client.try_verify_envelope(&ReceiptEnvelope::new(receipt.clone(), None));
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::ZeroClaimDigest
+    );
+
+    let other = verifier.mock_prove_claim(&BytesN::from_array(&env, &[0xAA; 32]));
+    let claims = vec![&env, other.claim_digest, zero_digest];
+    let result = client.try_verify_many(&receipt.seal, &claims);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::ZeroClaimDigest
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_revoke_claim_requires_guardian_role() {
+    let (env, roles, client) = setup_env();
+
+    client.revoke_claim(&BytesN::from_array(&env, &[0x01; 32]), &roles.operator);
+}
+
+// =============================================================================
+// Journal Prefix Tests
+// =============================================================================
+
+const JOURNAL_PREFIX: [u8; 2] = [0x01, 0x00];
+
+/// Registers a consumer whose journals must start with [`JOURNAL_PREFIX`].
+fn setup_consumer(env: &Env, client: &RiscZeroVerifierRouterClient<'static>) -> Address {
+    let consumer = Address::generate(env);
+    let config = ConsumerConfig {
+        journal_prefix: Bytes::from_array(env, &JOURNAL_PREFIX),
+    };
+    client.set_consumer_config(&consumer, &config);
+    consumer
+}
+
+/// Proves a raw journal for `image_id` with the claim-checking mock verifier.
+fn prove_journal(
+    env: &Env,
+    verifier: &RiscZeroMockVerifierClient<'static>,
+    image_id: &BytesN<32>,
+    journal: &Bytes,
+) -> Bytes {
+    let journal_digest = env.crypto().sha256(journal).into();
+    verifier.mock_prove(image_id, &journal_digest).seal
+}
+
+#[test]
+fn test_verify_with_journal_prefix() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+    let consumer = setup_consumer(&env, &client);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let mut journal = Bytes::from_array(&env, &JOURNAL_PREFIX);
+    journal.append(&Bytes::from_array(&env, &[0x42; 8]));
+    let seal = prove_journal(&env, &verifier, &image_id, &journal);
+
+    client.verify_with_journal_prefix(&consumer, &seal, &image_id, &journal);
+
+    // The prefix alone is a valid journal.
+    let journal = Bytes::from_array(&env, &JOURNAL_PREFIX);
+    let seal = prove_journal(&env, &verifier, &image_id, &journal);
+    client.verify_with_journal_prefix(&consumer, &seal, &image_id, &journal);
+}
+
+#[test]
+fn test_verify_with_journal_prefix_rejects_other_prefix() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+    let consumer = setup_consumer(&env, &client);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+
+    for journal in [[0x02, 0x00, 0x42].as_slice(), &[0x01]] {
+        let journal = Bytes::from_slice(&env, journal);
+        let seal = prove_journal(&env, &verifier, &image_id, &journal);
+
+        let result = client.try_verify_with_journal_prefix(&consumer, &seal, &image_id, &journal);
+        assert_eq!(
+            unwrap_verifier_error(result),
+            VerifierError::JournalPrefixMismatch
+        );
+    }
+}
+
+#[test]
+fn test_verify_with_journal_prefix_requires_consumer_config() {
+    let (env, roles, client) = setup_env();
+    let verifier = setup_claim_verifier(&env, &roles, &client);
+    let consumer = Address::generate(&env);
+    assert_eq!(client.consumer_config(&consumer), None);
+
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal = Bytes::from_array(&env, &JOURNAL_PREFIX);
+    let seal = prove_journal(&env, &verifier, &image_id, &journal);
+
+    let result = client.try_verify_with_journal_prefix(&consumer, &seal, &image_id, &journal);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::ConsumerNotConfigured
+    );
+}
+
+#[test]
+fn test_set_consumer_config_replaces_previous() {
+    let (env, _roles, client) = setup_env();
+    let consumer = setup_consumer(&env, &client);
+
+    let config = ConsumerConfig {
+        journal_prefix: Bytes::from_array(&env, &[0x02]),
+    };
+    client.set_consumer_config(&consumer, &config);
+
+    assert_eq!(client.consumer_config(&consumer), Some(config));
+}
+
+#[test]
+#[should_panic]
+fn test_set_consumer_config_requires_consumer_auth() {
+    let env = Env::default();
+    let (_roles, client) = register_router(&env);
+
+    let config = ConsumerConfig {
+        journal_prefix: Bytes::from_array(&env, &JOURNAL_PREFIX),
+    };
+    // Should trap on consumer.require_auth().
+    client.set_consumer_config(&Address::generate(&env), &config);
+}
+
+// =============================================================================
+// Cost Estimate Tests
+// =============================================================================
+
+fn create_cost_estimate() -> CostEstimate {
+    CostEstimate {
+        cpu_instructions: 40_000_000,
+        fee: 1_500_000,
+    }
+}
+
+#[test]
+fn test_set_cost_estimate() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, selector_b, _, _) = setup_two_verifiers(&env, &roles, &client);
+    assert_eq!(client.estimate_cost(&selector_a), None);
+
+    let estimate = create_cost_estimate();
+    client.set_cost_estimate(&selector_a, &estimate, &roles.operator);
+
+    assert_eq!(client.estimate_cost(&selector_a), Some(estimate));
+    assert_eq!(client.estimate_cost(&selector_b), None);
+
+    // A new estimate replaces the previous one.
+    let estimate = CostEstimate {
+        cpu_instructions: 50_000_000,
+        fee: 2_000_000,
+    };
+    client.set_cost_estimate(&selector_a, &estimate, &roles.operator);
+    assert_eq!(client.estimate_cost(&selector_a), Some(estimate));
+}
+
+#[test]
+fn test_set_cost_estimate_requires_active_verifier() {
+    let (env, roles, client) = setup_env();
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    let estimate = create_cost_estimate();
+
+    let result = client.try_set_cost_estimate(&selector, &estimate, &roles.operator);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorUnknown
+    );
+
+    client.add_verifier(&selector, &Address::generate(&env), &roles.operator);
+    client.remove_verifier(&selector, &roles.guardian);
+
+    let result = client.try_set_cost_estimate(&selector, &estimate, &roles.operator);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorRemoved
+    );
+}
+
+#[test]
+fn test_remove_verifier_drops_cost_estimate() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, _, _, _) = setup_two_verifiers(&env, &roles, &client);
+    client.set_cost_estimate(&selector_a, &create_cost_estimate(), &roles.operator);
+
+    client.remove_verifier(&selector_a, &roles.guardian);
+
+    assert_eq!(client.estimate_cost(&selector_a), None);
+}
+
+// =============================================================================
+// Rate Limit Tests
+// =============================================================================
+
+fn create_rate_limit() -> RateLimit {
+    RateLimit {
+        max_verifications: 2,
+        window_ledgers: 100,
+    }
+}
+
+#[test]
+fn test_verify_as_without_rate_limit() {
+    let (env, roles, client) = setup_env();
+    let (_, seal) = setup_recording_verifier(&env, &roles, &client);
+    let caller = Address::generate(&env);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    assert_eq!(client.rate_limit(), None);
+
+    for _ in 0..5 {
+        client.verify_as(&caller, &seal, &image_id, &journal_digest);
+    }
+}
+
+#[test]
+fn test_verify_as_enforces_rate_limit_per_caller() {
+    let (env, roles, client) = setup_env();
+    let (_, seal) = setup_recording_verifier(&env, &roles, &client);
+    let caller = Address::generate(&env);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+
+    let limit = create_rate_limit();
+    client.set_rate_limit(&Some(limit.clone()), &roles.operator);
+    assert_eq!(client.rate_limit(), Some(limit));
+
+    client.verify_as(&caller, &seal, &image_id, &journal_digest);
+    client.verify_as(&caller, &seal, &image_id, &journal_digest);
+    let result = client.try_verify_as(&caller, &seal, &image_id, &journal_digest);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::RateLimitExceeded
+    );
+
+    // Other callers and the unauthenticated entrypoints are not limited.
+    let other = Address::generate(&env);
+    client.verify_as(&other, &seal, &image_id, &journal_digest);
+    client.verify(&seal, &image_id, &journal_digest);
+
+    // The window closes after `window_ledgers` ledgers.
+    env.ledger()
+        .with_mut(|ledger| ledger.sequence_number += 100);
+    client.verify_as(&caller, &seal, &image_id, &journal_digest);
+}
+
+#[test]
+fn test_failed_verification_does_not_count_against_rate_limit() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
+    let caller = Address::generate(&env);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    client.set_rate_limit(&Some(create_rate_limit()), &roles.operator);
+
+    mock_client.set_should_fail(&true);
+    for _ in 0..3 {
+        let result = client.try_verify_as(&caller, &seal, &image_id, &journal_digest);
+        assert_eq!(unwrap_verifier_error(result), VerifierError::InvalidProof);
+    }
+
+    mock_client.set_should_fail(&false);
+    client.verify_as(&caller, &seal, &image_id, &journal_digest);
+    client.verify_as(&caller, &seal, &image_id, &journal_digest);
+}
+
+#[test]
+fn test_set_rate_limit_disables_rate_limit() {
+    let (env, roles, client) = setup_env();
+    let (_, seal) = setup_recording_verifier(&env, &roles, &client);
+    let caller = Address::generate(&env);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    client.set_rate_limit(&Some(create_rate_limit()), &roles.operator);
+    client.verify_as(&caller, &seal, &image_id, &journal_digest);
+    client.verify_as(&caller, &seal, &image_id, &journal_digest);
+
+    client.set_rate_limit(&None, &roles.operator);
+
+    assert_eq!(client.rate_limit(), None);
+    client.verify_as(&caller, &seal, &image_id, &journal_digest);
+}
+
+// =============================================================================
+// Circuit Breaker Tests
+// =============================================================================
+
+/// Trips past half of the verifications failing, once a window holds 4.
+fn create_circuit_breaker() -> CircuitBreaker {
+    CircuitBreaker {
+        max_failure_bps: 5_000,
+        min_verifications: 4,
+        window_ledgers: 100,
+    }
+}
+
+#[test]
+fn test_report_verification_without_circuit_breaker() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    assert_eq!(client.circuit_breaker(), None);
+
+    assert_eq!(
+        client.report_verification(&seal, &image_id, &journal_digest, &roles.operator),
+        None
+    );
+    mock_client.set_should_fail(&true);
+    for _ in 0..5 {
+        assert_eq!(
+            client.report_verification(&seal, &image_id, &journal_digest, &roles.operator),
+            Some(VerifierError::InvalidProof as u32)
+        );
+    }
+    assert!(!client.is_selector_paused(&create_selector(&env, [0x01, 0x02, 0x03, 0x04])));
+}
+
+#[test]
+fn test_circuit_breaker_pauses_failing_selector() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    client.set_circuit_breaker(&Some(create_circuit_breaker()), &roles.operator);
+
+    client.verify(&seal, &image_id, &journal_digest);
+    client.verify(&seal, &image_id, &journal_digest);
+
+    // 1 of 3 and 2 of 4 failures stay within the threshold, 3 of 5 trips it.
+    mock_client.set_should_fail(&true);
+    for _ in 0..3 {
+        assert_eq!(
+            client.report_verification(&seal, &image_id, &journal_digest, &roles.operator),
+            Some(VerifierError::InvalidProof as u32)
+        );
+    }
+    assert_eq!(
+        env.events().all(),
+        [
+            VerifierCallFailed {
+                verifier: mock_client.address.clone(),
+                code: Some(VerifierError::InvalidProof as u32),
+            }
+            .to_xdr(&env, &client.address),
+            SelectorTripped {
+                selector: selector.clone(),
+                failures: 3,
+                verifications: 5,
+            }
+            .to_xdr(&env, &client.address),
+        ]
+    );
+    assert!(client.is_selector_paused(&selector));
+
+    mock_client.set_should_fail(&false);
+    let result = client.try_verify(&seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::SelectorPaused);
+    let result = client.try_verify_with(&mock_client.address, &seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::SelectorPaused);
+    let result = client.try_report_verification(&seal, &image_id, &journal_digest, &roles.operator);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::SelectorPaused);
+}
+
+#[test]
+fn test_circuit_breaker_window_expires() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    client.set_circuit_breaker(&Some(create_circuit_breaker()), &roles.operator);
+
+    mock_client.set_should_fail(&true);
+    for _ in 0..3 {
+        client.report_verification(&seal, &image_id, &journal_digest, &roles.operator);
+    }
+
+    // The failures of the closed window do not count towards the next one. The mock's
+    // failure flag is temporary as well, so it is set again.
+    env.ledger()
+        .with_mut(|ledger| ledger.sequence_number += 100);
+    mock_client.set_should_fail(&true);
+    for _ in 0..3 {
+        client.report_verification(&seal, &image_id, &journal_digest, &roles.operator);
+    }
+    assert!(!client.is_selector_paused(&selector));
+
+    client.report_verification(&seal, &image_id, &journal_digest, &roles.operator);
+    assert!(client.is_selector_paused(&selector));
+}
+
+#[test]
+fn test_resume_selector() {
+    let (env, roles, client) = setup_env();
+    let (mock_client, seal) = setup_recording_verifier(&env, &roles, &client);
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    client.set_circuit_breaker(&Some(create_circuit_breaker()), &roles.operator);
+
+    mock_client.set_should_fail(&true);
+    for _ in 0..4 {
+        client.report_verification(&seal, &image_id, &journal_digest, &roles.operator);
+    }
+    assert!(client.is_selector_paused(&selector));
+
+    client.resume_selector(&selector, &roles.guardian);
+
+    assert_eq!(
+        env.events().all(),
+        [SelectorResumed {
+            selector: selector.clone()
+        }
+        .to_xdr(&env, &client.address)]
+    );
+    assert!(!client.is_selector_paused(&selector));
+    mock_client.set_should_fail(&false);
+    client.verify(&seal, &image_id, &journal_digest);
+
+    let unknown = create_selector(&env, [0xEE; 4]);
+    let result = client.try_resume_selector(&unknown, &roles.guardian);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorUnknown
+    );
+}
+
+#[test]
+fn test_circuit_breaker_events_are_alerts() {
+    let env = Env::default();
+    let selector = create_selector(&env, [0xEE; 4]);
+    let verifier = Address::generate(&env);
+
+    let tripped = SelectorTripped {
+        selector: selector.clone(),
+        failures: 3,
+        verifications: 5,
+    };
+    let resumed = SelectorResumed { selector };
+    let failed = VerifierCallFailed {
+        verifier,
+        code: None,
+    };
+    assert!(alerts::is_alert(
+        &env,
+        &tripped.topics(&env),
+        alerts::PAUSE_ALERT
+    ));
+    assert!(alerts::is_alert(
+        &env,
+        &resumed.topics(&env),
+        alerts::RESUME_ALERT
+    ));
+    assert!(alerts::is_alert(
+        &env,
+        &failed.topics(&env),
+        alerts::VERIFICATION_FAILED_ALERT
+    ));
+}
+
+// =============================================================================
+// Image Registry Tests
+// =============================================================================
+
+fn setup_image_registry(
+    env: &Env,
+    client: &RiscZeroVerifierRouterClient<'static>,
+) -> ImageRegistryClient<'static> {
+    let registry = ImageRegistryClient::new(env, &env.register(ImageRegistry, ()));
+    client.set_image_registry(&Some(registry.address.clone()));
+    registry
+}
+
+#[test]
+fn test_verify_without_image_registry_is_not_annotated() {
+    let (env, roles, client) = setup_env();
+    let (_, seal) = setup_recording_verifier(&env, &roles, &client);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    assert_eq!(client.image_registry(), None);
+
+    client.verify(&seal, &image_id, &journal_digest);
+
+    assert!(env.events().all().events().is_empty());
+}
+
+#[test]
+fn test_verify_annotates_registered_image() {
+    let (env, roles, client) = setup_env();
+    let (_, seal) = setup_recording_verifier(&env, &roles, &client);
+    let registry = setup_image_registry(&env, &client);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let name = String::from_str(&env, "airdrop-guest");
+    let metadata = ImageMetadata {
+        name: name.clone(),
+        version: String::from_str(&env, "1.0.0"),
+        source_url_digest: BytesN::from_array(&env, &[0x02; 32]),
+    };
+    registry.register(&Address::generate(&env), &image_id, &metadata);
+
+    client.verify(&seal, &image_id, &journal_digest);
+
+    let event = ImageVerified {
+        image_id,
+        name: Some(name),
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_verify_annotates_unregistered_image() {
+    let (env, roles, client) = setup_env();
+    let (_, seal) = setup_recording_verifier(&env, &roles, &client);
+    setup_image_registry(&env, &client);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+
+    client.verify_returning_digest(&seal, &image_id, &journal_digest);
+
+    let event = ImageVerified {
+        image_id,
+        name: None,
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_unreachable_image_registry_does_not_fail_verification() {
+    let (env, roles, client) = setup_env();
+    let (_, seal) = setup_recording_verifier(&env, &roles, &client);
+    client.set_image_registry(&Some(Address::generate(&env)));
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+
+    client.verify(&seal, &image_id, &journal_digest);
+
+    let event = ImageVerified {
+        image_id,
+        name: None,
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_set_image_registry_disables_annotations() {
+    let (env, roles, client) = setup_env();
+    let (_, seal) = setup_recording_verifier(&env, &roles, &client);
+    setup_image_registry(&env, &client);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+
+    client.set_image_registry(&None);
+    assert_eq!(client.image_registry(), None);
+
+    client.verify(&seal, &image_id, &journal_digest);
+    assert!(env.events().all().events().is_empty());
+}
+
+// =============================================================================
+// Registry Export Tests
+// =============================================================================
+
+#[test]
+fn test_export_registry_includes_tombstones() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, selector_b, _, verifier_b) = setup_two_verifiers(&env, &roles, &client);
+
+    client.remove_verifier(&selector_a, &roles.guardian);
+
+    assert_eq!(
+        client.export_registry(),
+        vec![
+            &env,
+            (selector_a, VerifierEntry::Tombstone),
+            (selector_b, VerifierEntry::Active(verifier_b)),
+        ]
+    );
+}
+
+#[test]
+fn test_import_registry_restores_export() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, selector_b, verifier_a, verifier_b) =
+        setup_two_verifiers(&env, &roles, &client);
+    client.remove_verifier(&selector_a, &roles.guardian);
+    let exported = client.export_registry();
+
+    let (replacement_roles, replacement) = register_router(&env);
+    replacement.import_registry(&exported);
+
+    assert_eq!(replacement.export_registry(), exported);
+    assert_eq!(
+        replacement.get_verifier_by_selector(&selector_b),
+        verifier_b
+    );
+    let result =
+        replacement.try_add_verifier(&selector_a, &verifier_a, &replacement_roles.operator);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorRemoved
+    );
+}
+
+#[test]
+fn test_import_registry_rejects_assigned_selector() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, _, _, _) = setup_two_verifiers(&env, &roles, &client);
+    let exported = client.export_registry();
+
+    let result = client.try_import_registry(&exported);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::SelectorInUse);
+
+    client.remove_verifier(&selector_a, &roles.guardian);
+    let tombstone = vec![&env, (selector_a, VerifierEntry::Tombstone)];
+    let result = client.try_import_registry(&tombstone);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorRemoved
+    );
+}
+
+// =============================================================================
+// Registry Finalization Tests
+// =============================================================================
+
+#[test]
+fn test_finalize_registry_freezes_routing_table() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, selector_b, verifier_a, _) = setup_two_verifiers(&env, &roles, &client);
+    let exported = client.export_registry();
+    assert!(!client.is_registry_finalized());
+
+    client.finalize_registry();
+
+    assert!(client.is_registry_finalized());
+    let selector_c = create_selector(&env, [0x0C; 4]);
+    let result = client.try_add_verifier(&selector_c, &verifier_a, &roles.operator);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::RegistryFinalized
+    );
+    let result = client.try_remove_verifier(&selector_a, &roles.guardian);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::RegistryFinalized
+    );
+    let result = client.try_import_registry(&vec![&env, (selector_c, VerifierEntry::Tombstone)]);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::RegistryFinalized
+    );
+    let result = client.try_set_selector_sunset(&selector_a, &Some(0));
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::RegistryFinalized
+    );
+    let result = client.try_set_quorum(&BytesN::from_array(&env, &[0xAA; 32]), &2);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::RegistryFinalized
+    );
+    let result = client.try_finalize_registry();
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::RegistryFinalized
+    );
+
+    // Routing and revocations keep working.
+    assert_eq!(client.export_registry(), exported);
+    let seal = create_seal_with_selector(&env, &selector_b);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    client.verify(&seal, &image_id, &journal_digest);
+    client.revoke_image_id(&image_id, &roles.guardian);
+    let result = client.try_verify(&seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ClaimRevoked);
+}
+
+// =============================================================================
+// Quorum Tests
+// =============================================================================
+
+#[test]
+fn test_verify_quorum_requires_distinct_verifiers() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, selector_b, verifier_a, verifier_b) =
+        setup_two_verifiers(&env, &roles, &client);
+    let mock_a = mock_verifier::MockVerifierClient::new(&env, &verifier_a);
+    let mock_b = mock_verifier::MockVerifierClient::new(&env, &verifier_b);
+    let seal_a = create_seal_with_selector(&env, &selector_a);
+    let seal_b = create_seal_with_selector(&env, &selector_b);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    assert_eq!(client.quorum(&image_id), 1);
+
+    client.set_quorum(&image_id, &2);
+    assert_eq!(client.quorum(&image_id), 2);
+
+    // Too few seals fail before any verifier is called.
+    let result = client.try_verify_quorum(&vec![&env, seal_a.clone()], &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::QuorumNotMet);
+    assert!(!mock_a.was_called());
+
+    // Two seals for the same verifier count once.
+    let seals = vec![&env, seal_a.clone(), seal_a.clone()];
+    let result = client.try_verify_quorum(&seals, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::QuorumNotMet);
+
+    client.verify_quorum(
+        &vec![&env, seal_a, seal_b.clone()],
+        &image_id,
+        &journal_digest,
+    );
+    assert!(mock_a.was_called());
+    assert_eq!(mock_b.get_verified_receipt().unwrap().seal, seal_b);
+}
+
+#[test]
+fn test_verify_quorum_fails_if_any_seal_fails() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, selector_b, _, verifier_b) = setup_two_verifiers(&env, &roles, &client);
+    let seals = vec![
+        &env,
+        create_seal_with_selector(&env, &selector_a),
+        create_seal_with_selector(&env, &selector_b),
+    ];
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    client.set_quorum(&image_id, &2);
+
+    mock_verifier::MockVerifierClient::new(&env, &verifier_b).set_should_fail(&true);
+    let result = client.try_verify_quorum(&seals, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::InvalidProof);
+
+    client.revoke_image_id(&image_id, &roles.guardian);
+    let result = client.try_verify_quorum(&seals, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::ClaimRevoked);
+}
+
+#[test]
+fn test_quorum_blocks_single_seal_verification() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, _, verifier_a, _) = setup_two_verifiers(&env, &roles, &client);
+    let seal = create_seal_with_selector(&env, &selector_a);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let other_image_id = BytesN::from_array(&env, &[0xBB; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    client.set_quorum(&image_id, &2);
+
+    let result = client.try_verify(&seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::QuorumRequired);
+    let result = client.try_verify_with(&verifier_a, &seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::QuorumRequired);
+    let result = client.try_verify_returning_digest(&seal, &image_id, &journal_digest);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::QuorumRequired);
+    let result = client.try_verify_with_exit_code(&seal, &image_id, &journal_digest, &1);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::QuorumRequired);
+    let result = client.try_verify_conditional(&seal, &image_id, &journal_digest, &vec![&env]);
+    assert_eq!(unwrap_verifier_error(result), VerifierError::QuorumRequired);
+
+    // The quorum is per image id, and a quorum of 1 disables it.
+    client.verify(&seal, &other_image_id, &journal_digest);
+    client.verify_quorum(&vec![&env, seal.clone()], &other_image_id, &journal_digest);
+    client.set_quorum(&image_id, &1);
+    assert_eq!(client.quorum(&image_id), 1);
+    client.verify(&seal, &image_id, &journal_digest);
+}
+
+// =============================================================================
+// Selector Sunset Tests
+// =============================================================================
+
+#[test]
+fn test_selector_sunset_refuses_selector() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, selector_b, verifier_a, _) = setup_two_verifiers(&env, &roles, &client);
+    let seal_a = create_seal_with_selector(&env, &selector_a);
+    let seal_b = create_seal_with_selector(&env, &selector_b);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+    let sunset_ledger = env.ledger().sequence() + 10;
+    assert_eq!(client.selector_sunset(&selector_a), None);
+
+    client.set_selector_sunset(&selector_a, &Some(sunset_ledger));
+
+    assert_eq!(client.selector_sunset(&selector_a), Some(sunset_ledger));
+    env.ledger().set_sequence_number(sunset_ledger - 1);
+    client.verify(&seal_a, &image_id, &journal_digest);
+
+    env.ledger().set_sequence_number(sunset_ledger);
+    let result = client.try_verify(&seal_a, &image_id, &journal_digest);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorExpired
+    );
+    let result = client.try_verify_with(&verifier_a, &seal_a, &image_id, &journal_digest);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorExpired
+    );
+    let receipt = Receipt::from_execution(
+        &env,
+        seal_a.clone(),
+        image_id.clone(),
+        journal_digest.clone(),
+    );
+    let result = client.try_verify_integrity(&receipt);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorExpired
+    );
+    let result = client.try_healthcheck(&selector_a);
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorExpired
+    );
+
+    // Other selectors are unaffected, and the sunset can be cancelled after it passed.
+    client.verify(&seal_b, &image_id, &journal_digest);
+    client.set_selector_sunset(&selector_a, &None);
+    assert_eq!(client.selector_sunset(&selector_a), None);
+    client.verify(&seal_a, &image_id, &journal_digest);
+}
+
+#[test]
+fn test_set_selector_sunset_requires_active_verifier() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, _, _, _) = setup_two_verifiers(&env, &roles, &client);
+
+    let unknown = create_selector(&env, [0xEE; 4]);
+    let result = client.try_set_selector_sunset(&unknown, &Some(100));
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorUnknown
+    );
+
+    // Removing the verifier drops its sunset.
+    client.set_selector_sunset(&selector_a, &Some(100));
+    client.remove_verifier(&selector_a, &roles.guardian);
+    assert_eq!(client.selector_sunset(&selector_a), None);
+    let result = client.try_set_selector_sunset(&selector_a, &Some(100));
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::SelectorRemoved
+    );
+}
+
+// =============================================================================
+// Role Authorization Tests
+// =============================================================================
+
+#[test]
+#[should_panic]
+fn test_add_verifier_requires_operator_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (roles, client) = register_router(&env);
+    env.set_auths(&[]);
+
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    let verifier = Address::generate(&env);
+
+    // Should trap on operator.require_auth().
+    client.add_verifier(&selector, &verifier, &roles.operator);
+}
+
+#[test]
+#[should_panic]
+fn test_add_verifier_requires_operator_role() {
+    let (env, roles, client) = setup_env();
+
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    let verifier = Address::generate(&env);
+
+    // Neither the admin nor the guardian can register verifiers.
+    client.add_verifier(&selector, &verifier, &roles.guardian);
+}
+
+#[test]
+#[should_panic]
+fn test_verify_as_requires_caller_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (roles, client) = register_router(&env);
+    let (_, seal) = setup_recording_verifier(&env, &roles, &client);
+    env.set_auths(&[]);
+
+    let caller = Address::generate(&env);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+
+    // Should trap on caller.require_auth().
+    client.verify_as(&caller, &seal, &image_id, &journal_digest);
+}
+
+#[test]
+#[should_panic]
+fn test_set_rate_limit_requires_operator_role() {
+    let (_env, roles, client) = setup_env();
+
+    client.set_rate_limit(&Some(create_rate_limit()), &roles.guardian);
+}
+
+#[test]
+#[should_panic]
+fn test_report_verification_requires_operator_role() {
+    let (env, roles, client) = setup_env();
+    let (_, seal) = setup_recording_verifier(&env, &roles, &client);
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+    let journal_digest = BytesN::from_array(&env, &[0x01; 32]);
+
+    client.report_verification(&seal, &image_id, &journal_digest, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic]
+fn test_resume_selector_requires_guardian_role() {
+    let (env, roles, client) = setup_env();
+
+    client.resume_selector(&create_selector(&env, [0x01; 4]), &roles.operator);
+}
+
+#[test]
+#[should_panic]
+fn test_finalize_registry_requires_admin_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_roles, client) = register_router(&env);
+    env.set_auths(&[]);
+
+    // Should trap on admin.require_auth().
+    client.finalize_registry();
+}
+
+#[test]
+#[should_panic]
+fn test_set_image_registry_requires_admin_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_roles, client) = register_router(&env);
+    env.set_auths(&[]);
+
+    // Should trap on admin.require_auth().
+    client.set_image_registry(&Some(Address::generate(&env)));
+}
+
+#[test]
+#[should_panic]
+fn test_set_quorum_requires_admin_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_roles, client) = register_router(&env);
+    env.set_auths(&[]);
+
+    // Should trap on admin.require_auth().
+    client.set_quorum(&BytesN::from_array(&env, &[0xAA; 32]), &2);
+}
+
+#[test]
+#[should_panic]
+fn test_set_selector_sunset_requires_admin_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (roles, client) = register_router(&env);
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    client.add_verifier(&selector, &Address::generate(&env), &roles.operator);
+    env.set_auths(&[]);
+
+    // Should trap on admin.require_auth().
+    client.set_selector_sunset(&selector, &Some(100));
+}
+
+#[test]
+#[should_panic]
+fn test_import_registry_requires_admin_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_roles, client) = register_router(&env);
+    env.set_auths(&[]);
+
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    let verifier = Address::generate(&env);
+
+    // Should trap on admin.require_auth().
+    client.import_registry(&vec![&env, (selector, VerifierEntry::Active(verifier))]);
+}
+
+#[test]
+#[should_panic]
+fn test_remove_verifier_requires_guardian_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (roles, client) = register_router(&env);
+    env.set_auths(&[]);
+
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    let verifier = Address::generate(&env);
+
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DataKey::Verifier(selector.clone()),
+            &VerifierEntry::Active(verifier),
+        );
+    });
+
+    // Should trap on guardian.require_auth().
+    client.remove_verifier(&selector, &roles.guardian);
+}
+
+#[test]
+#[should_panic]
+fn test_remove_verifier_requires_guardian_role() {
+    let (env, roles, client) = setup_env();
+
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    client.add_verifier(&selector, &Address::generate(&env), &roles.operator);
+
+    client.remove_verifier(&selector, &roles.operator);
+}
+
+#[test]
+#[should_panic]
+fn test_set_cost_estimate_requires_operator_role() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, _, _, _) = setup_two_verifiers(&env, &roles, &client);
+
+    client.set_cost_estimate(&selector_a, &create_cost_estimate(), &roles.guardian);
+}
+
+#[test]
+fn test_admin_grants_operator_role() {
+    let (env, roles, client) = setup_env();
+    let operator = Address::generate(&env);
+
+    client.grant_role(&operator, &OPERATOR_ROLE, &roles.admin);
+    assert!(client.has_role(&operator, &OPERATOR_ROLE).is_some());
+
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    let verifier = Address::generate(&env);
+    client.add_verifier(&selector, &verifier, &operator);
+    assert_eq!(client.get_verifier_by_selector(&selector), verifier);
+}
+
+#[test]
+#[should_panic]
+fn test_operator_cannot_grant_roles() {
+    let (env, roles, client) = setup_env();
+
+    client.grant_role(&Address::generate(&env), &GUARDIAN_ROLE, &roles.operator);
+}
+
+// =============================================================================
+// Upgrade Tests
+// =============================================================================
+
+#[test]
+fn test_constructor_sets_contract_version() {
+    let (_env, _roles, client) = setup_env();
+
+    assert_eq!(client.contract_version(), 1);
+}
+
+#[test]
+fn test_upgrade_rejects_non_increasing_version() {
+    let (env, _roles, client) = setup_env();
+    let wasm_hash = BytesN::from_array(&env, &[0xAB; 32]);
+
+    for version in [0, 1] {
+        let result = client.try_upgrade(&wasm_hash, &version);
+        assert_eq!(
+            unwrap_verifier_error(result),
+            VerifierError::VersionNotIncreasing
+        );
+    }
+    assert_eq!(client.contract_version(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_upgrade_requires_admin_auth() {
+    let env = Env::default();
+    let (_roles, client) = register_router(&env);
+
+    client.upgrade(&BytesN::from_array(&env, &[0xAB; 32]), &2);
+}
+
+#[test]
+fn test_migrate_up_to_date_storage_is_noop() {
+    let (env, _roles, client) = setup_env();
+    assert_eq!(client.schema_version(), STORAGE_SCHEMA_VERSION);
+
+    client.migrate();
+
+    assert_eq!(client.schema_version(), STORAGE_SCHEMA_VERSION);
+    assert!(env.events().all().events().is_empty());
+}
+
+#[test]
+fn test_migrate_unversioned_storage() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, _, verifier_a, _) = setup_two_verifiers(&env, &roles, &client);
+    env.as_contract(&client.address, || upgrade::set_schema_version(&env, 0));
+
+    client.migrate();
+
+    let event = upgrade::Migrated {
+        from: 0,
+        to: STORAGE_SCHEMA_VERSION,
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+    assert_eq!(client.schema_version(), STORAGE_SCHEMA_VERSION);
+    assert_eq!(client.get_verifier_by_selector(&selector_a), verifier_a);
+}
+
+#[test]
+fn test_migrate_rejects_newer_schema() {
+    let (env, _roles, client) = setup_env();
+    env.as_contract(&client.address, || {
+        upgrade::set_schema_version(&env, STORAGE_SCHEMA_VERSION + 1);
+    });
+
+    let result = client.try_migrate();
+    assert_eq!(
+        unwrap_verifier_error(result),
+        VerifierError::UnsupportedSchemaVersion
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_migrate_requires_admin_auth() {
+    let env = Env::default();
+    let (_roles, client) = register_router(&env);
+
+    client.migrate();
+}
+
+// =============================================================================
+// Event Tests
+// =============================================================================
+
+#[test]
+fn test_add_verifier_emits_event() {
+    let (env, roles, client) = setup_env();
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    let verifier = Address::generate(&env);
+
+    client.add_verifier(&selector, &verifier, &roles.operator);
+
+    let event = VerifierAdded { selector, verifier };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_remove_verifier_emits_event_with_old_verifier() {
+    let (env, roles, client) = setup_env();
+    let selector = create_selector(&env, [0x01, 0x02, 0x03, 0x04]);
+    let verifier = Address::generate(&env);
+    client.add_verifier(&selector, &verifier, &roles.operator);
+
+    client.remove_verifier(&selector, &roles.guardian);
+
+    let event = VerifierRemoved {
+        selector,
+        verifier: Some(verifier),
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_revocations_emit_events() {
+    let (env, roles, client) = setup_env();
+    let digest = BytesN::from_array(&env, &[0x42; 32]);
+
+    client.revoke_claim(&digest, &roles.guardian);
+    let event = ClaimRevoked {
+        claim_digest: digest.clone(),
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+
+    client.revoke_image_id(&digest, &roles.guardian);
+    let event = ImageIdRevoked { image_id: digest };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_set_consumer_config_emits_event() {
+    let (env, _roles, client) = setup_env();
+    let consumer = Address::generate(&env);
+    let config = ConsumerConfig {
+        journal_prefix: Bytes::from_array(&env, &JOURNAL_PREFIX),
+    };
+
+    client.set_consumer_config(&consumer, &config);
+
+    let event = ConsumerConfigured { consumer, config };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_set_rate_limit_emits_event() {
+    let (env, roles, client) = setup_env();
+    let limit = Some(create_rate_limit());
+
+    client.set_rate_limit(&limit, &roles.operator);
+
+    let event = RateLimitSet { limit };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_set_circuit_breaker_emits_event() {
+    let (env, roles, client) = setup_env();
+    let breaker = Some(create_circuit_breaker());
+
+    client.set_circuit_breaker(&breaker, &roles.operator);
+
+    let event = CircuitBreakerSet {
+        breaker: breaker.clone(),
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+    assert_eq!(client.circuit_breaker(), breaker);
+}
+
+#[test]
+fn test_finalize_registry_emits_event() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, selector_b, _, _) = setup_two_verifiers(&env, &roles, &client);
+
+    client.finalize_registry();
+
+    let event = RegistryFinalized {
+        selectors: vec![&env, selector_a, selector_b],
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_set_image_registry_emits_event() {
+    let (env, _roles, client) = setup_env();
+    let registry = Some(Address::generate(&env));
+
+    client.set_image_registry(&registry);
+
+    let event = ImageRegistrySet { registry };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_set_cost_estimate_emits_event() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, _, _, _) = setup_two_verifiers(&env, &roles, &client);
+    let estimate = create_cost_estimate();
+
+    client.set_cost_estimate(&selector_a, &estimate, &roles.operator);
+
+    let event = CostEstimateSet {
+        selector: selector_a,
+        estimate,
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_set_quorum_emits_event() {
+    let (env, _roles, client) = setup_env();
+    let image_id = BytesN::from_array(&env, &[0xAA; 32]);
+
+    client.set_quorum(&image_id, &0);
+
+    let event = QuorumSet {
+        image_id,
+        quorum: 1,
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_set_selector_sunset_emits_event() {
+    let (env, roles, client) = setup_env();
+    let (selector_a, _, _, _) = setup_two_verifiers(&env, &roles, &client);
+
+    client.set_selector_sunset(&selector_a, &Some(100));
+
+    let event = SelectorSunsetSet {
+        selector: selector_a,
+        sunset_ledger: Some(100),
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
 }
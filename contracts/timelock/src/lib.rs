@@ -159,11 +159,15 @@ pub struct OperationMeta {
     pub executor: Option<Address>,
 }
 
+/// Errors returned by the timelock controller (registry range `600..=699`).
+///
+/// `BatchLengthMismatch` moved from `5000` when the registry was introduced; see the error codes
+/// in `risc0-interface`.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum TimelockControllerError {
-    BatchLengthMismatch = 5000,
+    BatchLengthMismatch = 600,
 }
 
 /// Event emitted when a scheduled operation uses a non-zero salt.
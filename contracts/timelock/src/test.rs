@@ -7,7 +7,7 @@ use soroban_sdk::{
 };
 use stellar_governance::timelock::TimelockError;
 
-use crate::{OperationMeta, TimelockController, TimelockControllerClient};
+use crate::{OperationMeta, TimelockController, TimelockControllerClient, TimelockControllerError};
 
 // A simple target contract for testing timelock operations
 mod target_contract {
@@ -1248,7 +1248,7 @@ fn test_batch_id_can_be_used_as_predecessor() {
 }
 
 #[test]
-#[should_panic(expected = "#5000")]
+#[should_panic(expected = "#600")]
 fn test_schedule_batch_length_mismatch() {
     let e = Env::default();
     e.mock_all_auths();
@@ -1508,3 +1508,13 @@ fn test_get_timestamp() {
     let ready_timestamp = timelock.get_timestamp(&op_id);
     assert_eq!(ready_timestamp, initial_timestamp + u64::from(delay));
 }
+
+#[test]
+fn test_error_codes_are_stable() {
+    // Part of the public ABI, see the error registry in `risc0-interface`.
+    assert_eq!(TimelockControllerError::BatchLengthMismatch as u32, 600);
+
+    // The code used before the registry, which must never be reassigned.
+    let error = soroban_sdk::Error::from_contract_error(5000);
+    assert!(TimelockControllerError::try_from(error).is_err());
+}
@@ -0,0 +1,90 @@
+//! # RISC Zero System Constants
+//!
+//! Protocol constants shared by the contracts (through `risc0-digest` and `risc0-interface`)
+//! and the build scripts (through `build-utils`). Digests only match the prover's if every
+//! component hashes with the same tags, so each tag is defined once here instead of being
+//! spelled out at every use site.
+//!
+//! Tag digests are pre-computed so that contracts do not hash the tag strings at runtime. The
+//! tests check each of them against its tag string.
+
+#![no_std]
+
+/// The size of a SHA-256 digest in bytes.
+pub const DIGEST_SIZE: usize = 32;
+
+/// The size in bytes of the verifier selector prefixing every seal.
+pub const SELECTOR_SIZE: usize = 4;
+
+/// Tag of the `Output` struct of a receipt claim.
+pub const OUTPUT_TAG: &str = "risc0.Output";
+
+/// Tag of the `ReceiptClaim` struct.
+pub const RECEIPT_CLAIM_TAG: &str = "risc0.ReceiptClaim";
+
+/// Tag of an `Assumption` struct.
+pub const ASSUMPTION_TAG: &str = "risc0.Assumption";
+
+/// Tag of the list of assumptions of a receipt claim.
+pub const ASSUMPTIONS_TAG: &str = "risc0.Assumptions";
+
+/// Tag of the Groth16 verifying key struct.
+pub const GROTH16_VERIFYING_KEY_TAG: &str = "risc0_groth16.VerifyingKey";
+
+/// Tag of the list of `IC` points of the Groth16 verifying key.
+pub const GROTH16_VERIFYING_KEY_IC_TAG: &str = "risc0_groth16.VerifyingKey.IC";
+
+/// Tag of the Groth16 receipt verifier parameters, whose digest prefix is the selector.
+pub const GROTH16_RECEIPT_VERIFIER_PARAMETERS_TAG: &str = "risc0.Groth16ReceiptVerifierParameters";
+
+/// Pre-computed SHA-256([`OUTPUT_TAG`]) tag digest.
+pub const OUTPUT_TAG_DIGEST: [u8; DIGEST_SIZE] = [
+    0x77, 0xea, 0xfe, 0xb3, 0x66, 0xa7, 0x8b, 0x47, 0x74, 0x7d, 0xe0, 0xd7, 0xbb, 0x17, 0x62, 0x84,
+    0x08, 0x5f, 0xf5, 0x56, 0x48, 0x87, 0x00, 0x9a, 0x5b, 0xe6, 0x3d, 0xa3, 0x2d, 0x35, 0x59, 0xd4,
+];
+
+/// Pre-computed SHA-256([`RECEIPT_CLAIM_TAG`]) tag digest.
+pub const RECEIPT_CLAIM_TAG_DIGEST: [u8; DIGEST_SIZE] = [
+    0xcb, 0x1f, 0xef, 0xcd, 0x1f, 0x2d, 0x9a, 0x64, 0x97, 0x5c, 0xbb, 0xbf, 0x6e, 0x16, 0x1e, 0x29,
+    0x14, 0x43, 0x4b, 0x0c, 0xbb, 0x99, 0x60, 0xb8, 0x4d, 0xf5, 0xd7, 0x17, 0xe8, 0x6b, 0x48, 0xaf,
+];
+
+/// Pre-computed SHA-256([`ASSUMPTION_TAG`]) tag digest.
+pub const ASSUMPTION_TAG_DIGEST: [u8; DIGEST_SIZE] = [
+    0x9f, 0xb5, 0x24, 0xf6, 0x5d, 0x5d, 0xe5, 0x3c, 0xe0, 0xb5, 0xdf, 0xeb, 0x62, 0xfd, 0x58, 0x66,
+    0x78, 0x67, 0x6f, 0x67, 0xa2, 0x2f, 0x58, 0xb0, 0x71, 0xc4, 0x8a, 0x46, 0x50, 0x5a, 0x2e, 0xe8,
+];
+
+/// Pre-computed SHA-256([`ASSUMPTIONS_TAG`]) tag digest.
+pub const ASSUMPTIONS_TAG_DIGEST: [u8; DIGEST_SIZE] = [
+    0x8e, 0x37, 0x8d, 0x42, 0x56, 0xf0, 0x78, 0x98, 0xdf, 0x0b, 0xb8, 0x91, 0x2f, 0x5d, 0xa8, 0x0f,
+    0x8e, 0x78, 0x44, 0x8c, 0x2a, 0x7b, 0x32, 0x1f, 0x92, 0x32, 0xe2, 0x11, 0x24, 0x18, 0x68, 0x39,
+];
+
+/// Fixed post-state digest for a halted execution.
+///
+/// This is a protocol constant used in standard successful receipt claims.
+pub const POST_STATE_DIGEST_HALTED: [u8; DIGEST_SIZE] = [
+    0xa3, 0xac, 0xc2, 0x71, 0x17, 0x41, 0x89, 0x96, 0x34, 0x0b, 0x84, 0xe5, 0xa9, 0x0f, 0x3e, 0xf4,
+    0xc4, 0x9d, 0x22, 0xc7, 0x9e, 0x44, 0xaa, 0xd8, 0x22, 0xec, 0x9c, 0x31, 0x3e, 0x1e, 0xb8, 0xe2,
+];
+
+#[cfg(test)]
+mod tests {
+    use sha2::{Digest as _, Sha256};
+
+    use super::{
+        ASSUMPTION_TAG, ASSUMPTION_TAG_DIGEST, ASSUMPTIONS_TAG, ASSUMPTIONS_TAG_DIGEST, OUTPUT_TAG,
+        OUTPUT_TAG_DIGEST, RECEIPT_CLAIM_TAG, RECEIPT_CLAIM_TAG_DIGEST,
+    };
+
+    #[test]
+    fn test_tag_digests() {
+        let tag = |tag: &str| -> [u8; 32] { Sha256::digest(tag.as_bytes()).into() };
+
+        assert_eq!(OUTPUT_TAG_DIGEST, tag(OUTPUT_TAG));
+        assert_eq!(RECEIPT_CLAIM_TAG_DIGEST, tag(RECEIPT_CLAIM_TAG));
+        assert_eq!(ASSUMPTION_TAG_DIGEST, tag(ASSUMPTION_TAG));
+        assert_eq!(ASSUMPTIONS_TAG_DIGEST, tag(ASSUMPTIONS_TAG));
+    }
+}
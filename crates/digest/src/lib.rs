@@ -0,0 +1,331 @@
+//! # RISC Zero Digest Core
+//!
+//! Pure `no_std` implementation of the RISC Zero tagged hashing scheme and the claim digests
+//! built on top of it. The crate has no Soroban dependency, so the exact same code can run:
+//!
+//! - inside Soroban contracts, through a thin adapter hashing with the host SHA-256 (see
+//!   `risc0-interface`)
+//! - inside RISC Zero guest programs (enable the `sha2` feature), for recursive or
+//!   compositional designs that need to compute claim digests in the zkVM
+//!
+//! ## Hash Construction
+//!
+//! A tagged struct is hashed as:
+//!
+//! ```text
+//! SHA-256(tag_digest || down[0] || ... || down[n-1] || data[0] || ... || data[m-1] || n)
+//! ```
+//!
+//! Where `tag_digest` is `SHA-256(tag)`, `down` are the digests of the nested fields, `data`
+//! are little-endian `u32` words, and `n` is encoded as a little-endian `u16`.
+
+#![no_std]
+// The `panic-audit` feature denies panicking calls outside of tests, as in the contracts
+// embedding this crate. A panic that is kept must be allowed at its call site, with the reason
+// it cannot be reached.
+#![cfg_attr(
+    all(feature = "panic-audit", not(test)),
+    deny(
+        clippy::expect_used,
+        clippy::indexing_slicing,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented,
+        clippy::unreachable,
+        clippy::unwrap_used
+    )
+)]
+
+pub use risc0_constants::{
+    ASSUMPTION_TAG_DIGEST, ASSUMPTIONS_TAG_DIGEST, DIGEST_SIZE, OUTPUT_TAG_DIGEST,
+    POST_STATE_DIGEST_HALTED, RECEIPT_CLAIM_TAG_DIGEST,
+};
+
+/// A 32-byte SHA-256 digest.
+pub type Digest = [u8; DIGEST_SIZE];
+
+/// The all-zero digest, used for empty lists and unused fields.
+pub const ZERO_DIGEST: Digest = [0u8; DIGEST_SIZE];
+
+/// Incremental SHA-256 hasher used by the digest functions.
+///
+/// Implementations only need to produce a standard SHA-256 over the concatenation of all
+/// `update` calls. Contracts can buffer the data and hash it with the host function once in
+/// `finalize`, while guests can use a streaming implementation.
+pub trait Sha256 {
+    /// Appends `data` to the hashed message.
+    fn update(&mut self, data: &[u8]);
+
+    /// Consumes the hasher and returns the digest of the message.
+    fn finalize(self) -> Digest;
+}
+
+#[cfg(feature = "sha2")]
+impl Sha256 for sha2::Sha256 {
+    fn update(&mut self, data: &[u8]) {
+        sha2::Digest::update(self, data);
+    }
+
+    fn finalize(self) -> Digest {
+        sha2::Digest::finalize(self).into()
+    }
+}
+
+/// Hashes a tagged struct with the given tag digest, nested field digests and data words.
+///
+/// # Panics
+///
+/// Panics if the number of fields exceeds 65535 (2^16 - 1)
+pub fn tagged_struct<H: Sha256>(
+    mut hasher: H,
+    tag_digest: &Digest,
+    down: &[Digest],
+    data: &[u32],
+) -> Digest {
+    hasher.update(tag_digest);
+    for digest in down {
+        hasher.update(digest);
+    }
+    for word in data {
+        hasher.update(&word.to_le_bytes());
+    }
+    #[allow(
+        clippy::expect_used,
+        reason = "the field count is fixed by the struct definition, at most 4 in RISC Zero"
+    )]
+    let down_count: u16 = down
+        .len()
+        .try_into()
+        .expect("struct defined with more than 2^16 fields");
+    hasher.update(&down_count.to_le_bytes());
+    hasher.finalize()
+}
+
+/// Hashes a cons cell of a tagged list: a struct with the `head` element and the `tail` list.
+pub fn tagged_list_cons<H: Sha256>(
+    hasher: H,
+    tag_digest: &Digest,
+    head: &Digest,
+    tail: &Digest,
+) -> Digest {
+    tagged_struct(hasher, tag_digest, &[*head, *tail], &[])
+}
+
+/// Computes the digest of a RISC Zero `Output` struct.
+///
+/// ```text
+/// SHA-256(SHA-256("risc0.Output") || journal_digest || assumptions_digest || 2u16)
+/// ```
+pub fn output_digest<H: Sha256>(
+    hasher: H,
+    journal_digest: &Digest,
+    assumptions_digest: &Digest,
+) -> Digest {
+    tagged_struct(
+        hasher,
+        &OUTPUT_TAG_DIGEST,
+        &[*journal_digest, *assumptions_digest],
+        &[],
+    )
+}
+
+/// Computes the digest of a RISC Zero `Assumption` struct.
+///
+/// ```text
+/// SHA-256(SHA-256("risc0.Assumption") || claim_digest || control_root || 2u16)
+/// ```
+///
+/// A zero `control_root` means the assumption must be resolved by a receipt verified against
+/// the same control root as the conditional receipt.
+pub fn assumption_digest<H: Sha256>(
+    hasher: H,
+    claim_digest: &Digest,
+    control_root: &Digest,
+) -> Digest {
+    tagged_struct(
+        hasher,
+        &ASSUMPTION_TAG_DIGEST,
+        &[*claim_digest, *control_root],
+        &[],
+    )
+}
+
+/// Computes the digest of a RISC Zero `Assumptions` list from its assumption digests.
+///
+/// The list is hashed as a cons list, starting from the last element with the zero digest as
+/// the tail. An empty list hashes to [`ZERO_DIGEST`], the assumptions digest of an
+/// unconditional receipt.
+pub fn assumptions_digest<H: Sha256>(
+    mut new_hasher: impl FnMut() -> H,
+    assumptions: impl DoubleEndedIterator<Item = Digest>,
+) -> Digest {
+    assumptions.rev().fold(ZERO_DIGEST, |tail, head| {
+        tagged_list_cons(new_hasher(), &ASSUMPTIONS_TAG_DIGEST, &head, &tail)
+    })
+}
+
+/// Computes the digest of a RISC Zero `ReceiptClaim` struct.
+///
+/// ```text
+/// SHA-256(
+///     SHA-256("risc0.ReceiptClaim") ||
+///     input || pre_state_digest || post_state_digest || output ||
+///     system_exit || user_exit ||
+///     4u16
+/// )
+/// ```
+///
+/// Exit codes are encoded as full little-endian `u32` words, matching the RISC Zero reference
+/// implementation. For codes below 256 this is the same preimage as the `code << 24`
+/// big-endian encoding of the Solidity verifier.
+pub fn receipt_claim_digest<H: Sha256>(
+    hasher: H,
+    input: &Digest,
+    pre_state_digest: &Digest,
+    post_state_digest: &Digest,
+    output: &Digest,
+    system_exit: u32,
+    user_exit: u32,
+) -> Digest {
+    tagged_struct(
+        hasher,
+        &RECEIPT_CLAIM_TAG_DIGEST,
+        &[*input, *pre_state_digest, *post_state_digest, *output],
+        &[system_exit, user_exit],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ASSUMPTIONS_TAG_DIGEST, Digest, OUTPUT_TAG_DIGEST, RECEIPT_CLAIM_TAG_DIGEST, ZERO_DIGEST,
+        assumption_digest, assumptions_digest, output_digest, receipt_claim_digest, tagged_struct,
+    };
+
+    /// `sha2` hasher for the tests, which must not depend on the `sha2` feature being enabled.
+    #[derive(Default)]
+    struct Sha256(sha2::Sha256);
+
+    impl Sha256 {
+        fn new() -> Self {
+            Self::default()
+        }
+
+        fn digest(data: impl AsRef<[u8]>) -> Digest {
+            <sha2::Sha256 as sha2::Digest>::digest(data).into()
+        }
+    }
+
+    impl super::Sha256 for Sha256 {
+        fn update(&mut self, data: &[u8]) {
+            sha2::Digest::update(&mut self.0, data);
+        }
+
+        fn finalize(self) -> Digest {
+            sha2::Digest::finalize(self.0).into()
+        }
+    }
+
+    fn tag(tag: &str) -> [u8; 32] {
+        Sha256::digest(tag)
+    }
+
+    #[test]
+    fn test_tagged_struct() {
+        // Same vector as `build-utils`, computed with the host-side implementation.
+        let digest1 = tagged_struct(Sha256::new(), &tag("foo"), &[], &[]);
+        let digest2 = tagged_struct(Sha256::new(), &tag("bar"), &[digest1, digest1], &[]);
+        let digest3 = tagged_struct(
+            Sha256::new(),
+            &tag("baz"),
+            &[digest1, digest2, digest1],
+            &[],
+        );
+
+        assert_eq!(
+            hex::encode(digest3),
+            "2228eb06bfbeaeb2cc12de86fd13373cb5ccdc8afac9af4299dd5a86a72afc4b"
+        );
+    }
+
+    #[test]
+    fn test_output_digest_preimage() {
+        let journal = [0x11; 32];
+        let mut preimage = [0u8; 98];
+        preimage[0..32].copy_from_slice(&OUTPUT_TAG_DIGEST);
+        preimage[32..64].copy_from_slice(&journal);
+        preimage[96] = 0x02;
+
+        assert_eq!(
+            output_digest(Sha256::new(), &journal, &ZERO_DIGEST),
+            Sha256::digest(preimage)
+        );
+    }
+
+    #[test]
+    fn test_assumptions_digest_cons_list() {
+        let a = assumption_digest(Sha256::new(), &[0x01; 32], &ZERO_DIGEST);
+        let b = assumption_digest(Sha256::new(), &[0x02; 32], &ZERO_DIGEST);
+
+        let tail = tagged_struct(
+            Sha256::new(),
+            &ASSUMPTIONS_TAG_DIGEST,
+            &[b, ZERO_DIGEST],
+            &[],
+        );
+        let expected = tagged_struct(Sha256::new(), &ASSUMPTIONS_TAG_DIGEST, &[a, tail], &[]);
+
+        assert_eq!(
+            assumptions_digest(Sha256::new, [a, b].into_iter()),
+            expected
+        );
+        assert_eq!(
+            assumptions_digest(Sha256::new, core::iter::empty()),
+            ZERO_DIGEST
+        );
+    }
+
+    #[test]
+    fn test_receipt_claim_digest_exit_code_encoding() {
+        let mut preimage = [0u8; 170];
+        preimage[0..32].copy_from_slice(&RECEIPT_CLAIM_TAG_DIGEST);
+        preimage[160] = 0x02; // system exit code
+        preimage[164] = 0x05; // user exit code
+        preimage[168] = 0x04;
+
+        assert_eq!(
+            receipt_claim_digest(
+                Sha256::new(),
+                &ZERO_DIGEST,
+                &ZERO_DIGEST,
+                &ZERO_DIGEST,
+                &ZERO_DIGEST,
+                2,
+                5
+            ),
+            Sha256::digest(preimage)
+        );
+    }
+
+    #[test]
+    fn test_receipt_claim_digest_commits_full_user_exit_code() {
+        let mut preimage = [0u8; 170];
+        preimage[0..32].copy_from_slice(&RECEIPT_CLAIM_TAG_DIGEST);
+        preimage[164..168].copy_from_slice(&[0x01, 0x01, 0x00, 0x00]); // user exit code 257
+        preimage[168] = 0x04;
+
+        let digest = |user_exit| {
+            receipt_claim_digest(
+                Sha256::new(),
+                &ZERO_DIGEST,
+                &ZERO_DIGEST,
+                &ZERO_DIGEST,
+                &ZERO_DIGEST,
+                0,
+                user_exit,
+            )
+        };
+        assert_eq!(digest(257), Sha256::digest(preimage));
+        assert_ne!(digest(257), digest(1));
+    }
+}
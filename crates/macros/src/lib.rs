@@ -0,0 +1,202 @@
+//! # RISC Zero Consumer Macros
+//!
+//! Attribute macros for application contracts consuming RISC Zero proofs.
+//!
+//! [`macro@require_risc0_proof`] generates the verification preamble of an entrypoint taking a
+//! seal and a raw journal: it verifies the seal against the journal through the verifier (or
+//! router), then hands the body the decoded journal. Entrypoints written with it cannot skip
+//! or discard the verification, nor read a journal other than the one proven.
+//!
+//! Generated code refers to `risc0_interface` and `soroban_sdk`, which the contract must depend
+//! on.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    Error, Expr, FnArg, Ident, ItemFn, Pat, Result, Token, Type,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+};
+
+/// Requires a valid RISC Zero proof of the journal before running the entrypoint.
+///
+/// ```ignore
+/// #[contractimpl]
+/// impl Consumer {
+///     #[require_risc0_proof(
+///         verifier = config(&env).verifier,
+///         image_id = config(&env).image_id,
+///         decode = (BytesN<32>, u64),
+///     )]
+///     pub fn consume(env: Env, seal: Bytes, journal: Bytes) -> u64 {
+///         let (commitment, amount) = journal;
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// Before the body runs, the generated code:
+///
+/// 1. Verifies that `seal` proves a successful execution of `image_id` that committed exactly
+///    `journal`, with `RiscZeroVerifierInterface::verify` on the `verifier` contract. A failure
+///    aborts the invocation with the verifier's error.
+/// 2. If `decode` is given, decodes the journal as that type with `journal::decode` and shadows
+///    the journal parameter with it. A journal that is not the encoding of the type aborts the
+///    invocation with `VerifierError::MalformedJournal`: it was proven, so the guest and the
+///    contract disagree on its layout.
+///
+/// # Arguments
+///
+/// - `verifier`: Expression evaluating to the `Address` of the verifier or router
+/// - `image_id`: Expression evaluating to the `BytesN<32>` image id of the guest
+/// - `decode` (optional): Type of the value the guest commits, implementing `JournalDecode`
+/// - `seal` (optional): Name of the `Bytes` seal parameter, `seal` by default
+/// - `journal` (optional): Name of the `Bytes` journal parameter, `journal` by default
+///
+/// `verifier` and `image_id` are evaluated before the body, and may only use the parameters.
+/// The entrypoint must take an `Env` parameter.
+#[proc_macro_attribute]
+pub fn require_risc0_proof(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as Args);
+    let item = parse_macro_input!(item as ItemFn);
+    expand(args, item)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Arguments of [`macro@require_risc0_proof`].
+struct Args {
+    verifier: Expr,
+    image_id: Expr,
+    decode: Option<Type>,
+    seal: Ident,
+    journal: Ident,
+}
+
+/// A single `name = value` argument.
+enum Arg {
+    Verifier(Expr),
+    ImageId(Expr),
+    Decode(Type),
+    Seal(Ident),
+    Journal(Ident),
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        match name.to_string().as_str() {
+            "verifier" => input.parse().map(Arg::Verifier),
+            "image_id" => input.parse().map(Arg::ImageId),
+            "decode" => input.parse().map(Arg::Decode),
+            "seal" => input.parse().map(Arg::Seal),
+            "journal" => input.parse().map(Arg::Journal),
+            _ => Err(Error::new(
+                name.span(),
+                "expected `verifier`, `image_id`, `decode`, `seal` or `journal`",
+            )),
+        }
+    }
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let (mut verifier, mut image_id, mut decode, mut seal, mut journal) =
+            (None, None, None, None, None);
+        for arg in Punctuated::<Arg, Token![,]>::parse_terminated(input)? {
+            match arg {
+                Arg::Verifier(expr) => verifier = Some(expr),
+                Arg::ImageId(expr) => image_id = Some(expr),
+                Arg::Decode(ty) => decode = Some(ty),
+                Arg::Seal(ident) => seal = Some(ident),
+                Arg::Journal(ident) => journal = Some(ident),
+            }
+        }
+        Ok(Args {
+            verifier: verifier.ok_or_else(|| input.error("missing `verifier = ...`"))?,
+            image_id: image_id.ok_or_else(|| input.error("missing `image_id = ...`"))?,
+            decode,
+            seal: seal.unwrap_or_else(|| Ident::new("seal", Span::call_site())),
+            journal: journal.unwrap_or_else(|| Ident::new("journal", Span::call_site())),
+        })
+    }
+}
+
+fn expand(args: Args, item: ItemFn) -> Result<TokenStream2> {
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = item;
+    let env = find_env(&sig)?;
+    for param in [&args.seal, &args.journal] {
+        if !has_param(&sig, param) {
+            return Err(Error::new(
+                param.span(),
+                format!("the entrypoint has no `{param}` parameter"),
+            ));
+        }
+    }
+
+    let Args {
+        verifier,
+        image_id,
+        decode,
+        seal,
+        journal,
+    } = args;
+    let decode = decode.map(|ty| {
+        quote! {
+            let #journal: #ty = match ::risc0_interface::journal::decode(&#journal) {
+                ::core::option::Option::Some(value) => value,
+                ::core::option::Option::None => ::soroban_sdk::panic_with_error!(
+                    &#env,
+                    ::risc0_interface::VerifierError::MalformedJournal
+                ),
+            };
+        }
+    });
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis #sig {
+            ::risc0_interface::journal::verify_raw(
+                &::risc0_interface::RiscZeroVerifierClient::new(&#env, &(#verifier)),
+                &#seal,
+                &(#image_id),
+                &#journal,
+            );
+            #decode
+            #block
+        }
+    })
+}
+
+/// Returns the name of the `Env` parameter.
+fn find_env(sig: &syn::Signature) -> Result<Ident> {
+    sig.inputs
+        .iter()
+        .find_map(|input| match input {
+            FnArg::Typed(arg) => match (&*arg.pat, &*arg.ty) {
+                (Pat::Ident(pat), Type::Path(ty))
+                    if ty.path.segments.last().is_some_and(|s| s.ident == "Env") =>
+                {
+                    Some(pat.ident.clone())
+                }
+                _ => None,
+            },
+            FnArg::Receiver(_) => None,
+        })
+        .ok_or_else(|| Error::new_spanned(sig, "the entrypoint has no `Env` parameter"))
+}
+
+/// Returns whether the signature has a parameter named `name`.
+fn has_param(sig: &syn::Signature, name: &Ident) -> bool {
+    sig.inputs.iter().any(|input| {
+        matches!(input, FnArg::Typed(arg) if matches!(&*arg.pat, Pat::Ident(pat) if pat.ident == *name))
+    })
+}
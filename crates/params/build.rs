@@ -0,0 +1,152 @@
+// Generates the verifier parameters from this crate's `parameters.json`. The key and the values
+// derived from it are computed here once, at build time, so the contract and the off-chain
+// consumers of this crate embed the same bytes.
+
+use std::{env, fs, path::PathBuf};
+
+use build_utils::{
+    Sha256Digest, format_byte_array, g1_to_bytes, g2_to_bytes,
+    groth16::{VerificationKeyJson, check_selector, selector, split_control_root},
+};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+const PARAMETERS_PATH: &str = "parameters.json";
+
+#[derive(Deserialize)]
+struct VerifierParameters {
+    version: String,
+    control_root: String,
+    bn254_control_id: String,
+    /// The post-state digest of a halted execution in this zkVM release, which standard claims
+    /// commit to.
+    post_state_digest: String,
+    verification_key: VerificationKeyJson,
+    /// The selector the parameters must derive, if pinned. Catches a control root, control id
+    /// and verification key taken from different releases before the verifier is deployed.
+    #[serde(default)]
+    expected_selector: Option<String>,
+}
+
+fn decode_digest(hex: &str, name: &str) -> Sha256Digest {
+    hex::decode(hex)
+        .unwrap_or_else(|_| panic!("Invalid hex string for {name}"))
+        .try_into()
+        .unwrap_or_else(|_| panic!("{name} must be exactly 32 bytes"))
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={PARAMETERS_PATH}");
+    let data = fs::read_to_string(PARAMETERS_PATH).unwrap();
+    let params: VerifierParameters = serde_json::from_str(&data).unwrap();
+    let parameters_digest: [u8; 32] = Sha256::digest(data.as_bytes()).into();
+
+    let vk = params
+        .verification_key
+        .to_verification_key()
+        .expect("Invalid verification key");
+
+    // Compute all parameters (this will print intermediate values)
+    let vk_digest = vk.digest();
+    let control_root = decode_digest(&params.control_root, "control_root");
+    let bn254_control_id = decode_digest(&params.bn254_control_id, "bn254_control_id");
+    let post_state_digest = decode_digest(&params.post_state_digest, "post_state_digest");
+    let selector = selector(&control_root, &bn254_control_id, &vk_digest);
+    let (control_root_0, control_root_1) = split_control_root(&control_root);
+
+    // Print key verifier parameters during build
+    println!("cargo:warning===========================================");
+    println!("cargo:warning=RISC Zero Groth16 Verifier Parameters");
+    println!("cargo:warning===========================================");
+    println!(
+        "cargo:warning=SELECTOR:            {}",
+        hex::encode(selector)
+    );
+    println!(
+        "cargo:warning=CONTROL_ROOT:        {}",
+        &params.control_root
+    );
+    println!(
+        "cargo:warning=CONTROL_ROOT_0:      {}",
+        hex::encode(control_root_0)
+    );
+    println!(
+        "cargo:warning=CONTROL_ROOT_1:      {}",
+        hex::encode(control_root_1)
+    );
+    println!(
+        "cargo:warning=BN254_CONTROL_ID:    {}",
+        &params.bn254_control_id
+    );
+    println!(
+        "cargo:warning=POST_STATE_DIGEST:   {}",
+        &params.post_state_digest
+    );
+    println!(
+        "cargo:warning=VERIFIER_KEY_DIGEST: {}",
+        hex::encode(vk_digest)
+    );
+    println!("cargo:warning=VERSION:             {}", &params.version);
+    println!(
+        "cargo:warning=PARAMETERS_DIGEST:   {}",
+        hex::encode(parameters_digest)
+    );
+    println!("cargo:warning===========================================");
+
+    if let Some(expected_selector) = &params.expected_selector
+        && let Err(err) = check_selector(expected_selector, &selector)
+    {
+        println!("cargo:warning=ERROR: {err}");
+        println!(
+            "cargo:warning=control_root, bn254_control_id and verification_key in \
+             parameters.json must come from the same RISC Zero release"
+        );
+        panic!("parameters.json: {err}");
+    }
+
+    // Generate the VerificationKey IC array
+    let ic: Vec<String> = vk
+        .ic
+        .iter()
+        .map(|point| format_byte_array(&g1_to_bytes(point)))
+        .collect();
+    let ic = ic.join(", ");
+
+    let vk_code = format!(
+        "VerificationKey {{
+    alpha: {},
+    beta: {},
+    gamma: {},
+    delta: {},
+    ic: &[{}],
+}}",
+        format_byte_array(&g1_to_bytes(&vk.alpha)),
+        format_byte_array(&g2_to_bytes(&vk.beta)),
+        format_byte_array(&g2_to_bytes(&vk.gamma)),
+        format_byte_array(&g2_to_bytes(&vk.delta)),
+        ic
+    );
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let files = [
+        ("verification_key.rs", vk_code),
+        ("vk_digest.rs", format_byte_array(&vk_digest)),
+        ("version.rs", format!("{:?}", params.version)),
+        ("selector.rs", format_byte_array(&selector)),
+        ("control_root.rs", format_byte_array(&control_root)),
+        ("control_root_0.rs", format_byte_array(&control_root_0)),
+        ("control_root_1.rs", format_byte_array(&control_root_1)),
+        ("bn254_control_id.rs", format_byte_array(&bn254_control_id)),
+        (
+            "post_state_digest.rs",
+            format_byte_array(&post_state_digest),
+        ),
+        (
+            "parameters_digest.rs",
+            format_byte_array(&parameters_digest),
+        ),
+    ];
+    for (name, code) in files {
+        fs::write(out_dir.join(name), code).unwrap_or_else(|_| panic!("failed to write {name}"));
+    }
+}
@@ -0,0 +1,113 @@
+//! # RISC Zero Stellar Verifier Parameters
+//!
+//! The parameters of the deployed Groth16 verifier: the verification key and the values
+//! derived from it, generated at build time from this crate's `parameters.json`.
+//!
+//! The contract embeds them from this crate, so off-chain consumers (e.g. risk engines
+//! recomputing selectors or simulating verification) read the exact bytes the verifier checks
+//! proofs against, without building the contract crates or the Soroban SDK. The crate is
+//! `no_std` and has no dependencies.
+//!
+//! The crate is not published to crates.io: its build script uses the workspace's unpublished
+//! `build-utils`. Depend on it through a git or path dependency on this repository.
+//!
+//! Points use the Soroban BN254 encoding: big-endian coordinates, `x || y` for G1 and
+//! `x.c1 || x.c0 || y.c1 || y.c0` for G2.
+
+#![no_std]
+
+/// The size of an encoded G1 point in bytes.
+pub const G1_SIZE: usize = 64;
+
+/// The size of an encoded G2 point in bytes.
+pub const G2_SIZE: usize = 128;
+
+/// A Groth16 verification key for the BN254 curve, with encoded points.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VerificationKey {
+    /// The alpha element in G1.
+    pub alpha: [u8; G1_SIZE],
+    /// The beta element in G2.
+    pub beta: [u8; G2_SIZE],
+    /// The gamma element in G2.
+    pub gamma: [u8; G2_SIZE],
+    /// The delta element in G2.
+    pub delta: [u8; G2_SIZE],
+    /// The input coefficient (IC) points in G1, one per public signal plus one.
+    pub ic: &'static [[u8; G1_SIZE]],
+}
+
+/// The verification key of the RISC Zero Groth16 circuit.
+pub const VERIFICATION_KEY: VerificationKey =
+    include!(concat!(env!("OUT_DIR"), "/verification_key.rs"));
+
+/// Digest of [`VERIFICATION_KEY`], as hashed by RISC Zero's `Groth16ReceiptVerifierParameters`.
+pub const VERIFIER_KEY_DIGEST: [u8; 32] = include!(concat!(env!("OUT_DIR"), "/vk_digest.rs"));
+
+/// The RISC Zero release the parameters come from, e.g. `3.0.0`.
+pub const VERSION: &str = include!(concat!(env!("OUT_DIR"), "/version.rs"));
+
+/// The selector of the verifier: the 4 bytes its seals start with, and the key it is
+/// registered under in the router.
+pub const SELECTOR: [u8; 4] = include!(concat!(env!("OUT_DIR"), "/selector.rs"));
+
+/// The control root, in the byte order RISC Zero prints it in.
+pub const CONTROL_ROOT: [u8; 32] = include!(concat!(env!("OUT_DIR"), "/control_root.rs"));
+
+/// The upper half of the control root, the first public signal.
+pub const CONTROL_ROOT_0: [u8; 16] = include!(concat!(env!("OUT_DIR"), "/control_root_0.rs"));
+
+/// The lower half of the control root, the second public signal.
+pub const CONTROL_ROOT_1: [u8; 16] = include!(concat!(env!("OUT_DIR"), "/control_root_1.rs"));
+
+/// The BN254 control id, the last public signal, in the byte order RISC Zero prints it in.
+pub const BN254_CONTROL_ID: [u8; 32] = include!(concat!(env!("OUT_DIR"), "/bn254_control_id.rs"));
+
+/// The post-state digest of a halted execution in this release, which standard claims commit
+/// to.
+pub const POST_STATE_DIGEST: [u8; 32] = include!(concat!(env!("OUT_DIR"), "/post_state_digest.rs"));
+
+/// SHA-256 digest of the `parameters.json` the parameters were generated from.
+pub const PARAMETERS_DIGEST: [u8; 32] = include!(concat!(env!("OUT_DIR"), "/parameters_digest.rs"));
+
+#[cfg(test)]
+mod tests {
+    use build_utils::{
+        GROTH16_VERIFYING_KEY_IC_TAG, GROTH16_VERIFYING_KEY_TAG, Sha256Digest, groth16,
+        tagged_iter, tagged_struct,
+    };
+    use sha2::{Digest, Sha256};
+
+    use super::{BN254_CONTROL_ID, CONTROL_ROOT, SELECTOR, VERIFICATION_KEY, VERIFIER_KEY_DIGEST};
+
+    fn sha256(bytes: &[u8]) -> Sha256Digest {
+        Sha256::digest(bytes).into()
+    }
+
+    // Off-chain consumers recompute the selector from the embedded bytes alone.
+    #[test]
+    fn test_selector_derives_from_embedded_key() {
+        let vk = VERIFICATION_KEY;
+        let ic = tagged_iter(
+            GROTH16_VERIFYING_KEY_IC_TAG,
+            vk.ic.iter().map(|point| sha256(point)),
+        );
+        let vk_digest = tagged_struct(
+            GROTH16_VERIFYING_KEY_TAG,
+            &[
+                sha256(&vk.alpha),
+                sha256(&vk.beta),
+                sha256(&vk.gamma),
+                sha256(&vk.delta),
+                ic,
+            ],
+        );
+
+        assert_eq!(vk_digest, VERIFIER_KEY_DIGEST);
+        assert_eq!(
+            groth16::selector(&CONTROL_ROOT, &BN254_CONTROL_ID, &vk_digest),
+            SELECTOR
+        );
+        assert_eq!(hex::encode(SELECTOR), "73c457ba");
+    }
+}
@@ -0,0 +1,237 @@
+//! # ZK Airdrop Example
+//!
+//! This example contract distributes tokens to members of a private Merkle set. A claimant
+//! proves off-chain, with a RISC Zero guest program, that they know a leaf of the Merkle tree
+//! committed at deployment, without revealing which one. The guest commits the following
+//! journal:
+//!
+//! ```text
+//! merkle_root (32 bytes) || nullifier (32 bytes) || recipient_digest (32 bytes)
+//! ```
+//!
+//! - `merkle_root`: Root of the membership set, must match the configured root
+//! - `nullifier`: Deterministic per-leaf value, used to prevent double claims
+//! - `recipient_digest`: SHA-256 of the recipient [`Address`] XDR encoding, binding the proof to
+//!   the account receiving the tokens so that a proof cannot be front-run by another account
+//!
+//! ## Claim Flow
+//!
+//! 1. The claimant submits the recipient, the seal, and the raw journal to
+//!    [`ZkAirdrop::claim`]
+//! 2. The journal is decoded and checked against the configured root and the recipient
+//! 3. The seal is verified against the configured image id through the verifier (or router)
+//! 4. The nullifier is recorded and the configured amount is transferred to the recipient
+
+#![no_std]
+
+use risc0_interface::RiscZeroVerifierClient;
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, TryFromVal, Val, contract, contracterror, contractevent,
+    contractimpl, contracttype, token::TokenClient, xdr::ToXdr,
+};
+
+#[cfg(test)]
+mod test;
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+const NULLIFIER_EXTEND_AMOUNT: u32 = 90 * DAY_IN_LEDGERS;
+const NULLIFIER_TTL_THRESHOLD: u32 = NULLIFIER_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+
+/// Size of the journal committed by the membership guest.
+const JOURNAL_SIZE: u32 = 96;
+
+/// Storage keys used by the airdrop example contract.
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    /// Static airdrop configuration.
+    Config,
+    /// Marker for a nullifier that has already been claimed.
+    Nullifier(BytesN<32>),
+}
+
+/// Airdrop configuration set at deployment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AirdropConfig {
+    /// Address of the verifier (or router) used to check proofs.
+    pub verifier: Address,
+    /// Image id of the membership guest program.
+    pub image_id: BytesN<32>,
+    /// Token distributed by the airdrop.
+    pub token: Address,
+    /// Root of the Merkle set of eligible members.
+    pub merkle_root: BytesN<32>,
+    /// Amount transferred per successful claim.
+    pub amount: i128,
+}
+
+/// Errors returned by the airdrop example contract (registry range `500..=599`).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AirdropError {
+    /// The journal does not match the expected layout.
+    MalformedJournal = 500,
+    /// The journal commits to a different Merkle root.
+    RootMismatch = 501,
+    /// The journal commits to a different recipient.
+    RecipientMismatch = 502,
+    /// The nullifier has already been claimed.
+    AlreadyClaimed = 503,
+}
+
+/// Event emitted for each successful claim.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Claimed {
+    /// Nullifier spent by the claim.
+    #[topic]
+    pub nullifier: BytesN<32>,
+    /// Account the tokens were transferred to.
+    pub recipient: Address,
+    /// Amount transferred, in the token's smallest unit.
+    pub amount: i128,
+}
+
+/// Decoded membership guest journal.
+struct Journal {
+    merkle_root: BytesN<32>,
+    nullifier: BytesN<32>,
+    recipient_digest: BytesN<32>,
+}
+
+/// Example contract paying out tokens for proofs of Merkle set membership.
+#[contract]
+pub struct ZkAirdrop;
+
+#[contractimpl]
+impl ZkAirdrop {
+    /// Initializes the airdrop. The contract must be funded with `token` separately.
+    pub fn __constructor(
+        env: Env,
+        verifier: Address,
+        image_id: BytesN<32>,
+        token: Address,
+        merkle_root: BytesN<32>,
+        amount: i128,
+    ) {
+        let config = AirdropConfig {
+            verifier,
+            image_id,
+            token,
+            merkle_root,
+            amount,
+        };
+        env.storage().instance().set(&DataKey::Config, &config);
+    }
+
+    /// Returns the airdrop configuration.
+    pub fn config(env: Env) -> AirdropConfig {
+        read_instance(&env, &DataKey::Config)
+    }
+
+    /// Returns whether the nullifier has already been claimed.
+    pub fn is_claimed(env: Env, nullifier: BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Nullifier(nullifier))
+    }
+
+    /// Claims the airdrop for `recipient` with a membership proof.
+    ///
+    /// # Parameters
+    ///
+    /// - `recipient`: Account receiving the tokens, must match the journal commitment
+    /// - `seal`: The encoded proof produced for the membership guest
+    /// - `journal`: The raw journal committed by the guest
+    ///
+    /// # Errors
+    ///
+    /// - [`AirdropError::MalformedJournal`] - The journal does not match the expected layout
+    /// - [`AirdropError::RootMismatch`] - The proof is for a different membership set
+    /// - [`AirdropError::RecipientMismatch`] - The proof is bound to a different recipient
+    /// - [`AirdropError::AlreadyClaimed`] - The nullifier has already been used
+    ///
+    /// Proof verification failures abort the invocation with the verifier's error.
+    pub fn claim(
+        env: Env,
+        recipient: Address,
+        seal: Bytes,
+        journal: Bytes,
+    ) -> Result<(), AirdropError> {
+        let config: AirdropConfig = read_instance(&env, &DataKey::Config);
+        let decoded = decode_journal(&journal)?;
+
+        if decoded.merkle_root != config.merkle_root {
+            return Err(AirdropError::RootMismatch);
+        }
+
+        let recipient_digest: BytesN<32> =
+            env.crypto().sha256(&recipient.clone().to_xdr(&env)).into();
+        if decoded.recipient_digest != recipient_digest {
+            return Err(AirdropError::RecipientMismatch);
+        }
+
+        let nullifier_key = DataKey::Nullifier(decoded.nullifier.clone());
+        if env.storage().persistent().has(&nullifier_key) {
+            return Err(AirdropError::AlreadyClaimed);
+        }
+
+        let journal_digest: BytesN<32> = env.crypto().sha256(&journal).into();
+        RiscZeroVerifierClient::new(&env, &config.verifier).verify(
+            &seal,
+            &config.image_id,
+            &journal_digest,
+        );
+
+        env.storage().persistent().set(&nullifier_key, &true);
+        env.storage().persistent().extend_ttl(
+            &nullifier_key,
+            NULLIFIER_TTL_THRESHOLD,
+            NULLIFIER_EXTEND_AMOUNT,
+        );
+
+        TokenClient::new(&env, &config.token).transfer(
+            &env.current_contract_address(),
+            &recipient,
+            &config.amount,
+        );
+
+        Claimed {
+            nullifier: decoded.nullifier,
+            recipient,
+            amount: config.amount,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+}
+
+fn read_instance<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> V {
+    env.storage()
+        .instance()
+        .get(key)
+        .expect("contract is initialized in the constructor")
+}
+
+/// Decodes the membership guest journal.
+fn decode_journal(journal: &Bytes) -> Result<Journal, AirdropError> {
+    if journal.len() != JOURNAL_SIZE {
+        return Err(AirdropError::MalformedJournal);
+    }
+
+    let field = |start: u32| -> Result<BytesN<32>, AirdropError> {
+        journal
+            .slice(start..start + 32)
+            .try_into()
+            .map_err(|_| AirdropError::MalformedJournal)
+    };
+
+    Ok(Journal {
+        merkle_root: field(0)?,
+        nullifier: field(32)?,
+        recipient_digest: field(64)?,
+    })
+}
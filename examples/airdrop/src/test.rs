@@ -0,0 +1,151 @@
+extern crate std;
+
+use mock_verifier::testutils::MockProver;
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    xdr::ToXdr,
+};
+
+use crate::{AirdropError, ZkAirdrop, ZkAirdropClient};
+
+const MERKLE_ROOT: [u8; 32] = [0x77; 32];
+const AMOUNT: i128 = 1_000;
+
+struct Setup {
+    env: Env,
+    airdrop: ZkAirdropClient<'static>,
+    verifier: MockProver,
+    token: TokenClient<'static>,
+}
+
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let verifier = MockProver::register(&env);
+
+    let issuer = Address::generate(&env);
+    let token_id = env.register_stellar_asset_contract_v2(issuer).address();
+    let token = TokenClient::new(&env, &token_id);
+
+    let airdrop_id = env.register(
+        ZkAirdrop,
+        (
+            verifier.address().clone(),
+            verifier.image_id(),
+            token_id.clone(),
+            BytesN::from_array(&env, &MERKLE_ROOT),
+            AMOUNT,
+        ),
+    );
+    let airdrop = ZkAirdropClient::new(&env, &airdrop_id);
+    StellarAssetClient::new(&env, &token_id).mint(&airdrop_id, &(AMOUNT * 10));
+
+    Setup {
+        env,
+        airdrop,
+        verifier,
+        token,
+    }
+}
+
+fn journal(env: &Env, root: [u8; 32], nullifier: [u8; 32], recipient: &Address) -> Bytes {
+    let mut journal = Bytes::from_array(env, &root);
+    journal.append(&Bytes::from_array(env, &nullifier));
+    journal.append(&env.crypto().sha256(&recipient.clone().to_xdr(env)).into());
+    journal
+}
+
+fn prove(s: &Setup, journal: &Bytes) -> Bytes {
+    s.verifier.prove_journal(journal)
+}
+
+#[test]
+fn claim_transfers_tokens_and_records_nullifier() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let journal = journal(&s.env, MERKLE_ROOT, [0x01; 32], &recipient);
+
+    s.airdrop.claim(&recipient, &prove(&s, &journal), &journal);
+
+    assert_eq!(s.token.balance(&recipient), AMOUNT);
+    assert!(
+        s.airdrop
+            .is_claimed(&BytesN::from_array(&s.env, &[0x01; 32]))
+    );
+}
+
+#[test]
+fn claim_rejects_reused_nullifier() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let journal = journal(&s.env, MERKLE_ROOT, [0x01; 32], &recipient);
+    let seal = prove(&s, &journal);
+
+    s.airdrop.claim(&recipient, &seal, &journal);
+    let result = s.airdrop.try_claim(&recipient, &seal, &journal);
+
+    assert_eq!(result, Err(Ok(AirdropError::AlreadyClaimed)));
+    assert_eq!(s.token.balance(&recipient), AMOUNT);
+}
+
+#[test]
+fn claim_rejects_other_root() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let journal = journal(&s.env, [0x00; 32], [0x01; 32], &recipient);
+
+    let result = s
+        .airdrop
+        .try_claim(&recipient, &prove(&s, &journal), &journal);
+
+    assert_eq!(result, Err(Ok(AirdropError::RootMismatch)));
+}
+
+#[test]
+fn claim_rejects_other_recipient() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let thief = Address::generate(&s.env);
+    let journal = journal(&s.env, MERKLE_ROOT, [0x01; 32], &recipient);
+
+    let result = s.airdrop.try_claim(&thief, &prove(&s, &journal), &journal);
+
+    assert_eq!(result, Err(Ok(AirdropError::RecipientMismatch)));
+    assert_eq!(s.token.balance(&thief), 0);
+}
+
+#[test]
+fn claim_rejects_malformed_journal() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let journal = Bytes::from_array(&s.env, &[0u8; 64]);
+
+    let result = s
+        .airdrop
+        .try_claim(&recipient, &prove(&s, &journal), &journal);
+
+    assert_eq!(result, Err(Ok(AirdropError::MalformedJournal)));
+}
+
+#[test]
+#[should_panic]
+fn claim_rejects_invalid_proof() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let journal = journal(&s.env, MERKLE_ROOT, [0x01; 32], &recipient);
+    let other = self::journal(&s.env, MERKLE_ROOT, [0x02; 32], &recipient);
+
+    s.airdrop.claim(&recipient, &prove(&s, &other), &journal);
+}
+
+#[test]
+fn error_codes_are_stable() {
+    // Part of the public ABI, see the error registry in `risc0-interface`.
+    assert_eq!(AirdropError::MalformedJournal as u32, 500);
+    assert_eq!(AirdropError::RootMismatch as u32, 501);
+    assert_eq!(AirdropError::RecipientMismatch as u32, 502);
+    assert_eq!(AirdropError::AlreadyClaimed as u32, 503);
+}
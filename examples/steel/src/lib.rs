@@ -0,0 +1,276 @@
+//! # Steel State-Proof Example
+//!
+//! This example contract shows how an application consumes the RISC Zero verifier to
+//! import Ethereum state onto Stellar using a [Steel](https://github.com/risc0/risc0-ethereum)
+//! proof.
+//!
+//! The guest program reads a single storage slot of an Ethereum account through Steel and
+//! commits the following ABI-encoded journal:
+//!
+//! ```text
+//! struct Journal {
+//!     Steel.Commitment commitment; // (uint256 id, bytes32 digest, bytes32 configID)
+//!     address account;             // Account whose storage was read
+//!     bytes32 slot;                // Storage slot key
+//!     bytes32 value;               // Storage slot value
+//! }
+//! ```
+//!
+//! All fields are static, so the ABI encoding is six consecutive 32-byte words.
+//!
+//! ## Integration Path
+//!
+//! 1. The relayer submits the seal and the raw journal to [`SteelStateVerifier::prove_storage`]
+//! 2. The contract hashes the journal and calls `verify` on the configured verifier (usually the
+//!    router) with the configured image id
+//! 3. The journal is decoded and the proven value is stored, keyed by
+//!    `(account, slot, commitment)`
+//! 4. Other contracts read the value proven at a commitment they validated through
+//!    [`SteelStateVerifier::storage_value`]
+//!
+//! ## Trust Assumptions
+//!
+//! The Steel commitment binds the proof to an Ethereum block, but Stellar has no native view
+//! of Ethereum block hashes. Consumers must check the commitment against a block hash they
+//! trust (e.g., a light client or an oracle) before relying on the value.
+//!
+//! Until then the commitment is unvalidated: anyone can prove a value against a fabricated
+//! chain, with any block number. The contract therefore stores every value under its own
+//! commitment instead of keeping the latest one per slot, which such a proof could overwrite
+//! or, with a large block number, lock. Ordering commitments by block number is only
+//! meaningful after they are validated, and only between commitments of the same version:
+//! version `1` commitments carry a beacon timestamp instead.
+
+#![no_std]
+
+use risc0_interface::RiscZeroVerifierClient;
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, contract, contracterror, contractimpl, contracttype, xdr::ToXdr,
+};
+
+#[cfg(test)]
+mod test;
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+const SLOT_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const SLOT_TTL_THRESHOLD: u32 = SLOT_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+
+/// Size of an ABI-encoded word.
+const WORD_SIZE: u32 = 32;
+/// Number of ABI words in the journal.
+const JOURNAL_WORDS: u32 = 6;
+
+/// Steel commitment version for commitments to an execution block hash.
+const COMMITMENT_VERSION_BLOCK: u16 = 0;
+/// Steel commitment version for commitments to a beacon block root.
+const COMMITMENT_VERSION_BEACON: u16 = 1;
+
+/// Storage keys used by the Steel example contract.
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    /// Address of the verifier (or router) used to check proofs.
+    Verifier,
+    /// Image id of the Steel guest program.
+    ImageId,
+    /// Proven value of an `(account, slot)` pair at a commitment, keyed by [`slot_key`].
+    Slot(BytesN<32>),
+}
+
+/// Errors returned by the Steel example contract (registry range `400..=499`).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SteelExampleError {
+    /// The journal does not match the expected ABI layout.
+    MalformedJournal = 400,
+    /// The Steel commitment uses a version this contract does not understand.
+    UnsupportedCommitment = 401,
+}
+
+/// Ethereum block commitment produced by Steel.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SteelCommitment {
+    /// Commitment version (`0` = block hash, `1` = beacon root).
+    pub version: u32,
+    /// Block number (or timestamp for beacon commitments) the state was read at. Only
+    /// comparable between validated commitments of the same version.
+    pub block_number: u64,
+    /// Block hash (or beacon root) the state was read against.
+    pub block_digest: BytesN<32>,
+    /// Digest of the chain configuration used by the guest.
+    pub config_id: BytesN<32>,
+}
+
+/// Storage slot value proven by a Steel receipt.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProvenSlot {
+    /// Ethereum block the value was read at.
+    pub commitment: SteelCommitment,
+    /// Value of the storage slot at that block.
+    pub value: BytesN<32>,
+}
+
+/// Decoded Steel guest journal.
+struct Journal {
+    commitment: SteelCommitment,
+    account: BytesN<20>,
+    slot: BytesN<32>,
+    value: BytesN<32>,
+}
+
+/// Example contract importing Ethereum storage slots proven with Steel.
+#[contract]
+pub struct SteelStateVerifier;
+
+#[contractimpl]
+impl SteelStateVerifier {
+    /// Initializes the example with the verifier address and the Steel guest image id.
+    pub fn __constructor(env: Env, verifier: Address, image_id: BytesN<32>) {
+        env.storage().instance().set(&DataKey::Verifier, &verifier);
+        env.storage().instance().set(&DataKey::ImageId, &image_id);
+    }
+
+    /// Returns the verifier address used to check proofs.
+    pub fn verifier(env: Env) -> Address {
+        read_instance(&env, &DataKey::Verifier)
+    }
+
+    /// Returns the image id of the Steel guest program.
+    pub fn image_id(env: Env) -> BytesN<32> {
+        read_instance(&env, &DataKey::ImageId)
+    }
+
+    /// Verifies a Steel proof and records the proven storage slot value under its commitment.
+    ///
+    /// The commitment is not validated, see the trust assumptions in the crate docs. Proving
+    /// the same commitment again stores the same value, as the commitment binds it.
+    ///
+    /// # Parameters
+    ///
+    /// - `seal`: The encoded proof produced for the Steel guest
+    /// - `journal`: The raw ABI-encoded journal committed by the guest
+    ///
+    /// # Errors
+    ///
+    /// - [`SteelExampleError::MalformedJournal`] - The journal does not match the ABI layout
+    /// - [`SteelExampleError::UnsupportedCommitment`] - Unknown Steel commitment version
+    ///
+    /// Proof verification failures abort the invocation with the verifier's error.
+    pub fn prove_storage(
+        env: Env,
+        seal: Bytes,
+        journal: Bytes,
+    ) -> Result<ProvenSlot, SteelExampleError> {
+        let decoded = decode_journal(&journal)?;
+
+        let verifier: Address = read_instance(&env, &DataKey::Verifier);
+        let image_id: BytesN<32> = read_instance(&env, &DataKey::ImageId);
+        let journal_digest: BytesN<32> = env.crypto().sha256(&journal).into();
+        RiscZeroVerifierClient::new(&env, &verifier).verify(&seal, &image_id, &journal_digest);
+
+        let key = DataKey::Slot(slot_key(
+            &env,
+            decoded.account,
+            decoded.slot,
+            decoded.commitment.clone(),
+        ));
+        env.storage().persistent().set(&key, &decoded.value);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, SLOT_TTL_THRESHOLD, SLOT_EXTEND_AMOUNT);
+
+        Ok(ProvenSlot {
+            commitment: decoded.commitment,
+            value: decoded.value,
+        })
+    }
+
+    /// Returns the value of an Ethereum storage slot proven at `commitment`, if any.
+    ///
+    /// Callers must have validated `commitment` against a block hash they trust.
+    pub fn storage_value(
+        env: Env,
+        account: BytesN<20>,
+        slot: BytesN<32>,
+        commitment: SteelCommitment,
+    ) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Slot(slot_key(&env, account, slot, commitment)))
+    }
+}
+
+/// Hashes an `(account, slot, commitment)` triple into a storage key, as the commitment makes
+/// the triple too large to be a key itself.
+fn slot_key(
+    env: &Env,
+    account: BytesN<20>,
+    slot: BytesN<32>,
+    commitment: SteelCommitment,
+) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(account, slot, commitment).to_xdr(env))
+        .into()
+}
+
+fn read_instance<V: soroban_sdk::TryFromVal<Env, soroban_sdk::Val>>(env: &Env, key: &DataKey) -> V {
+    env.storage()
+        .instance()
+        .get(key)
+        .expect("contract is initialized in the constructor")
+}
+
+/// Decodes the ABI-encoded Steel journal.
+fn decode_journal(journal: &Bytes) -> Result<Journal, SteelExampleError> {
+    if journal.len() != JOURNAL_WORDS * WORD_SIZE {
+        return Err(SteelExampleError::MalformedJournal);
+    }
+    let env = journal.env();
+
+    // Commitment id: `version (uint16) << 240 | block number`.
+    let id = read_word(journal, 0);
+    let version = u16::from_be_bytes([id[0], id[1]]);
+    if id[2..24].iter().any(|b| *b != 0) {
+        return Err(SteelExampleError::MalformedJournal);
+    }
+    if version != COMMITMENT_VERSION_BLOCK && version != COMMITMENT_VERSION_BEACON {
+        return Err(SteelExampleError::UnsupportedCommitment);
+    }
+    let mut block_number = [0u8; 8];
+    block_number.copy_from_slice(&id[24..32]);
+
+    let commitment = SteelCommitment {
+        version: u32::from(version),
+        block_number: u64::from_be_bytes(block_number),
+        block_digest: BytesN::from_array(env, &read_word(journal, 1)),
+        config_id: BytesN::from_array(env, &read_word(journal, 2)),
+    };
+
+    // Addresses are left-padded to a full word.
+    let account_word = read_word(journal, 3);
+    if account_word[0..12].iter().any(|b| *b != 0) {
+        return Err(SteelExampleError::MalformedJournal);
+    }
+    let mut account = [0u8; 20];
+    account.copy_from_slice(&account_word[12..32]);
+
+    Ok(Journal {
+        commitment,
+        account: BytesN::from_array(env, &account),
+        slot: BytesN::from_array(env, &read_word(journal, 4)),
+        value: BytesN::from_array(env, &read_word(journal, 5)),
+    })
+}
+
+/// Reads the `index`-th 32-byte ABI word. The caller checks the journal length.
+fn read_word(journal: &Bytes, index: u32) -> [u8; 32] {
+    let start = index * WORD_SIZE;
+    let mut word = [0u8; 32];
+    journal
+        .slice(start..start + WORD_SIZE)
+        .copy_into_slice(&mut word);
+    word
+}
@@ -0,0 +1,126 @@
+extern crate std;
+
+use mock_verifier::testutils::MockProver;
+use soroban_sdk::{Bytes, BytesN, Env};
+
+use crate::{SteelExampleError, SteelStateVerifier, SteelStateVerifierClient};
+
+const ACCOUNT: [u8; 20] = [0xAC; 20];
+const SLOT: [u8; 32] = [0x01; 32];
+
+fn setup() -> (Env, SteelStateVerifierClient<'static>, MockProver) {
+    let env = Env::default();
+    let verifier = MockProver::register(&env);
+
+    let contract_id = env.register(
+        SteelStateVerifier,
+        (verifier.address().clone(), verifier.image_id()),
+    );
+    let client = SteelStateVerifierClient::new(&env, &contract_id);
+    (env, client, verifier)
+}
+
+/// Builds the ABI-encoded journal committed by the Steel guest.
+fn journal(env: &Env, version: u16, block_number: u64, value: u8) -> Bytes {
+    let mut id = [0u8; 32];
+    id[0..2].copy_from_slice(&version.to_be_bytes());
+    id[24..32].copy_from_slice(&block_number.to_be_bytes());
+    let mut account = [0u8; 32];
+    account[12..32].copy_from_slice(&ACCOUNT);
+
+    let mut journal = Bytes::new(env);
+    journal.append(&Bytes::from_array(env, &id));
+    journal.append(&Bytes::from_array(env, &[0xB1; 32]));
+    journal.append(&Bytes::from_array(env, &[0xC0; 32]));
+    journal.append(&Bytes::from_array(env, &account));
+    journal.append(&Bytes::from_array(env, &SLOT));
+    journal.append(&Bytes::from_array(env, &[value; 32]));
+    journal
+}
+
+#[test]
+fn prove_storage_records_value() {
+    let (env, client, verifier) = setup();
+    let journal = journal(&env, 0, 21_000_000, 0x42);
+    let seal = verifier.prove_journal(&journal);
+
+    let proven = client.prove_storage(&seal, &journal);
+
+    assert_eq!(proven.commitment.version, 0);
+    assert_eq!(proven.commitment.block_number, 21_000_000);
+    assert_eq!(proven.commitment.block_digest.to_array(), [0xB1; 32]);
+    assert_eq!(proven.value.to_array(), [0x42; 32]);
+
+    let account = BytesN::from_array(&env, &ACCOUNT);
+    let slot = BytesN::from_array(&env, &SLOT);
+    assert_eq!(
+        client.storage_value(&account, &slot, &proven.commitment),
+        Some(proven.value)
+    );
+}
+
+#[test]
+fn prove_storage_keeps_values_per_commitment() {
+    let (env, client, verifier) = setup();
+    // An unvalidated commitment far in the future must not lock the slot.
+    let future = journal(&env, 0, u64::MAX, 0x02);
+    let current = journal(&env, 0, 100, 0x01);
+
+    let future = client.prove_storage(&verifier.prove_journal(&future), &future);
+    let current = client.prove_storage(&verifier.prove_journal(&current), &current);
+
+    let account = BytesN::from_array(&env, &ACCOUNT);
+    let slot = BytesN::from_array(&env, &SLOT);
+    assert_eq!(
+        client.storage_value(&account, &slot, &current.commitment),
+        Some(current.value)
+    );
+    assert_eq!(
+        client.storage_value(&account, &slot, &future.commitment),
+        Some(future.value)
+    );
+}
+
+#[test]
+fn prove_storage_rejects_malformed_journal() {
+    let (env, client, verifier) = setup();
+    let journal = Bytes::from_array(&env, &[0u8; 64]);
+    let seal = verifier.prove_journal(&journal);
+
+    let result = client.try_prove_storage(&seal, &journal);
+
+    assert_eq!(result, Err(Ok(SteelExampleError::MalformedJournal)));
+}
+
+#[test]
+fn prove_storage_rejects_unknown_commitment_version() {
+    let (env, client, verifier) = setup();
+    let journal = journal(&env, 7, 100, 0x01);
+    let seal = verifier.prove_journal(&journal);
+
+    let result = client.try_prove_storage(&seal, &journal);
+
+    assert_eq!(result, Err(Ok(SteelExampleError::UnsupportedCommitment)));
+}
+
+#[test]
+#[should_panic]
+fn prove_storage_rejects_proof_for_other_journal() {
+    let (env, client, verifier) = setup();
+    let proven = journal(&env, 0, 100, 0x01);
+    let forged = journal(&env, 0, 100, 0x02);
+    let seal = verifier.prove_journal(&proven);
+
+    client.prove_storage(&seal, &forged);
+}
+
+#[test]
+fn error_codes_are_stable() {
+    // Part of the public ABI, see the error registry in `risc0-interface`.
+    assert_eq!(SteelExampleError::MalformedJournal as u32, 400);
+    assert_eq!(SteelExampleError::UnsupportedCommitment as u32, 401);
+
+    // `StaleCommitment`, removed with the block number ordering, must never be reassigned.
+    let error = soroban_sdk::Error::from_contract_error(402);
+    assert!(SteelExampleError::try_from(error).is_err());
+}
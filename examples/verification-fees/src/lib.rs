@@ -0,0 +1,159 @@
+//! # Verification Fees Example
+//!
+//! This example contract sells proof verification as a service, with fees paid in a Stellar
+//! Asset Contract (SAC) token. It fronts a verifier (usually the router): each verification
+//! charges a fixed fee to a payer and pays it into a treasury, then forwards the receipt to the
+//! verifier. The router itself charges nothing, so the fee is collected here, in front of it.
+//!
+//! ## Fee Flow
+//!
+//! 1. The payer approves this contract on the fee token, with the SAC `approve` entrypoint,
+//!    for as much as it is willing to spend on verifications
+//! 2. The payer (or a contract it authorizes) calls [`VerificationFees::verify`]
+//! 3. The fee is moved from the payer into the treasury with `transfer_from`, against that
+//!    allowance
+//! 4. The receipt is verified through the configured verifier
+//!
+//! The verification and the transfer happen in the same invocation, so a failed verification
+//! rolls back the fee: payers are only charged for receipts that verify.
+//!
+//! ## Sponsoring
+//!
+//! The payer does not have to be the party submitting the proof. An application contract can
+//! sponsor its users' verifications by approving an allowance from its own balance and calling
+//! [`VerificationFees::verify`] with itself as the payer: as the direct invoker, its
+//! authorization is implicit. The allowance caps the total the service can ever charge.
+
+#![no_std]
+
+use risc0_interface::RiscZeroVerifierClient;
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, contract, contracterror, contractevent, contractimpl,
+    contracttype, panic_with_error, token::TokenClient,
+};
+
+#[cfg(test)]
+mod test;
+
+/// Storage keys used by the verification fees example contract.
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    /// Static fee configuration.
+    Config,
+}
+
+/// Fee configuration set at deployment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    /// Address of the verifier (or router) receipts are forwarded to.
+    pub verifier: Address,
+    /// SAC token the fees are paid in.
+    pub token: Address,
+    /// Account receiving the fees.
+    pub treasury: Address,
+    /// Fee charged per verification, in the token's smallest unit.
+    pub fee: i128,
+}
+
+/// Errors returned by the verification fees example contract (registry range `800..=899`).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VerificationFeesError {
+    /// The configured fee is negative.
+    NegativeFee = 800,
+}
+
+/// Event emitted for each paid verification.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCharged {
+    /// Account the fee was charged to.
+    #[topic]
+    pub payer: Address,
+    /// Fee paid into the treasury, in the token's smallest unit.
+    pub amount: i128,
+}
+
+/// Example contract charging a SAC token fee per verification.
+#[contract]
+pub struct VerificationFees;
+
+#[contractimpl]
+impl VerificationFees {
+    /// Initializes the service.
+    ///
+    /// # Panics
+    ///
+    /// - [`VerificationFeesError::NegativeFee`] - `fee` is negative
+    pub fn __constructor(
+        env: Env,
+        verifier: Address,
+        token: Address,
+        treasury: Address,
+        fee: i128,
+    ) {
+        if fee < 0 {
+            panic_with_error!(&env, VerificationFeesError::NegativeFee);
+        }
+
+        let config = FeeConfig {
+            verifier,
+            token,
+            treasury,
+            fee,
+        };
+        env.storage().instance().set(&DataKey::Config, &config);
+    }
+
+    /// Returns the fee configuration.
+    pub fn config(env: Env) -> FeeConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .expect("contract is initialized in the constructor")
+    }
+
+    /// Verifies a receipt, charging the fee to `payer`.
+    ///
+    /// # Parameters
+    ///
+    /// - `payer`: Account paying the fee, must authorize the call and have approved this
+    ///   contract for at least the fee
+    /// - `seal`, `image_id`, `journal`: The receipt, as taken by
+    ///   [`verify`](risc0_interface::RiscZeroVerifierInterface::verify)
+    ///
+    /// Verification failures abort the invocation with the verifier's error, and a missing
+    /// allowance or balance with the token's; either way nothing is charged.
+    pub fn verify(
+        env: Env,
+        payer: Address,
+        seal: Bytes,
+        image_id: BytesN<32>,
+        journal: BytesN<32>,
+    ) {
+        payer.require_auth();
+        let config = Self::config(env.clone());
+
+        // Charged before verifying, so that a payer without allowance fails before the costly
+        // part of the invocation.
+        if config.fee > 0 {
+            TokenClient::new(&env, &config.token).transfer_from(
+                &env.current_contract_address(),
+                &payer,
+                &config.treasury,
+                &config.fee,
+            );
+        }
+
+        RiscZeroVerifierClient::new(&env, &config.verifier).verify(&seal, &image_id, &journal);
+
+        FeeCharged {
+            payer,
+            amount: config.fee,
+        }
+        .publish(&env);
+    }
+}
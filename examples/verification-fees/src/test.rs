@@ -0,0 +1,159 @@
+extern crate std;
+
+use mock_verifier::testutils::MockProver;
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, Event, IntoVal, Symbol,
+    testutils::{Address as _, AuthorizedFunction, Events as _},
+    token::{StellarAssetClient, TokenClient},
+};
+
+use crate::{FeeCharged, VerificationFees, VerificationFeesClient, VerificationFeesError};
+
+const FEE: i128 = 25;
+
+struct Setup {
+    env: Env,
+    fees: VerificationFeesClient<'static>,
+    verifier: MockProver,
+    token: TokenClient<'static>,
+    treasury: Address,
+    payer: Address,
+}
+
+fn setup(fee: i128) -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let verifier = MockProver::register(&env);
+
+    let issuer = Address::generate(&env);
+    let token_id = env.register_stellar_asset_contract_v2(issuer).address();
+    let token = TokenClient::new(&env, &token_id);
+
+    let treasury = Address::generate(&env);
+    let fees_id = env.register(
+        VerificationFees,
+        (
+            verifier.address().clone(),
+            token_id.clone(),
+            treasury.clone(),
+            fee,
+        ),
+    );
+    let fees = VerificationFeesClient::new(&env, &fees_id);
+
+    let payer = Address::generate(&env);
+    StellarAssetClient::new(&env, &token_id).mint(&payer, &(FEE * 10));
+    token.approve(&payer, &fees_id, &(FEE * 2), &env.ledger().sequence());
+
+    Setup {
+        env,
+        fees,
+        verifier,
+        token,
+        treasury,
+        payer,
+    }
+}
+
+/// Returns a seal proving `journal_digest` for the test image, and the image id.
+fn prove(s: &Setup, journal_digest: &BytesN<32>) -> (Bytes, BytesN<32>) {
+    (s.verifier.prove(journal_digest), s.verifier.image_id())
+}
+
+#[test]
+fn verify_pays_fee_into_treasury() {
+    let s = setup(FEE);
+    let journal = BytesN::from_array(&s.env, &[0x01; 32]);
+    let (seal, image_id) = prove(&s, &journal);
+
+    s.fees.verify(&s.payer, &seal, &image_id, &journal);
+
+    let event = FeeCharged {
+        payer: s.payer.clone(),
+        amount: FEE,
+    };
+    assert_eq!(
+        s.env.events().all().filter_by_contract(&s.fees.address),
+        [event.to_xdr(&s.env, &s.fees.address)]
+    );
+    assert_eq!(s.token.balance(&s.treasury), FEE);
+    assert_eq!(s.token.balance(&s.payer), FEE * 9);
+    assert_eq!(s.token.allowance(&s.payer, &s.fees.address), FEE);
+}
+
+#[test]
+fn verify_requires_payer_auth() {
+    let s = setup(FEE);
+    let journal = BytesN::from_array(&s.env, &[0x01; 32]);
+    let (seal, image_id) = prove(&s, &journal);
+
+    s.fees.verify(&s.payer, &seal, &image_id, &journal);
+
+    let (address, invocation) = &s.env.auths()[0];
+    assert_eq!(address, &s.payer);
+    assert_eq!(
+        invocation.function,
+        AuthorizedFunction::Contract((
+            s.fees.address.clone(),
+            Symbol::new(&s.env, "verify"),
+            (s.payer.clone(), seal, image_id, journal).into_val(&s.env),
+        ))
+    );
+}
+
+#[test]
+fn verify_does_not_charge_invalid_proof() {
+    let s = setup(FEE);
+    let journal = BytesN::from_array(&s.env, &[0x01; 32]);
+    let (seal, image_id) = prove(&s, &BytesN::from_array(&s.env, &[0x02; 32]));
+
+    assert!(
+        s.fees
+            .try_verify(&s.payer, &seal, &image_id, &journal)
+            .is_err()
+    );
+    assert_eq!(s.token.balance(&s.treasury), 0);
+    assert_eq!(s.token.allowance(&s.payer, &s.fees.address), FEE * 2);
+}
+
+#[test]
+fn verify_stops_at_allowance() {
+    let s = setup(FEE);
+    let journal = BytesN::from_array(&s.env, &[0x01; 32]);
+    let (seal, image_id) = prove(&s, &journal);
+
+    s.fees.verify(&s.payer, &seal, &image_id, &journal);
+    s.fees.verify(&s.payer, &seal, &image_id, &journal);
+    assert!(
+        s.fees
+            .try_verify(&s.payer, &seal, &image_id, &journal)
+            .is_err()
+    );
+    assert_eq!(s.token.balance(&s.treasury), FEE * 2);
+}
+
+#[test]
+fn verify_without_fee_skips_transfer() {
+    let s = setup(0);
+    let payer = Address::generate(&s.env);
+    let journal = BytesN::from_array(&s.env, &[0x01; 32]);
+    let (seal, image_id) = prove(&s, &journal);
+
+    // No balance nor allowance needed.
+    s.fees.verify(&payer, &seal, &image_id, &journal);
+
+    assert_eq!(s.token.balance(&s.treasury), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #800)")]
+fn constructor_rejects_negative_fee() {
+    setup(-1);
+}
+
+#[test]
+fn error_codes_are_stable() {
+    // Part of the public ABI, see the error registry in `risc0-interface`.
+    assert_eq!(VerificationFeesError::NegativeFee as u32, 800);
+}
@@ -12,11 +12,15 @@
 # Options:
 #   -n, --network     Network to deploy (local|futurenet|testnet|mainnet)
 #   -a, --account     Account identity alias configured in Stellar CLI
+#   --admin           Address allowed to deprecate the verifier (default: deployer)
+#   --audit           Build with the `audit` feature's invariant assertions (not on mainnet)
 #   -h, --help        Show this help message
 #
 # Environment Variables:
 #   NETWORK              Same as --network
 #   ACCOUNT_NAME         Same as --account
+#   ADMIN_ADDRESS        Same as --admin
+#   AUDIT                Set to 1 for the same effect as --audit
 #
 
 set -euo pipefail
@@ -175,11 +179,19 @@ show_help() {
     echo -e "    ${GREEN}-a, --account${RESET} <IDENTITY>   Account identity alias from Stellar CLI"
     echo -e "                              ${DIM}(configured via 'stellar keys generate')${RESET}"
     echo ""
+    echo -e "    ${GREEN}--admin${RESET} <ADDRESS>          Address allowed to deprecate the verifier"
+    echo -e "                              ${DIM}(defaults to the deployer address)${RESET}"
+    echo ""
+    echo -e "    ${GREEN}--audit${RESET}                    Build with extra invariant assertions"
+    echo -e "                              ${DIM}(testnet builds only, refused on mainnet)${RESET}"
+    echo ""
     echo -e "    ${GREEN}-h, --help${RESET}                 Show this help message"
     echo ""
     echo -e "${BOLD_WHITE}ENVIRONMENT VARIABLES${RESET}"
     echo -e "    ${YELLOW}NETWORK${RESET}              Override network selection"
     echo -e "    ${YELLOW}ACCOUNT_NAME${RESET}         Override account identity"
+    echo -e "    ${YELLOW}ADMIN_ADDRESS${RESET}        Override verifier admin"
+    echo -e "    ${YELLOW}AUDIT${RESET}                Set to 1 to build the audit variant"
     echo ""
     echo -e "${BOLD_WHITE}EXAMPLES${RESET}"
     echo -e "    ${DIM}# Deploy to testnet with identity 'deployer'${RESET}"
@@ -196,6 +208,8 @@ show_help() {
 # Default values
 NETWORK="${NETWORK:-}"
 ACCOUNT="${ACCOUNT_NAME:-${IDENTITY_NAME:-}}"
+ADMIN="${ADMIN_ADDRESS:-}"
+AUDIT="${AUDIT:-0}"
 
 # Parse arguments
 while [[ $# -gt 0 ]]; do
@@ -208,6 +222,14 @@ while [[ $# -gt 0 ]]; do
             ACCOUNT="$2"
             shift 2
             ;;
+        --admin)
+            ADMIN="$2"
+            shift 2
+            ;;
+        --audit)
+            AUDIT=1
+            shift
+            ;;
         -h|--help)
             show_help
             exit 0
@@ -301,6 +323,9 @@ main() {
     success "Account: ${BOLD_GREEN}$ACCOUNT${RESET}"
     info "Address: ${DIM}$DEPLOYER_ADDRESS${RESET}"
 
+    ADMIN="${ADMIN:-$DEPLOYER_ADDRESS}"
+    info "Admin: ${DIM}$ADMIN${RESET}"
+
     success "Contract: ${BOLD_YELLOW}groth16-verifier${RESET}"
 
     print_section_end
@@ -313,8 +338,20 @@ main() {
     # ── Build Contract ───────────────────────────────────────────────────────
     print_section "Building Contract"
 
+    # The audit variant only adds assertions, so mainnet always gets the lean build
+    local build_args=(--optimize)
+    if [[ "$AUDIT" == "1" ]]; then
+        if [[ "$NETWORK" == "mainnet" ]]; then
+            error "The audit build is not meant for mainnet"
+            print_section_end
+            exit 1
+        fi
+        build_args+=(--package groth16-verifier --features audit)
+        info "Variant: ${DIM}audit${RESET}"
+    fi
+
     # Build and optimize contract with spinner
-    stellar contract build --optimize > /tmp/build_output.txt 2>&1 &
+    stellar contract build "${build_args[@]}" > /tmp/build_output.txt 2>&1 &
     local build_pid=$!
     spinner $build_pid "Building and optimizing groth16-verifier..."
     wait $build_pid
@@ -352,10 +389,10 @@ main() {
         warn "Could not extract parameters from build output"
         info "Reading from parameters.json..."
 
-        if [[ -f "contracts/groth16-verifier/parameters.json" ]]; then
-            VERSION=$(jq -r '.version // "N/A"' "contracts/groth16-verifier/parameters.json")
-            CONTROL_ROOT=$(jq -r '.control_root // "N/A"' "contracts/groth16-verifier/parameters.json")
-            BN254_CONTROL_ID=$(jq -r '.bn254_control_id // "N/A"' "contracts/groth16-verifier/parameters.json")
+        if [[ -f "crates/params/parameters.json" ]]; then
+            VERSION=$(jq -r '.version // "N/A"' "crates/params/parameters.json")
+            CONTROL_ROOT=$(jq -r '.control_root // "N/A"' "crates/params/parameters.json")
+            BN254_CONTROL_ID=$(jq -r '.bn254_control_id // "N/A"' "crates/params/parameters.json")
             SELECTOR="${DIM}(computed at build time)${RESET}"
             VK_DIGEST="${DIM}(computed at build time)${RESET}"
         fi
@@ -419,6 +456,8 @@ main() {
         --source "$ACCOUNT" \
         --network "$NETWORK" \
         --alias groth16-verifier \
+        -- \
+        --admin "$ADMIN" \
         > /tmp/deploy_output.txt 2>&1 &
     local deploy_pid=$!
     spinner $deploy_pid "Deploying to $NETWORK..."
@@ -446,6 +485,8 @@ main() {
     kv "Contract" "groth16-verifier" "$WHITE" "$BOLD_CYAN"
     kv "Network" "$NETWORK" "$WHITE" "$BOLD_MAGENTA"
     kv "Deployer" "$ACCOUNT" "$WHITE" "$BOLD_GREEN"
+    kv "Admin" "$ADMIN" "$WHITE" "$BOLD_GREEN"
+    kv "Audit build" "$([[ "$AUDIT" == "1" ]] && echo yes || echo no)" "$WHITE" "$BOLD_YELLOW"
     print_divider
     echo -e "${BOLD_BLUE}│${RESET}"
     echo -e "${BOLD_BLUE}│${RESET}    ${BOLD_WHITE}CONTRACT ID:${RESET}"
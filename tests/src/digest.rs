@@ -0,0 +1,87 @@
+//! Property tests checking that the claim digests computed on-chain match `risc0_zkvm`.
+//!
+//! The verifier only accepts a proof if the claim digest is recomputed exactly as the prover
+//! computed it, so any drift from the upstream digest scheme silently rejects every receipt.
+
+use proptest::prelude::*;
+use risc0_interface::ReceiptClaim;
+use risc0_zkvm::{
+    MaybePruned,
+    sha::{self, Digest, Digestible},
+};
+use soroban_sdk::{Bytes, BytesN, Env};
+
+/// Computes the claim digest of a successful execution with the upstream implementation.
+fn upstream_claim_digest(image_id: [u8; 32], journal_digest: [u8; 32]) -> [u8; 32] {
+    let claim = risc0_zkvm::ReceiptClaim::ok(
+        Digest::from_bytes(image_id),
+        MaybePruned::Pruned(Digest::from_bytes(journal_digest)),
+    );
+    claim
+        .digest::<sha::Impl>()
+        .as_bytes()
+        .try_into()
+        .expect("digests are 32 bytes")
+}
+
+proptest! {
+    #[test]
+    fn claim_digest_matches_upstream(image_id: [u8; 32], journal_digest: [u8; 32]) {
+        let env = Env::default();
+        let claim = ReceiptClaim::new(
+            &env,
+            BytesN::from_array(&env, &image_id),
+            BytesN::from_array(&env, &journal_digest),
+        );
+
+        prop_assert_eq!(
+            claim.digest(&env).to_array(),
+            upstream_claim_digest(image_id, journal_digest)
+        );
+    }
+
+    #[test]
+    fn claim_digest_matches_upstream_for_journal(
+        image_id: [u8; 32],
+        journal in prop::collection::vec(any::<u8>(), 0..256),
+    ) {
+        let env = Env::default();
+        let journal_digest = env.crypto().sha256(&Bytes::from_slice(&env, &journal));
+        let claim = ReceiptClaim::new(
+            &env,
+            BytesN::from_array(&env, &image_id),
+            journal_digest.into(),
+        );
+
+        let upstream = risc0_zkvm::ReceiptClaim::ok(Digest::from_bytes(image_id), journal);
+        prop_assert_eq!(
+            claim.digest(&env).to_array().as_slice(),
+            upstream.digest::<sha::Impl>().as_bytes()
+        );
+    }
+
+    #[test]
+    fn claim_digest_matches_upstream_for_user_exit_code(
+        image_id: [u8; 32],
+        journal_digest: [u8; 32],
+        user_exit_code: u32,
+    ) {
+        let env = Env::default();
+        let claim = ReceiptClaim::with_user_exit_code(
+            &env,
+            BytesN::from_array(&env, &image_id),
+            BytesN::from_array(&env, &journal_digest),
+            user_exit_code,
+        );
+
+        let mut upstream = risc0_zkvm::ReceiptClaim::ok(
+            Digest::from_bytes(image_id),
+            MaybePruned::Pruned(Digest::from_bytes(journal_digest)),
+        );
+        upstream.exit_code = risc0_zkvm::ExitCode::Halted(user_exit_code);
+        prop_assert_eq!(
+            claim.digest(&env).to_array().as_slice(),
+            upstream.digest::<sha::Impl>().as_bytes()
+        );
+    }
+}
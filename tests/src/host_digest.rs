@@ -0,0 +1,127 @@
+//! Property tests checking the host-backed `Output` and `ReceiptClaim` digests against a
+//! pure-Rust reference implementation.
+//!
+//! The contracts assemble digest preimages in host `Bytes` and hash them with
+//! `env.crypto().sha256`. The reference below spells out each preimage byte by byte and hashes
+//! it with `sha2`, so a field appended in the wrong order, a misencoded exit code, or a
+//! truncated word on the host path shows up as a mismatch for random inputs.
+
+use proptest::prelude::*;
+use risc0_interface::{ExitCode, Output, ReceiptClaim, SystemExitCode};
+use sha2::{Digest as _, Sha256};
+use soroban_sdk::{BytesN, Env};
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+/// Reference digest of a RISC Zero `Output` struct.
+fn reference_output_digest(journal_digest: [u8; 32], assumptions_digest: [u8; 32]) -> [u8; 32] {
+    let mut preimage = sha256(b"risc0.Output").to_vec();
+    preimage.extend_from_slice(&journal_digest);
+    preimage.extend_from_slice(&assumptions_digest);
+    preimage.extend_from_slice(&2u16.to_le_bytes());
+    sha256(&preimage)
+}
+
+/// Reference digest of a RISC Zero `ReceiptClaim` struct.
+fn reference_claim_digest(
+    pre_state_digest: [u8; 32],
+    post_state_digest: [u8; 32],
+    system_exit: u32,
+    user_exit: u32,
+    input: [u8; 32],
+    output: [u8; 32],
+) -> [u8; 32] {
+    let mut preimage = sha256(b"risc0.ReceiptClaim").to_vec();
+    preimage.extend_from_slice(&input);
+    preimage.extend_from_slice(&pre_state_digest);
+    preimage.extend_from_slice(&post_state_digest);
+    preimage.extend_from_slice(&output);
+    preimage.extend_from_slice(&system_exit.to_le_bytes());
+    preimage.extend_from_slice(&user_exit.to_le_bytes());
+    preimage.extend_from_slice(&4u16.to_le_bytes());
+    sha256(&preimage)
+}
+
+fn system_exit_code() -> impl Strategy<Value = SystemExitCode> {
+    prop_oneof![
+        Just(SystemExitCode::Halted),
+        Just(SystemExitCode::Paused),
+        Just(SystemExitCode::SystemSplit),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn output_digest_matches_reference(journal_digest: [u8; 32], assumptions_digest: [u8; 32]) {
+        let env = Env::default();
+        let output = Output::new(
+            BytesN::from_array(&env, &journal_digest),
+            BytesN::from_array(&env, &assumptions_digest),
+        );
+
+        prop_assert_eq!(
+            output.digest(&env).to_array(),
+            reference_output_digest(journal_digest, assumptions_digest)
+        );
+    }
+
+    #[test]
+    fn claim_digest_matches_reference(
+        pre_state_digest: [u8; 32],
+        post_state_digest: [u8; 32],
+        system in system_exit_code(),
+        user_exit: u32,
+        input: [u8; 32],
+        output: [u8; 32],
+    ) {
+        let env = Env::default();
+        let claim = ReceiptClaim::from_parts(
+            BytesN::from_array(&env, &pre_state_digest),
+            BytesN::from_array(&env, &post_state_digest),
+            ExitCode::new(system, user_exit),
+            BytesN::from_array(&env, &input),
+            BytesN::from_array(&env, &output),
+        );
+
+        prop_assert_eq!(
+            claim.digest(&env).to_array(),
+            reference_claim_digest(
+                pre_state_digest,
+                post_state_digest,
+                system as u32,
+                user_exit,
+                input,
+                output,
+            )
+        );
+    }
+
+    #[test]
+    fn user_exit_claim_digest_matches_reference(
+        image_id: [u8; 32],
+        journal_digest: [u8; 32],
+        user_exit: u32,
+    ) {
+        let env = Env::default();
+        let claim = ReceiptClaim::with_user_exit_code(
+            &env,
+            BytesN::from_array(&env, &image_id),
+            BytesN::from_array(&env, &journal_digest),
+            user_exit,
+        );
+
+        prop_assert_eq!(
+            claim.digest(&env).to_array(),
+            reference_claim_digest(
+                image_id,
+                ReceiptClaim::POST_STATE_DIGEST_HALTED,
+                SystemExitCode::Halted as u32,
+                user_exit,
+                [0u8; 32],
+                reference_output_digest(journal_digest, [0u8; 32]),
+            )
+        );
+    }
+}
@@ -0,0 +1,43 @@
+//! Property tests checking that `risc0_interface::journal` encodes values exactly like the
+//! guest's `env::commit`, i.e. like `risc0_zkvm::serde`.
+//!
+//! A mismatch would make application contracts compute a journal digest that no guest ever
+//! commits, so every proof bound through the helper would be rejected.
+
+use proptest::prelude::*;
+use risc0_interface::journal;
+use soroban_sdk::{Bytes, BytesN, Env};
+
+/// Flattens upstream serde words into the committed journal bytes.
+fn journal_bytes(words: Vec<u32>) -> Vec<u8> {
+    words.into_iter().flat_map(u32::to_le_bytes).collect()
+}
+
+proptest! {
+    #[test]
+    fn journal_encoding_matches_upstream(
+        flag: bool,
+        word: u32,
+        signed: i32,
+        wide: i64,
+        amount: i128,
+        digest: [u8; 32],
+        payload in prop::collection::vec(any::<u8>(), 0..64),
+    ) {
+        let env = Env::default();
+        let value = (
+            (flag, word, signed, wide),
+            amount,
+            BytesN::from_array(&env, &digest),
+            Bytes::from_slice(&env, &payload),
+        );
+        let encoded = journal::encode(&env, &value);
+
+        let upstream =
+            risc0_zkvm::serde::to_vec(&((flag, word, signed, wide), amount, digest, payload))
+                .expect("values are serializable");
+        let mut bytes = vec![0u8; encoded.len() as usize];
+        encoded.copy_into_slice(&mut bytes);
+        prop_assert_eq!(bytes, journal_bytes(upstream));
+    }
+}
@@ -0,0 +1,125 @@
+//! Known-answer tests (KATs) loaded from the JSON fixtures in `tests/kat/`.
+//!
+//! Every `*.json` file in the directory is a fixture file, run against the production stack
+//! deployed by [`deploy`]. New fixtures (e.g. receipts of a new RISC Zero release) only need a
+//! file dropped in the directory, no Rust. A fixture file is:
+//!
+//! ```json
+//! {
+//!     "version": 1,
+//!     "description": "Free-form description of the fixtures",
+//!     "cases": [
+//!         {
+//!             "name": "valid_receipt",
+//!             "description": "Free-form description of the case (optional)",
+//!             "seal": "73c457ba...",
+//!             "image_id": "a77e5491...",
+//!             "journal": "01000078",
+//!             "error": null
+//!         }
+//!     ]
+//! }
+//! ```
+//!
+//! - `seal`, `image_id` and `journal` are hex, with an optional `0x` prefix. `journal` is the
+//!   raw journal, which the loader hashes into the journal digest.
+//! - `error` is `null` if the router accepts the receipt, and otherwise the numeric
+//!   `VerifierError` code it rejects it with, e.g. `1` for `InvalidProof`.
+//!
+//! `version` is the version of this format, currently 1.
+
+use serde::Deserialize;
+use soroban_sdk::{Bytes, BytesN};
+
+use crate::{
+    test::deploy,
+    testutils::{fixture_files, load_fixture},
+};
+
+/// The version of the fixture format read by this loader.
+const FORMAT_VERSION: u32 = 1;
+
+/// A fixture file.
+///
+/// Unknown fields are rejected, so that a misspelled `error` fails loudly instead of turning
+/// into an expected success.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KatFile {
+    version: u32,
+    #[allow(dead_code, reason = "documentation for fixture authors")]
+    description: String,
+    cases: Vec<KatCase>,
+}
+
+/// A single known-answer test.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KatCase {
+    name: String,
+    #[serde(default)]
+    description: String,
+    seal: String,
+    image_id: String,
+    journal: String,
+    error: Option<u32>,
+}
+
+fn decode_hex(value: &str, field: &str, case: &str) -> Vec<u8> {
+    hex::decode(value.strip_prefix("0x").unwrap_or(value))
+        .unwrap_or_else(|err| panic!("{case}: invalid hex for {field}: {err}"))
+}
+
+/// Runs `case` through the router and returns its outcome as an error code, `None` on
+/// success.
+fn run_case(case: &KatCase) -> Option<u32> {
+    let s = deploy();
+    let seal = Bytes::from_slice(&s.env, &decode_hex(&case.seal, "seal", &case.name));
+    let image_id: [u8; 32] = decode_hex(&case.image_id, "image_id", &case.name)
+        .try_into()
+        .unwrap_or_else(|_| panic!("{}: image_id must be 32 bytes", case.name));
+    let journal = Bytes::from_slice(&s.env, &decode_hex(&case.journal, "journal", &case.name));
+
+    let journal_digest: BytesN<32> = s.env.crypto().sha256(&journal).into();
+    match s.router.try_verify(
+        &seal,
+        &BytesN::from_array(&s.env, &image_id),
+        &journal_digest,
+    ) {
+        Ok(_) => None,
+        Err(Ok(error)) => Some(error as u32),
+        Err(Err(error)) => panic!("{}: not a verifier error: {error:?}", case.name),
+    }
+}
+
+#[test]
+fn known_answer_tests() {
+    let files = fixture_files("kat");
+    assert!(!files.is_empty(), "no fixture files in tests/kat");
+
+    let mut failures = Vec::new();
+    for path in files {
+        let file: KatFile = load_fixture(&path);
+        assert_eq!(
+            file.version,
+            FORMAT_VERSION,
+            "{}: unsupported fixture format version",
+            path.display()
+        );
+
+        for case in &file.cases {
+            let actual = run_case(case);
+            if actual != case.error {
+                failures.push(format!(
+                    "{}: {} ({}): expected {:?}, got {:?}",
+                    path.display(),
+                    case.name,
+                    case.description,
+                    case.error,
+                    actual
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,35 @@
+//! # Integration Tests
+//!
+//! Multi-contract scenarios exercising the verifier contracts together in a single `Env`,
+//! catching cross-contract bugs that the per-contract unit tests cannot see.
+//!
+//! Every scenario deploys the production stack:
+//!
+//! ```text
+//! RiscZeroVerifierRouter ──(Groth16 selector)──▶ RiscZeroVerifierEmergencyStop ──▶ RiscZeroGroth16Verifier
+//! ```
+//!
+//! and verifies a real Groth16 receipt through it, directly and from a consumer contract
+//! gated with `risc0_macros::require_risc0_proof`. Known-answer fixtures in `kat/` run
+//! through the same stack, and `snapshot` pins the ledger state and events of verification
+//! transactions against the `Env` snapshots in `snapshots/`. `testutils` holds the shared
+//! `Env` builder, contract registration helpers and fixtures.
+//!
+//! It also cross-checks the on-chain digest scheme and journal encoding against the upstream
+//! `risc0_zkvm` crate, and the host-backed digests against a pure-Rust reference, with property
+//! tests. This crate has no library code.
+
+#[cfg(test)]
+mod digest;
+#[cfg(test)]
+mod host_digest;
+#[cfg(test)]
+mod journal;
+#[cfg(test)]
+mod kat;
+#[cfg(test)]
+mod snapshot;
+#[cfg(test)]
+mod test;
+#[cfg(test)]
+mod testutils;
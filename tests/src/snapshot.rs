@@ -0,0 +1,171 @@
+//! Golden `Env` snapshots of full verification transactions.
+//!
+//! Each scenario deploys the production stack, runs transactions through it, and compares the
+//! `Env` snapshot of those transactions with the one committed in `tests/snapshots/`: the
+//! ledger entries they wrote, the events of the last one, the authorizations they required,
+//! and the ledger header and generators. The deployment is left out, so the snapshots only
+//! change with what the transactions do. An upgrade of `soroban-sdk` whose host changes what a
+//! transaction does, e.g. a different result from a bn254 host function, shows up as a diff of
+//! the snapshot instead of passing silently.
+//!
+//! After an intended change, regenerate the snapshots with
+//!
+//! ```text
+//! UPDATE_SNAPSHOTS=1 cargo test -p integration-tests snapshot
+//! ```
+//!
+//! and review the diff before committing it.
+
+use std::{env, fs, path::PathBuf};
+
+use risc0_interface::{CircuitBreaker, VerifierError};
+use risc0_router::RiscZeroVerifierRouterClient;
+use soroban_sdk::{
+    Address, BytesN, Env,
+    testutils::{Address as _, Snapshot},
+    vec,
+};
+
+use crate::testutils::{
+    BudgetPreset, TestEnvBuilder, register_emergency_stop, register_groth16_verifier,
+    register_router, test_inputs,
+};
+
+/// Marks the end of a scenario's setup: the snapshot only covers what happens afterwards.
+struct Recording {
+    /// Snapshot of the `Env` at the end of the setup.
+    setup: Snapshot,
+}
+
+impl Recording {
+    fn start(env: &Env) -> Self {
+        Self {
+            setup: env.to_snapshot(),
+        }
+    }
+
+    /// Compares the snapshot of the recorded transactions with `tests/snapshots/<name>.json`,
+    /// or overwrites it if `UPDATE_SNAPSHOTS` is set.
+    ///
+    /// Ledger entries the transactions created or changed are kept; those they removed are not
+    /// recorded.
+    fn assert_snapshot(self, env: &Env, name: &str) {
+        let mut snapshot = env.to_snapshot();
+        snapshot
+            .ledger
+            .ledger_entries
+            .retain(|entry| !self.setup.ledger.ledger_entries.contains(entry));
+        snapshot.auth.0.drain(..self.setup.auth.0.len());
+
+        let mut actual = std::vec::Vec::new();
+        snapshot.write(&mut actual).expect("snapshot serializes");
+        let actual = String::from_utf8(actual).expect("snapshot is UTF-8");
+        compare(&actual, name);
+    }
+}
+
+/// Compares `actual` with `tests/snapshots/<name>.json`, or overwrites it if
+/// `UPDATE_SNAPSHOTS` is set.
+fn compare(actual: &str, name: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(format!("{name}.json"));
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, format!("{actual}\n"))
+            .unwrap_or_else(|err| panic!("cannot write {}: {err}", path.display()));
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "cannot read {}: {err}; run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+    if let Some((line, (expected, actual))) = expected
+        .lines()
+        .zip(actual.lines())
+        .enumerate()
+        .find(|(_, (expected, actual))| expected != actual)
+    {
+        panic!(
+            "{}:{}: snapshot differs\n  expected: {expected}\n  actual:   {actual}\n\
+             run with UPDATE_SNAPSHOTS=1 and review the diff if the change is intended",
+            path.display(),
+            line + 1
+        );
+    }
+    assert_eq!(
+        expected.lines().count(),
+        actual.lines().count(),
+        "{}: snapshot differs in length; run with UPDATE_SNAPSHOTS=1 and review the diff if \
+         the change is intended",
+        path.display()
+    );
+}
+
+/// Deploys the production stack, with a single holder of every role, and returns its router
+/// and the role holder.
+fn deploy(env: &Env) -> (RiscZeroVerifierRouterClient<'static>, Address) {
+    let admin = Address::generate(env);
+    let groth16 = register_groth16_verifier(env, &admin);
+    let estop = register_emergency_stop(env, &groth16.address, &admin, &admin);
+    let router = register_router(
+        env,
+        &admin,
+        &admin,
+        &admin,
+        vec![env, (groth16.selector(), estop.address.clone())],
+    );
+    (router, admin)
+}
+
+#[test]
+fn snapshot_verify_through_stack() {
+    let env = TestEnvBuilder::new()
+        .budget(BudgetPreset::Unlimited)
+        .build();
+    let (router, _) = deploy(&env);
+    let (seal, image_id, journal_digest) = test_inputs(&env);
+
+    let recording = Recording::start(&env);
+    router.verify(&seal, &image_id, &journal_digest);
+
+    recording.assert_snapshot(&env, "verify_through_stack");
+}
+
+/// The circuit breaker's window records the outcome of every verification in the ledger, so
+/// the snapshot pins both an accepted and a rejected receipt.
+#[test]
+fn snapshot_report_verification() {
+    let env = TestEnvBuilder::new()
+        .budget(BudgetPreset::Unlimited)
+        .build();
+    let (router, operator) = deploy(&env);
+    router.set_circuit_breaker(
+        &Some(CircuitBreaker {
+            max_failure_bps: 10_000,
+            min_verifications: 10,
+            window_ledgers: 100,
+        }),
+        &operator,
+    );
+    let (seal, image_id, journal_digest) = test_inputs(&env);
+
+    let recording = Recording::start(&env);
+    assert_eq!(
+        router.report_verification(&seal, &image_id, &journal_digest, &operator),
+        None
+    );
+    assert_eq!(
+        router.report_verification(
+            &seal,
+            &image_id,
+            &BytesN::from_array(&env, &[1; 32]),
+            &operator
+        ),
+        Some(VerifierError::InvalidProof as u32)
+    );
+
+    recording.assert_snapshot(&env, "report_verification");
+}
@@ -0,0 +1,287 @@
+use emergency_stop::RiscZeroVerifierEmergencyStopClient;
+use risc0_interface::VerifierError;
+use risc0_macros::require_risc0_proof;
+use risc0_router::RiscZeroVerifierRouterClient;
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger as _},
+    vec,
+};
+
+use crate::testutils::{
+    TEST_IMAGE_ID, TEST_JOURNAL, TEST_SEAL, TestEnvBuilder, register_emergency_stop,
+    register_groth16_verifier, register_router, test_inputs, test_receipt,
+};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// The deployed verification stack and its role holders.
+pub(crate) struct Stack {
+    pub(crate) env: Env,
+    admin: Address,
+    operator: Address,
+    guardian: Address,
+    selector: BytesN<4>,
+    groth16: Address,
+    estop: RiscZeroVerifierEmergencyStopClient<'static>,
+    pub(crate) router: RiscZeroVerifierRouterClient<'static>,
+}
+
+/// Deploys the Groth16 verifier behind an emergency stop, registered in the router under its
+/// selector.
+pub(crate) fn deploy() -> Stack {
+    let env = TestEnvBuilder::new().build();
+
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let guardian = Address::generate(&env);
+
+    let groth16 = register_groth16_verifier(&env, &admin);
+    let selector = groth16.selector();
+
+    let estop = register_emergency_stop(&env, &groth16.address, &admin, &guardian);
+    let router = register_router(
+        &env,
+        &admin,
+        &operator,
+        &guardian,
+        vec![&env, (selector.clone(), estop.address.clone())],
+    );
+
+    Stack {
+        groth16: groth16.address,
+        estop,
+        router,
+        env,
+        admin,
+        operator,
+        guardian,
+        selector,
+    }
+}
+
+// =============================================================================
+// Scenarios
+// =============================================================================
+
+#[test]
+fn router_verifies_through_estop() {
+    let s = deploy();
+    let (seal, image_id, journal_digest) = test_inputs(&s.env);
+
+    s.router.verify(&seal, &image_id, &journal_digest);
+    s.router.verify_integrity(&test_receipt(&s.env));
+    s.router.healthcheck(&s.selector);
+    assert_eq!(s.router.get_verifier_from_seal(&seal), s.estop.address);
+}
+
+#[test]
+fn router_rejects_tampered_journal() {
+    let s = deploy();
+    let (seal, image_id, _journal_digest) = test_inputs(&s.env);
+    let journal_digest = BytesN::from_array(&s.env, &[0x42; 32]);
+
+    assert!(
+        s.router
+            .try_verify(&seal, &image_id, &journal_digest)
+            .is_err()
+    );
+}
+
+#[test]
+fn estop_stops_verification_through_router() {
+    let s = deploy();
+    let (seal, image_id, journal_digest) = test_inputs(&s.env);
+    s.router.verify(&seal, &image_id, &journal_digest);
+
+    s.estop.estop(&s.guardian);
+
+    // The pause error is outside `VerifierError`, so the router reports the verifier as
+    // unavailable.
+    assert_eq!(
+        s.router.try_verify(&seal, &image_id, &journal_digest),
+        Err(Ok(VerifierError::VerifierUnavailable))
+    );
+    assert_eq!(
+        s.router.try_verify_integrity(&test_receipt(&s.env)),
+        Err(Ok(VerifierError::VerifierUnavailable))
+    );
+    assert_eq!(
+        s.router.try_healthcheck(&s.selector),
+        Err(Ok(VerifierError::VerifierUnavailable))
+    );
+}
+
+#[test]
+fn temporary_pause_resumes_through_router() {
+    let s = deploy();
+    let (seal, image_id, journal_digest) = test_inputs(&s.env);
+    let start = s.env.ledger().sequence();
+
+    s.estop.pause_for(&100, &s.guardian);
+    assert!(
+        s.router
+            .try_verify(&seal, &image_id, &journal_digest)
+            .is_err()
+    );
+
+    s.env.ledger().set_sequence_number(start + 100);
+    s.router.verify(&seal, &image_id, &journal_digest);
+}
+
+#[test]
+fn removed_selector_cannot_be_reregistered() {
+    let s = deploy();
+    let (seal, image_id, journal_digest) = test_inputs(&s.env);
+
+    s.router.remove_verifier(&s.selector, &s.guardian);
+    assert_eq!(
+        s.router.try_verify(&seal, &image_id, &journal_digest),
+        Err(Ok(VerifierError::SelectorRemoved))
+    );
+
+    // Neither the emergency stop nor the bare verifier can take the selector back.
+    for verifier in [&s.estop.address, &s.groth16] {
+        assert_eq!(
+            s.router
+                .try_add_verifier(&s.selector, verifier, &s.operator),
+            Err(Ok(VerifierError::SelectorRemoved))
+        );
+    }
+}
+
+#[test]
+fn revoked_claim_rejected_despite_valid_proof() {
+    let s = deploy();
+    let (seal, image_id, journal_digest) = test_inputs(&s.env);
+    let receipt = test_receipt(&s.env);
+
+    s.router.revoke_claim(&receipt.claim_digest, &s.guardian);
+
+    assert_eq!(
+        s.router.try_verify(&seal, &image_id, &journal_digest),
+        Err(Ok(VerifierError::ClaimRevoked))
+    );
+    assert_eq!(
+        s.router.try_verify_integrity(&receipt),
+        Err(Ok(VerifierError::ClaimRevoked))
+    );
+}
+
+#[test]
+fn global_estop_in_front_of_router() {
+    let s = deploy();
+    let (seal, image_id, journal_digest) = test_inputs(&s.env);
+
+    let global = register_emergency_stop(&s.env, &s.router.address, &s.admin, &s.guardian);
+    global.verify(&seal, &image_id, &journal_digest);
+
+    global.estop(&s.guardian);
+
+    assert!(
+        global
+            .try_verify(&seal, &image_id, &journal_digest)
+            .is_err()
+    );
+    // Only the global stop is triggered; the router keeps serving direct callers.
+    s.router.verify(&seal, &image_id, &journal_digest);
+}
+
+// =============================================================================
+// Consumer Macro
+// =============================================================================
+// An application contract gating its entrypoints with `require_risc0_proof`, verifying
+// through the router.
+
+#[contract]
+struct Consumer;
+
+/// Returns the router and image id the consumer accepts proofs from.
+fn consumer_config(env: &Env) -> (Address, BytesN<32>) {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("config"))
+        .expect("config is set in the constructor")
+}
+
+#[contractimpl]
+impl Consumer {
+    pub fn __constructor(env: Env, router: Address, image_id: BytesN<32>) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("config"), &(router, image_id));
+    }
+
+    /// Returns the word committed by the test guest.
+    #[require_risc0_proof(
+        verifier = consumer_config(&env).0,
+        image_id = consumer_config(&env).1,
+        decode = u32,
+    )]
+    pub fn consume(env: Env, seal: Bytes, journal: Bytes) -> u32 {
+        journal
+    }
+
+    /// Reads the journal as a wider value than the test guest commits.
+    #[require_risc0_proof(
+        verifier = consumer_config(&env).0,
+        image_id = consumer_config(&env).1,
+        decode = u64,
+        seal = proof,
+    )]
+    pub fn consume_wide(env: Env, proof: Bytes, journal: Bytes) -> u64 {
+        journal
+    }
+}
+
+fn deploy_consumer(s: &Stack) -> ConsumerClient<'static> {
+    let consumer = s.env.register(
+        Consumer,
+        (
+            s.router.address.clone(),
+            BytesN::from_array(&s.env, &TEST_IMAGE_ID),
+        ),
+    );
+    ConsumerClient::new(&s.env, &consumer)
+}
+
+#[test]
+fn consumer_receives_proven_journal() {
+    let s = deploy();
+    let consumer = deploy_consumer(&s);
+
+    let value = consumer.consume(
+        &Bytes::from_slice(&s.env, &TEST_SEAL),
+        &Bytes::from_slice(&s.env, &TEST_JOURNAL),
+    );
+    assert_eq!(value, u32::from_le_bytes(TEST_JOURNAL));
+}
+
+#[test]
+fn consumer_rejects_unproven_journal() {
+    let s = deploy();
+    let consumer = deploy_consumer(&s);
+    let journal = Bytes::from_array(&s.env, &[0x02, 0x00, 0x00, 0x78]);
+
+    assert!(
+        consumer
+            .try_consume(&Bytes::from_slice(&s.env, &TEST_SEAL), &journal)
+            .is_err()
+    );
+}
+
+#[test]
+fn consumer_rejects_journal_of_another_type() {
+    let s = deploy();
+    let consumer = deploy_consumer(&s);
+
+    assert_eq!(
+        consumer.try_consume_wide(
+            &Bytes::from_slice(&s.env, &TEST_SEAL),
+            &Bytes::from_slice(&s.env, &TEST_JOURNAL),
+        ),
+        Err(Ok(VerifierError::MalformedJournal.into()))
+    );
+}
@@ -0,0 +1,268 @@
+//! Shared setup for the integration tests.
+//!
+//! - [`TestEnvBuilder`]: an `Env` with a [`BudgetPreset`] and mocked authorizations
+//! - `register_*`: deploy a workspace contract with its constructor arguments and return its
+//!   client
+//! - Test vectors: the Groth16 receipt shared with the groth16-verifier unit tests, and
+//!   [`fixture_files`] / [`load_fixture`] for the JSON fixtures next to this crate
+//!
+//! The timelock is left out: its scenarios are covered by its own tests.
+
+use std::{fs, path::PathBuf};
+
+use emergency_stop::{RiscZeroVerifierEmergencyStop, RiscZeroVerifierEmergencyStopClient};
+use groth16_verifier::{RiscZeroGroth16Verifier, RiscZeroGroth16VerifierClient, SEAL_SIZE};
+use image_registry::{ImageRegistry, ImageRegistryClient};
+use mock_verifier::{RiscZeroMockVerifier, RiscZeroMockVerifierClient};
+use risc0_interface::{ImageMetadata, Receipt};
+use risc0_router::{RiscZeroVerifierRouter, RiscZeroVerifierRouterClient};
+use serde::de::DeserializeOwned;
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec, testutils::Address as _};
+
+// =============================================================================
+// Environment
+// =============================================================================
+
+/// Per-transaction CPU instruction limit of the Stellar network.
+pub(crate) const NETWORK_CPU_LIMIT: u64 = 100_000_000;
+
+/// Per-transaction memory limit of the Stellar network, in bytes.
+pub(crate) const NETWORK_MEM_LIMIT: u64 = 41_943_040;
+
+/// Memory limit of [`BudgetPreset::TightMemory`], in bytes.
+pub(crate) const TIGHT_MEM_LIMIT: u64 = NETWORK_MEM_LIMIT / 8;
+
+/// Resource limits applied to the invocations of a test `Env`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum BudgetPreset {
+    /// The per-transaction limits of the Stellar network.
+    #[default]
+    Mainnet,
+    /// No limits, for tests checking behavior rather than cost.
+    Unlimited,
+    /// The network CPU limit with an eighth of its memory, to catch memory regressions with
+    /// margin to spare.
+    TightMemory,
+}
+
+/// Builder of test `Env`s.
+///
+/// ```ignore
+/// let env = TestEnvBuilder::new().budget(BudgetPreset::Unlimited).build();
+/// ```
+#[derive(Clone, Debug)]
+pub(crate) struct TestEnvBuilder {
+    budget: BudgetPreset,
+    mock_all_auths: bool,
+}
+
+impl Default for TestEnvBuilder {
+    fn default() -> Self {
+        Self {
+            budget: BudgetPreset::default(),
+            mock_all_auths: true,
+        }
+    }
+}
+
+impl TestEnvBuilder {
+    /// Returns a builder for an `Env` with mainnet limits and mocked authorizations.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the resource limits of the `Env`.
+    pub(crate) fn budget(mut self, budget: BudgetPreset) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Sets whether every authorization is mocked, `true` by default.
+    pub(crate) fn mock_all_auths(mut self, mock_all_auths: bool) -> Self {
+        self.mock_all_auths = mock_all_auths;
+        self
+    }
+
+    /// Builds the `Env`.
+    pub(crate) fn build(self) -> Env {
+        let env = Env::default();
+        if self.mock_all_auths {
+            env.mock_all_auths();
+        }
+
+        let mut budget = env.cost_estimate().budget();
+        match self.budget {
+            BudgetPreset::Mainnet => budget.reset_limits(NETWORK_CPU_LIMIT, NETWORK_MEM_LIMIT),
+            BudgetPreset::Unlimited => budget.reset_unlimited(),
+            BudgetPreset::TightMemory => budget.reset_limits(NETWORK_CPU_LIMIT, TIGHT_MEM_LIMIT),
+        }
+        env
+    }
+}
+
+// =============================================================================
+// Contract Registration
+// =============================================================================
+
+/// Registers a Groth16 verifier administered by `admin`.
+pub(crate) fn register_groth16_verifier(
+    env: &Env,
+    admin: &Address,
+) -> RiscZeroGroth16VerifierClient<'static> {
+    let address = env.register(RiscZeroGroth16Verifier, (admin.clone(),));
+    RiscZeroGroth16VerifierClient::new(env, &address)
+}
+
+/// Registers an emergency stop in front of `verifier`.
+pub(crate) fn register_emergency_stop(
+    env: &Env,
+    verifier: &Address,
+    admin: &Address,
+    guardian: &Address,
+) -> RiscZeroVerifierEmergencyStopClient<'static> {
+    let address = env.register(
+        RiscZeroVerifierEmergencyStop,
+        (verifier.clone(), admin.clone(), guardian.clone()),
+    );
+    RiscZeroVerifierEmergencyStopClient::new(env, &address)
+}
+
+/// Registers a router with its role holders and initial `(selector, verifier)` entries.
+pub(crate) fn register_router(
+    env: &Env,
+    admin: &Address,
+    operator: &Address,
+    guardian: &Address,
+    verifiers: Vec<(BytesN<4>, Address)>,
+) -> RiscZeroVerifierRouterClient<'static> {
+    let address = env.register(
+        RiscZeroVerifierRouter,
+        (admin.clone(), operator.clone(), guardian.clone(), verifiers),
+    );
+    RiscZeroVerifierRouterClient::new(env, &address)
+}
+
+/// Registers a mock verifier accepting seals made of `selector` alone.
+pub(crate) fn register_mock_verifier(
+    env: &Env,
+    selector: &BytesN<4>,
+) -> RiscZeroMockVerifierClient<'static> {
+    let address = env.register(RiscZeroMockVerifier, (selector.clone(),));
+    RiscZeroMockVerifierClient::new(env, &address)
+}
+
+/// Registers an image registry.
+pub(crate) fn register_image_registry(env: &Env) -> ImageRegistryClient<'static> {
+    let address = env.register(ImageRegistry, ());
+    ImageRegistryClient::new(env, &address)
+}
+
+// =============================================================================
+// Test Vectors
+// =============================================================================
+// Groth16 receipt shared with the groth16-verifier unit tests.
+
+pub(crate) const TEST_SEAL: [u8; SEAL_SIZE] = [
+    115, 196, 87, 186, 0, 237, 128, 235, 234, 82, 162, 215, 108, 219, 83, 253, 51, 151, 104, 190,
+    16, 27, 191, 115, 52, 20, 229, 22, 168, 155, 98, 214, 70, 109, 143, 168, 39, 163, 217, 215,
+    117, 155, 119, 189, 172, 46, 218, 8, 164, 36, 138, 163, 47, 66, 185, 51, 132, 186, 120, 68,
+    221, 173, 16, 91, 83, 154, 236, 240, 16, 135, 147, 199, 205, 147, 71, 212, 179, 74, 227, 197,
+    227, 148, 79, 255, 80, 116, 63, 60, 170, 174, 73, 33, 155, 190, 178, 211, 40, 104, 86, 133, 10,
+    5, 96, 15, 143, 195, 135, 173, 205, 13, 185, 87, 103, 138, 0, 115, 115, 112, 161, 19, 129, 254,
+    146, 216, 198, 153, 50, 139, 200, 104, 181, 15, 38, 239, 108, 112, 252, 67, 176, 221, 131, 101,
+    167, 44, 11, 201, 135, 216, 18, 128, 33, 146, 39, 28, 36, 140, 236, 249, 13, 70, 58, 47, 111,
+    147, 24, 26, 248, 151, 128, 30, 5, 148, 41, 172, 252, 33, 245, 34, 165, 60, 97, 133, 128, 111,
+    105, 241, 23, 184, 109, 191, 86, 40, 187, 198, 73, 117, 2, 109, 28, 132, 149, 6, 243, 7, 121,
+    100, 208, 124, 26, 204, 213, 137, 61, 33, 83, 93, 40, 164, 222, 86, 35, 238, 99, 177, 16, 168,
+    241, 210, 8, 57, 248, 143, 79, 105, 86, 248, 56, 157, 41, 90, 192, 78, 112, 102, 135, 217, 204,
+    56, 22, 57, 168, 230, 57, 33, 30, 155, 70, 128, 49, 27,
+];
+
+pub(crate) const TEST_IMAGE_ID: [u8; 32] = [
+    0xa7, 0x7e, 0x54, 0x91, 0x0c, 0x79, 0x2d, 0xdc, 0x3f, 0x14, 0x87, 0x8f, 0x3f, 0x13, 0x60, 0xaf,
+    0x96, 0x61, 0x24, 0x08, 0xd6, 0x90, 0x74, 0xe8, 0x73, 0x89, 0xa2, 0x15, 0xf5, 0x75, 0x95, 0xb9,
+];
+
+pub(crate) const TEST_JOURNAL: [u8; 4] = [0x01, 0x00, 0x00, 0x78];
+
+/// Returns the seal, image id and journal digest of the test receipt.
+pub(crate) fn test_inputs(env: &Env) -> (Bytes, BytesN<32>, BytesN<32>) {
+    let seal = Bytes::from_slice(env, &TEST_SEAL);
+    let image_id = BytesN::from_array(env, &TEST_IMAGE_ID);
+    let journal_digest = env.crypto().sha256(&Bytes::from_slice(env, &TEST_JOURNAL));
+    (seal, image_id, journal_digest.into())
+}
+
+/// Returns the test receipt in its claim digest form.
+pub(crate) fn test_receipt(env: &Env) -> Receipt {
+    let (seal, image_id, journal_digest) = test_inputs(env);
+    Receipt::from_execution(env, seal, image_id, journal_digest)
+}
+
+/// Returns the JSON fixture files in `dir`, relative to this crate, sorted by name.
+pub(crate) fn fixture_files(dir: &str) -> std::vec::Vec<PathBuf> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(dir);
+    let mut files: std::vec::Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap_or_else(|err| panic!("cannot read {}: {err}", dir.display()))
+        .map(|entry| entry.expect("readable directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Reads and parses a JSON fixture file.
+pub(crate) fn load_fixture<T: DeserializeOwned>(path: &PathBuf) -> T {
+    let data = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("cannot read {}: {err}", path.display()));
+    serde_json::from_str(&data)
+        .unwrap_or_else(|err| panic!("{}: invalid fixture file: {err}", path.display()))
+}
+
+#[test]
+fn mainnet_budget_fits_verification_through_the_stack() {
+    for budget in [BudgetPreset::Mainnet, BudgetPreset::TightMemory] {
+        let env = TestEnvBuilder::new().budget(budget).build();
+        let admin = Address::generate(&env);
+        let groth16 = register_groth16_verifier(&env, &admin);
+        let estop = register_emergency_stop(&env, &groth16.address, &admin, &admin);
+        let router = register_router(
+            &env,
+            &admin,
+            &admin,
+            &admin,
+            soroban_sdk::vec![&env, (groth16.selector(), estop.address.clone())],
+        );
+
+        let (seal, image_id, journal_digest) = test_inputs(&env);
+        router.verify(&seal, &image_id, &journal_digest);
+    }
+}
+
+#[test]
+fn register_helpers_deploy_mock_verifier_and_image_registry() {
+    let env = TestEnvBuilder::new()
+        .budget(BudgetPreset::Unlimited)
+        .mock_all_auths(false)
+        .build();
+    let selector = BytesN::from_array(&env, &[0xAA, 0xBB, 0xCC, 0xDD]);
+    let mock = register_mock_verifier(&env, &selector);
+    let registry = register_image_registry(&env);
+
+    let (_seal, image_id, journal_digest) = test_inputs(&env);
+    let receipt = mock.mock_prove(&image_id, &journal_digest);
+    mock.verify(&receipt.seal, &image_id, &journal_digest);
+
+    // Without mocked authorizations, registering requires the owner's signature.
+    let metadata = ImageMetadata {
+        name: String::from_str(&env, "guest"),
+        version: String::from_str(&env, "1.0.0"),
+        source_url_digest: BytesN::from_array(&env, &[1; 32]),
+    };
+    assert!(
+        registry
+            .try_register(&Address::generate(&env), &image_id, &metadata)
+            .is_err()
+    );
+    assert_eq!(registry.image(&image_id), None);
+}
@@ -0,0 +1,288 @@
+//! Groth16 verifier parameters: the verification key and the values derived from it.
+//!
+//! The groth16-verifier build script embeds these values in the contract, and `selector-gen`
+//! prints them for third-party verifiers, so both derive them the same way.
+
+use std::str::FromStr;
+
+use ark_bn254::{Fq, Fq2, G1Affine, G2Affine};
+use serde::Deserialize;
+
+use crate::{
+    GROTH16_RECEIPT_VERIFIER_PARAMETERS_TAG, GROTH16_VERIFYING_KEY_IC_TAG,
+    GROTH16_VERIFYING_KEY_TAG, SELECTOR_SIZE, Sha256Digest, hash_g1_point, hash_g2_point,
+    tagged_iter, tagged_struct,
+};
+
+/// The size of each half of a split control root.
+pub const CONTROL_ROOT_HALF_SIZE: usize = 16;
+
+/// A Groth16 verification key.
+pub struct VerificationKey {
+    /// The alpha element in G1.
+    pub alpha: G1Affine,
+    /// The beta element in G2.
+    pub beta: G2Affine,
+    /// The gamma element in G2.
+    pub gamma: G2Affine,
+    /// The delta element in G2.
+    pub delta: G2Affine,
+    /// The input coefficient (IC) points in G1.
+    pub ic: Vec<G1Affine>,
+}
+
+impl VerificationKey {
+    /// Returns the digest of the key, as hashed by RISC Zero's `Groth16ReceiptVerifierParameters`.
+    pub fn digest(&self) -> Sha256Digest {
+        let ic: Vec<Sha256Digest> = self.ic.iter().map(hash_g1_point).collect();
+        let ic_list = tagged_iter(GROTH16_VERIFYING_KEY_IC_TAG, ic.into_iter());
+
+        tagged_struct(
+            GROTH16_VERIFYING_KEY_TAG,
+            &[
+                hash_g1_point(&self.alpha),
+                hash_g2_point(&self.beta),
+                hash_g2_point(&self.gamma),
+                hash_g2_point(&self.delta),
+                ic_list,
+            ],
+        )
+    }
+}
+
+/// JSON representation of a Groth16 verification key, with decimal coordinates.
+#[derive(Deserialize)]
+pub struct VerificationKeyJson {
+    /// The alpha element in G1, part of the verification key.
+    alpha: PointG1Json,
+    /// The beta element in G2, part of the verification key.
+    beta: PointG2Json,
+    /// The gamma element in G2, used in the pairing equation
+    /// involving the public inputs.
+    gamma: PointG2Json,
+    /// The delta element in G2, used in the main pairing check
+    /// during proof verification.
+    delta: PointG2Json,
+    /// The input coefficient (IC) points in G1.
+    ///
+    /// These are used to compute a linear combination of the
+    /// public inputs:
+    ///   acc = IC[0] + sum_i public_inputs[i] * IC[i+1].
+    ///
+    /// The length of this vector is typically `num_public_inputs + 1`.
+    #[serde(rename = "IC")]
+    ic: Vec<PointG1Json>,
+}
+
+impl VerificationKeyJson {
+    /// Decodes the verification key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first coordinate that is not a field element, or the first
+    /// point that is not on the curve.
+    pub fn to_verification_key(&self) -> Result<VerificationKey, String> {
+        Ok(VerificationKey {
+            alpha: self.alpha.to_g1_affine("alpha")?,
+            beta: self.beta.to_g2_affine("beta")?,
+            gamma: self.gamma.to_g2_affine("gamma")?,
+            delta: self.delta.to_g2_affine("delta")?,
+            ic: self
+                .ic
+                .iter()
+                .enumerate()
+                .map(|(i, point)| point.to_g1_affine(&format!("IC[{i}]")))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct PointG1Json {
+    x: String,
+    y: String,
+}
+
+impl PointG1Json {
+    fn to_g1_affine(&self, name: &str) -> Result<G1Affine, String> {
+        let x = fq(&self.x, name, "x")?;
+        let y = fq(&self.y, name, "y")?;
+
+        let point = G1Affine::new_unchecked(x, y);
+        if !point.is_on_curve() {
+            return Err(format!("{name} is not on the curve"));
+        }
+        Ok(point)
+    }
+}
+
+#[derive(Deserialize)]
+struct PointG2Json {
+    x1: String,
+    x2: String,
+    y1: String,
+    y2: String,
+}
+
+impl PointG2Json {
+    fn to_g2_affine(&self, name: &str) -> Result<G2Affine, String> {
+        let x_im = fq(&self.x1, name, "x1")?;
+        let x_re = fq(&self.x2, name, "x2")?;
+        let y_im = fq(&self.y1, name, "y1")?;
+        let y_re = fq(&self.y2, name, "y2")?;
+
+        let point = G2Affine::new_unchecked(Fq2::new(x_re, x_im), Fq2::new(y_re, y_im));
+        if !point.is_on_curve() {
+            return Err(format!("{name} is not on the curve"));
+        }
+        Ok(point)
+    }
+}
+
+/// Parses a decimal base field element.
+fn fq(value: &str, point: &str, coordinate: &str) -> Result<Fq, String> {
+    Fq::from_str(value).map_err(|()| format!("invalid field element for {point}.{coordinate}"))
+}
+
+/// Returns the selector of a Groth16 verifier: the first bytes of the digest of its
+/// `Groth16ReceiptVerifierParameters`.
+///
+/// `control_root` and `bn254_control_id` are in the byte order RISC Zero prints them in, as in
+/// `parameters.json`.
+pub fn selector(
+    control_root: &Sha256Digest,
+    bn254_control_id: &Sha256Digest,
+    vk_digest: &Sha256Digest,
+) -> [u8; SELECTOR_SIZE] {
+    let mut bn254_control_id = *bn254_control_id;
+    bn254_control_id.reverse();
+
+    let parameters_digest = tagged_struct(
+        GROTH16_RECEIPT_VERIFIER_PARAMETERS_TAG,
+        &[*control_root, bn254_control_id, *vk_digest],
+    );
+
+    let mut selector = [0u8; SELECTOR_SIZE];
+    selector.copy_from_slice(&parameters_digest[..SELECTOR_SIZE]);
+    selector
+}
+
+/// Checks `selector` against the `expected` selector, in hex with an optional `0x` prefix.
+///
+/// # Errors
+///
+/// Returns a description of the mismatch, or of why `expected` is not a selector.
+pub fn check_selector(expected: &str, selector: &[u8; SELECTOR_SIZE]) -> Result<(), String> {
+    let expected_bytes = hex::decode(expected.strip_prefix("0x").unwrap_or(expected))
+        .map_err(|err| format!("invalid hex for expected_selector: {err}"))?;
+    if expected_bytes.len() != SELECTOR_SIZE {
+        return Err(format!("expected_selector must be {SELECTOR_SIZE} bytes"));
+    }
+    if expected_bytes != selector {
+        return Err(format!(
+            "selector mismatch: parameters derive {}, expected {}",
+            hex::encode(selector),
+            hex::encode(expected_bytes)
+        ));
+    }
+    Ok(())
+}
+
+/// Splits a control root into the two public signals of the Groth16 circuit,
+/// `(control_root_0, control_root_1)`.
+pub fn split_control_root(
+    control_root: &Sha256Digest,
+) -> ([u8; CONTROL_ROOT_HALF_SIZE], [u8; CONTROL_ROOT_HALF_SIZE]) {
+    let mut bytes = *control_root;
+    bytes.reverse();
+
+    let mut control_root_0 = [0u8; CONTROL_ROOT_HALF_SIZE];
+    let mut control_root_1 = [0u8; CONTROL_ROOT_HALF_SIZE];
+
+    // Note: Solidity's splitDigest returns (lower128, upper128) but assigns them as
+    // control_root0 = upper128, control_root1 = lower128. We match that convention here.
+    control_root_0.copy_from_slice(&bytes[CONTROL_ROOT_HALF_SIZE..]); // Upper 128 bits
+    control_root_1.copy_from_slice(&bytes[..CONTROL_ROOT_HALF_SIZE]); // Lower 128 bits
+
+    (control_root_0, control_root_1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{VerificationKeyJson, check_selector, selector, split_control_root};
+
+    const PARAMETERS: &str = include_str!("../../../crates/params/parameters.json");
+
+    fn digest(hex: &str) -> [u8; 32] {
+        hex::decode(hex).unwrap().try_into().unwrap()
+    }
+
+    fn parameters() -> serde_json::Value {
+        serde_json::from_str(PARAMETERS).unwrap()
+    }
+
+    // Reference values: the selector and control root halves of the RISC Zero 3.0 verifier,
+    // as registered on Ethereum.
+    #[test]
+    fn test_selector_of_embedded_parameters() {
+        let parameters = parameters();
+        let vk: VerificationKeyJson =
+            serde_json::from_value(parameters["verification_key"].clone()).unwrap();
+        let vk_digest = vk.to_verification_key().unwrap().digest();
+        let control_root = digest(parameters["control_root"].as_str().unwrap());
+        let bn254_control_id = digest(parameters["bn254_control_id"].as_str().unwrap());
+
+        assert_eq!(
+            hex::encode(vk_digest),
+            "21c5fdd9b4d576b17581f50b755482ba7a2134a3b5186e8e454acfa1f69511ab"
+        );
+        assert_eq!(
+            hex::encode(selector(&control_root, &bn254_control_id, &vk_digest)),
+            "73c457ba"
+        );
+    }
+
+    #[test]
+    fn test_split_control_root() {
+        let control_root = digest(parameters()["control_root"].as_str().unwrap());
+        let (control_root_0, control_root_1) = split_control_root(&control_root);
+
+        assert_eq!(
+            hex::encode(control_root_0),
+            "41af18736dc9d7921c859fc95ac84da5"
+        );
+        assert_eq!(
+            hex::encode(control_root_1),
+            "561f8c992a424deb37ccdf4e19c0e7db"
+        );
+    }
+
+    #[test]
+    fn test_check_selector() {
+        let selector = [0x73, 0xc4, 0x57, 0xba];
+
+        assert_eq!(check_selector("73c457ba", &selector), Ok(()));
+        assert_eq!(check_selector("0x73c457ba", &selector), Ok(()));
+        assert_eq!(
+            check_selector("73c457bb", &selector),
+            Err("selector mismatch: parameters derive 73c457ba, expected 73c457bb".to_string())
+        );
+        assert_eq!(
+            check_selector("73c457", &selector),
+            Err("expected_selector must be 4 bytes".to_string())
+        );
+    }
+
+    #[test]
+    fn test_verification_key_rejects_point_off_curve() {
+        let mut parameters = parameters();
+        parameters["verification_key"]["alpha"]["y"] = "1".into();
+        let vk: VerificationKeyJson =
+            serde_json::from_value(parameters["verification_key"].clone()).unwrap();
+
+        assert_eq!(
+            vk.to_verification_key().err().unwrap(),
+            "alpha is not on the curve"
+        );
+    }
+}
@@ -14,9 +14,16 @@
 //!
 //! ## Main Functions
 //!
-//! - [`hash_point()`]: Hashes elliptic curve points in a standardized format
+//! - [`hash_g1_point()`] / [`hash_g2_point()`]: Hash elliptic curve points in the RISC Zero
+//!   (and Solidity) format
+//! - [`g1_to_bytes()`] / [`g2_to_bytes()`] and [`format_byte_array()`]: Encode points and bytes
+//!   for build scripts embedding them in contracts
 //! - [`tagged_struct()`]: Creates hashes for tagged structs with named fields
 //! - [`tagged_iter()`]: Creates hashes for tagged lists from iterators
+//! - [`groth16`]: The Groth16 verification key digest, selector and control root halves
+//!
+//! The Groth16 tag strings and sizes are re-exported from `risc0-constants`, so build scripts
+//! hash with the same definitions as the contracts.
 //!
 //! ## Usage in Build Scripts
 //!
@@ -34,47 +41,95 @@
 //! let struct_hash = tagged_struct("MyStruct", &[field1, field2]);
 //! ```
 
+pub mod groth16;
+
 use ark_bn254::{Fq, G1Affine, G2Affine};
 use ark_ec::AffineRepr;
-use ark_serialize::CanonicalSerialize;
+use ark_ff::{BigInteger, PrimeField};
 use sha2::{Digest, Sha256};
 
-/// The size of a SHA-256 digest in bytes.
-const DIGEST_SIZE: usize = 32;
+pub use risc0_constants::{
+    DIGEST_SIZE, GROTH16_RECEIPT_VERIFIER_PARAMETERS_TAG, GROTH16_VERIFYING_KEY_IC_TAG,
+    GROTH16_VERIFYING_KEY_TAG, SELECTOR_SIZE,
+};
+
+/// The size of a serialized base field element in bytes.
+const FQ_SIZE: usize = 32;
 
 /// A 32-byte SHA-256 digest.
 pub type Sha256Digest = [u8; DIGEST_SIZE];
 
-/// Convert an Fq field element to big-endian bytes (Solidity format)
-fn fq_to_be_bytes(f: &Fq) -> [u8; 32] {
-    let mut buffer = [0u8; 32];
-    f.serialize_uncompressed(buffer.as_mut_slice()).unwrap();
-    buffer.reverse(); // arkworks uses little-endian, we need big-endian
+/// Serializes an Fq field element as a fixed-size, big-endian integer (Solidity format).
+fn fq_to_be_bytes(f: &Fq) -> [u8; FQ_SIZE] {
+    let bytes = f.into_bigint().to_bytes_be();
+    let mut buffer = [0u8; FQ_SIZE];
+    buffer[FQ_SIZE - bytes.len()..].copy_from_slice(&bytes);
+    buffer
+}
+
+/// Serializes a G1 point as `x || y`, each coordinate a 32-byte big-endian integer.
+///
+/// This is the encoding of Soroban's `Bn254G1Affine`, so build scripts can embed points in
+/// contracts with it.
+///
+/// # Panics
+///
+/// Panics on the point at infinity, which has no affine coordinates.
+pub fn g1_to_bytes(p: &G1Affine) -> [u8; 2 * FQ_SIZE] {
+    let (x, y) = p.xy().expect("cannot serialize the point at infinity");
+
+    let mut buffer = [0u8; 2 * FQ_SIZE];
+    for (chunk, coordinate) in buffer.chunks_exact_mut(FQ_SIZE).zip([x, y]) {
+        chunk.copy_from_slice(&fq_to_be_bytes(&coordinate));
+    }
     buffer
 }
 
-/// Hash a G1 point (Fq coordinates)
+/// Serializes a G2 point as `x.c1 || x.c0 || y.c1 || y.c0`, each component a 32-byte
+/// big-endian integer.
+///
+/// The imaginary component (`c1`) of each Fq2 coordinate comes first, matching the RISC Zero
+/// reference implementation, the EVM precompile encoding and Soroban's `Bn254G2Affine`.
+///
+/// # Panics
+///
+/// Panics on the point at infinity, which has no affine coordinates.
+pub fn g2_to_bytes(p: &G2Affine) -> [u8; 4 * FQ_SIZE] {
+    let (x, y) = p.xy().expect("cannot serialize the point at infinity");
+
+    let mut buffer = [0u8; 4 * FQ_SIZE];
+    for (chunk, component) in buffer
+        .chunks_exact_mut(FQ_SIZE)
+        .zip([x.c1, x.c0, y.c1, y.c0])
+    {
+        chunk.copy_from_slice(&fq_to_be_bytes(&component));
+    }
+    buffer
+}
+
+/// Hashes a G1 point as `SHA-256(x || y)`, see [`g1_to_bytes`].
+///
+/// # Panics
+///
+/// Panics on the point at infinity, which has no affine coordinates.
 pub fn hash_g1_point(p: &G1Affine) -> Sha256Digest {
-    let (x, y) = p.xy().unwrap();
-    let mut buffer = Vec::with_capacity(64);
-    buffer.extend_from_slice(&fq_to_be_bytes(&x));
-    buffer.extend_from_slice(&fq_to_be_bytes(&y));
-    Sha256::digest(&buffer).into()
+    Sha256::digest(g1_to_bytes(p)).into()
 }
 
-/// Hash a G2 point (Fq2 coordinates, each having c0 and c1 components)
+/// Hashes a G2 point as `SHA-256(x.c1 || x.c0 || y.c1 || y.c0)`, see [`g2_to_bytes`].
+///
+/// # Panics
+///
+/// Panics on the point at infinity, which has no affine coordinates.
 pub fn hash_g2_point(p: &G2Affine) -> Sha256Digest {
-    let (x, y) = p.xy().unwrap();
-    let mut buffer = Vec::with_capacity(128);
-
-    // For Fq2, we need to serialize c0 and c1 separately
-    // Solidity expects: x.c1, x.c0, y.c1, y.c0 (all big-endian)
-    buffer.extend_from_slice(&fq_to_be_bytes(&x.c1));
-    buffer.extend_from_slice(&fq_to_be_bytes(&x.c0));
-    buffer.extend_from_slice(&fq_to_be_bytes(&y.c1));
-    buffer.extend_from_slice(&fq_to_be_bytes(&y.c0));
+    Sha256::digest(g2_to_bytes(p)).into()
+}
 
-    Sha256::digest(&buffer).into()
+/// Formats bytes as a Rust array literal, e.g. `[0x01, 0xab]`, for build scripts to embed with
+/// `include!`.
+pub fn format_byte_array(bytes: &[u8]) -> String {
+    let formatted: Vec<String> = bytes.iter().map(|b| format!("{b:#04x}")).collect();
+    format!("[{}]", formatted.join(", "))
 }
 
 /// Creates a tagged struct hash from a tag and a list of field digests.
@@ -159,7 +214,46 @@ fn tagged_list_cons(tag: &str, head: Sha256Digest, tail: Sha256Digest) -> Sha256
 
 #[cfg(test)]
 mod tests {
-    use super::{tagged_iter, tagged_struct};
+    use ark_bn254::{G1Affine, G2Affine};
+    use ark_ec::AffineRepr;
+
+    use super::{
+        format_byte_array, g1_to_bytes, g2_to_bytes, hash_g1_point, hash_g2_point, tagged_iter,
+        tagged_struct,
+    };
+
+    // Reference vectors: SHA-256 over the big-endian coordinates of the standard BN254
+    // generators, computed independently of arkworks.
+    #[test]
+    fn test_hash_g1_generator() {
+        assert_eq!(
+            hex::encode(hash_g1_point(&G1Affine::generator())),
+            "d6ba9329f8932c12192b37849f772104d20048f76434a3290512d9d814e4116f"
+        );
+    }
+
+    #[test]
+    fn test_hash_g2_generator() {
+        assert_eq!(
+            hex::encode(hash_g2_point(&G2Affine::generator())),
+            "a0de5dc137b285f0d15790d0e0007a26331cafe00d29bdaa0e7e9afa276847aa"
+        );
+    }
+
+    #[test]
+    fn test_point_encoding() {
+        let g1 = g1_to_bytes(&G1Affine::generator());
+        assert_eq!(g1[31], 1);
+        assert_eq!(g1[63], 2);
+        assert!(g1[..31].iter().chain(&g1[32..63]).all(|&b| b == 0));
+
+        // x.c1 of the G2 generator comes first.
+        assert_eq!(
+            hex::encode(&g2_to_bytes(&G2Affine::generator())[..32]),
+            "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"
+        );
+        assert_eq!(format_byte_array(&[0x01, 0xab]), "[0x01, 0xab]");
+    }
 
     #[test]
     fn test_tagged_struct() {
@@ -0,0 +1,209 @@
+//! Generates a contract crate verifying Groth16 proofs of a custom circuit.
+//!
+//! ```text
+//! scaffold <name> <vk.json> [<directory>]
+//! ```
+//!
+//! `name` is the crate name, in kebab-case. `vk.json` holds the circuit's verification key in
+//! the format of the `verification_key` field of `risc0-stellar-params`' `parameters.json`, as
+//! exported by snarkjs. The crate is created in `<directory>/<name>`, `contracts` by default,
+//! and holds:
+//!
+//! - `vk.json`: a copy of the verification key
+//! - `build.rs`: embeds the verification key in the contract at compile time
+//! - `src/lib.rs`: a contract with a `verify(proof, public_inputs)` entrypoint taking as many
+//!   public inputs as the key has
+//! - `src/test.rs`: tests of the entrypoint's failure paths, to extend with proofs of the
+//!   circuit
+//!
+//! Unlike the groth16-verifier, the contract knows nothing of RISC Zero receipts: it checks
+//! the pairing equation against the public inputs it is given. Add the crate to the workspace
+//! members to build it.
+
+use std::{env, fs, path::Path, process::ExitCode};
+
+use build_utils::groth16::VerificationKeyJson;
+
+const USAGE: &str = "usage: scaffold <name> <vk.json> [<directory>]";
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const BUILD_RS: &str = include_str!("../templates/build.rs.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const TEST_RS: &str = include_str!("../templates/test.rs.tmpl");
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (name, vk_path, directory) = match args.as_slice() {
+        [name, vk_path] => (name, vk_path, "contracts"),
+        [name, vk_path, directory] => (name, vk_path, directory.as_str()),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = fs::read_to_string(vk_path)
+        .map_err(|err| format!("cannot read {vk_path}: {err}"))
+        .and_then(|vk_json| render(name, &vk_json))
+        .and_then(|files| write(&Path::new(directory).join(name), &files));
+    match result {
+        Ok(crate_dir) => {
+            println!("created {crate_dir}");
+            println!("add \"{crate_dir}\" to the workspace members to build it");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Returns the files of the crate, as `(path, contents)` relative to its directory.
+fn render(name: &str, vk_json: &str) -> Result<Vec<(&'static str, String)>, String> {
+    let contract_name = contract_name(name)?;
+    let vk: VerificationKeyJson =
+        serde_json::from_str(vk_json).map_err(|err| format!("invalid vk.json: {err}"))?;
+    let public_inputs = vk
+        .to_verification_key()?
+        .ic
+        .len()
+        .checked_sub(1)
+        .ok_or("the verification key has no IC points")?;
+
+    let fill = |template: &str| {
+        template
+            .replace("{{crate_name}}", name)
+            .replace("{{contract_name}}", &contract_name)
+            .replace("{{public_inputs}}", &public_inputs.to_string())
+    };
+    Ok(vec![
+        ("Cargo.toml", fill(CARGO_TOML)),
+        ("build.rs", fill(BUILD_RS)),
+        ("vk.json", vk_json.to_string()),
+        ("src/lib.rs", fill(LIB_RS)),
+        ("src/test.rs", fill(TEST_RS)),
+    ])
+}
+
+/// Writes the files of the crate to `crate_dir`, which must not exist yet.
+fn write(crate_dir: &Path, files: &[(&str, String)]) -> Result<String, String> {
+    let display = crate_dir.display().to_string();
+    if crate_dir.exists() {
+        return Err(format!("{display} already exists"));
+    }
+    for (path, contents) in files {
+        let path = crate_dir.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("cannot create {}: {err}", parent.display()))?;
+        }
+        fs::write(&path, contents)
+            .map_err(|err| format!("cannot write {}: {err}", path.display()))?;
+    }
+    Ok(display)
+}
+
+/// Returns the contract type name of the crate `name`, e.g. `MyCircuit` for `my-circuit`.
+fn contract_name(name: &str) -> Result<String, String> {
+    let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.split('-').all(|word| {
+            !word.is_empty()
+                && word
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        });
+    if !valid {
+        return Err(format!(
+            "invalid name {name:?}: expected lowercase words separated by `-`, e.g. my-circuit"
+        ));
+    }
+
+    Ok(name
+        .split('-')
+        .map(|word| {
+            let (first, rest) = word.split_at(1);
+            first.to_ascii_uppercase() + rest
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{contract_name, render};
+
+    const PARAMETERS: &str = include_str!("../../../crates/params/parameters.json");
+
+    fn vk_json() -> String {
+        let parameters: serde_json::Value = serde_json::from_str(PARAMETERS).unwrap();
+        parameters["verification_key"].to_string()
+    }
+
+    fn file<'a>(files: &'a [(&str, String)], path: &str) -> &'a str {
+        &files.iter().find(|(name, _)| *name == path).unwrap().1
+    }
+
+    #[test]
+    fn test_render_with_embedded_key() {
+        let vk_json = vk_json();
+        let files = render("my-circuit", &vk_json).unwrap();
+
+        assert_eq!(
+            files.iter().map(|(path, _)| *path).collect::<Vec<_>>(),
+            [
+                "Cargo.toml",
+                "build.rs",
+                "vk.json",
+                "src/lib.rs",
+                "src/test.rs"
+            ]
+        );
+        assert!(file(&files, "Cargo.toml").contains("name = \"my-circuit\""));
+        assert_eq!(file(&files, "vk.json"), vk_json);
+
+        // The RISC Zero key has 5 public inputs.
+        let lib = file(&files, "src/lib.rs");
+        assert!(lib.contains("pub const PUBLIC_INPUTS: usize = 5;"));
+        assert!(lib.contains("pub struct MyCircuit;"));
+        assert!(file(&files, "src/test.rs").contains("MyCircuitClient"));
+
+        for (path, contents) in &files {
+            for placeholder in ["{{crate_name}}", "{{contract_name}}", "{{public_inputs}}"] {
+                assert!(!contents.contains(placeholder), "{path} has {placeholder}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_rejects_invalid_key() {
+        let mut vk: serde_json::Value = serde_json::from_str(&vk_json()).unwrap();
+        vk["IC"] = serde_json::json!([]);
+        assert_eq!(
+            render("my-circuit", &vk.to_string()).unwrap_err(),
+            "the verification key has no IC points"
+        );
+
+        vk["IC"] = serde_json::json!([{ "x": "1", "y": "1" }]);
+        assert_eq!(
+            render("my-circuit", &vk.to_string()).unwrap_err(),
+            "IC[0] is not on the curve"
+        );
+    }
+
+    #[test]
+    fn test_contract_name() {
+        assert_eq!(contract_name("my-circuit").unwrap(), "MyCircuit");
+        assert_eq!(contract_name("semaphore2").unwrap(), "Semaphore2");
+
+        for name in [
+            "",
+            "My-circuit",
+            "my_circuit",
+            "my--circuit",
+            "2fa",
+            "circuit-",
+        ] {
+            assert!(contract_name(name).is_err(), "{name:?} accepted");
+        }
+    }
+}
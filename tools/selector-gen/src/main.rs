@@ -0,0 +1,118 @@
+//! Derives the on-chain parameters of a RISC Zero Groth16 verifier.
+//!
+//! ```text
+//! selector-gen <control_root> <bn254_control_id> <vk.json>
+//! ```
+//!
+//! `control_root` and `bn254_control_id` are hex digests, as RISC Zero prints them and as they
+//! appear in `risc0-stellar-params`' `parameters.json`. `vk.json` holds a verification key in
+//! the format of the `verification_key` field of that file.
+//!
+//! Prints the values a verifier built from these parameters uses on-chain, in hex:
+//!
+//! - `selector`: the 4 bytes seals start with, and the key to register the verifier under in
+//!   the router
+//! - `control_root_0` and `control_root_1`: the control root halves passed as public signals
+//! - `vk_digest`: the verification key digest the selector commits to
+
+use std::{env, fs, process::ExitCode};
+
+use build_utils::{
+    Sha256Digest,
+    groth16::{VerificationKeyJson, selector, split_control_root},
+};
+
+const USAGE: &str = "usage: selector-gen <control_root> <bn254_control_id> <vk.json>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let [control_root, bn254_control_id, vk_path] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let output = fs::read_to_string(vk_path)
+        .map_err(|err| format!("cannot read {vk_path}: {err}"))
+        .and_then(|vk_json| run(control_root, bn254_control_id, &vk_json));
+    match output {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Returns the derived parameters, one `name: value` line each.
+fn run(control_root: &str, bn254_control_id: &str, vk_json: &str) -> Result<String, String> {
+    let control_root = parse_digest(control_root, "control_root")?;
+    let bn254_control_id = parse_digest(bn254_control_id, "bn254_control_id")?;
+    let vk: VerificationKeyJson =
+        serde_json::from_str(vk_json).map_err(|err| format!("invalid vk.json: {err}"))?;
+    let vk_digest = vk.to_verification_key()?.digest();
+
+    let selector = selector(&control_root, &bn254_control_id, &vk_digest);
+    let (control_root_0, control_root_1) = split_control_root(&control_root);
+
+    Ok(format!(
+        "selector:       {}\n\
+         control_root_0: {}\n\
+         control_root_1: {}\n\
+         vk_digest:      {}\n",
+        hex::encode(selector),
+        hex::encode(control_root_0),
+        hex::encode(control_root_1),
+        hex::encode(vk_digest),
+    ))
+}
+
+/// Parses a hex digest, with or without a `0x` prefix.
+fn parse_digest(digest: &str, name: &str) -> Result<Sha256Digest, String> {
+    let digest = digest.strip_prefix("0x").unwrap_or(digest);
+    hex::decode(digest)
+        .map_err(|err| format!("invalid hex for {name}: {err}"))?
+        .try_into()
+        .map_err(|_| format!("{name} must be 32 bytes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+
+    const PARAMETERS: &str = include_str!("../../../crates/params/parameters.json");
+
+    fn vk_json() -> String {
+        let parameters: serde_json::Value = serde_json::from_str(PARAMETERS).unwrap();
+        parameters["verification_key"].to_string()
+    }
+
+    // The parameters embedded in the groth16-verifier must derive its selector.
+    #[test]
+    fn test_run_with_embedded_parameters() {
+        let output = run(
+            "a54dc85ac99f851c92d7c96d7318af41dbe7c0194edfcc37eb4d422a998c1f56",
+            "0x04446e66d300eb7fb45c9726bb53c793dda407a62e9601618bb43c5c14657ac0",
+            &vk_json(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            output,
+            "selector:       73c457ba\n\
+             control_root_0: 41af18736dc9d7921c859fc95ac84da5\n\
+             control_root_1: 561f8c992a424deb37ccdf4e19c0e7db\n\
+             vk_digest:      21c5fdd9b4d576b17581f50b755482ba7a2134a3b5186e8e454acfa1f69511ab\n"
+        );
+    }
+
+    #[test]
+    fn test_run_rejects_short_digest() {
+        assert_eq!(
+            run("a54d", "04446e66", &vk_json()).unwrap_err(),
+            "control_root must be 32 bytes"
+        );
+    }
+}